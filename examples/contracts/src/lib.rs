@@ -65,7 +65,7 @@ mod tests {
 
         // ANCHOR: use_deployed_contract
         // This is an instance of your contract which you can use to make calls to your functions
-        let contract_instance = MyContract::new(contract_id.to_string(), wallet);
+        let contract_instance = MyContract::new(contract_id.to_string(), wallet)?;
 
         let response = contract_instance
             .initialize_counter(42) // Build the ABI call
@@ -146,7 +146,7 @@ mod tests {
         .await?;
 
         println!("Contract deployed @ {:x}", contract_id_1);
-        let contract_instance_1 = MyContract::new(contract_id_1.to_string(), wallets[0].clone());
+        let contract_instance_1 = MyContract::new(contract_id_1.to_string(), wallets[0].clone())?;
 
         let response = contract_instance_1
             .initialize_counter(42) // Build the ABI call
@@ -166,7 +166,7 @@ mod tests {
         .await?;
 
         println!("Contract deployed @ {:x}", contract_id_2);
-        let contract_instance_2 = MyContract::new(contract_id_2.to_string(), wallets[1].clone());
+        let contract_instance_2 = MyContract::new(contract_id_2.to_string(), wallets[1].clone())?;
 
         let response = contract_instance_2
             .initialize_counter(42) // Build the ABI call
@@ -176,6 +176,17 @@ mod tests {
 
         assert_eq!(42, response.value);
         // ANCHOR_END: deploy_with_multiple_wallets
+
+        // ANCHOR: with_wallet
+        // Call the first contract as the second wallet, sharing the instance's provider
+        let response = contract_instance_1
+            .with_wallet(wallets[1].clone())
+            .increment_counter(10)
+            .call()
+            .await?;
+
+        assert_eq!(52, response.value);
+        // ANCHOR_END: with_wallet
         Ok(())
     }
 
@@ -198,7 +209,7 @@ mod tests {
             .await?;
         println!("Contract deployed @ {:x}", contract_id);
         // ANCHOR: instantiate_contract
-        let contract_instance = MyContract::new(contract_id.to_string(), wallet.clone());
+        let contract_instance = MyContract::new(contract_id.to_string(), wallet.clone())?;
         // ANCHOR_END: instantiate_contract
         // ANCHOR: tx_parameters
         // In order: gas_price, gas_limit, byte_price, and maturity
@@ -278,11 +289,17 @@ mod tests {
         )
         .await?;
         println!("Contract deployed @ {:x}", contract_id);
-        let contract_instance = MyContract::new(contract_id.to_string(), wallet.clone());
+        let contract_instance = MyContract::new(contract_id.to_string(), wallet.clone())?;
         // ANCHOR: simulate
         // you would mint 100 coins if the transaction wasn't simulated
         let counter = contract_instance.mint_coins(100).simulate().await?;
         // ANCHOR_END: simulate
+        // ANCHOR: read_only
+        // no wallet is needed to read the state of a contract
+        let provider = wallet.get_provider()?.clone();
+        let reader = MyContract::new_read_only(contract_id.to_string(), provider)?;
+        let balance = reader.get_balance(contract_id, contract_id).read().await?;
+        // ANCHOR_END: read_only
         let response = contract_instance.mint_coins(1_000_000).call().await?;
         // ANCHOR: variable_outputs
         let address = wallet.address();
@@ -316,7 +333,7 @@ mod tests {
 
         )
         .await?;
-        let contract_instance = TestContract::new(contract_id.to_string(), wallet);
+        let contract_instance = TestContract::new(contract_id.to_string(), wallet)?;
 
         // ANCHOR: good_practice
         let response = contract_instance.increment_counter(162).call().await?;
@@ -352,7 +369,7 @@ mod tests {
         let contract_id =
             "0x068fe90ddc43b18a8f76756ecad8bf30eb0ceea33d2e6990c0185d01b0dbb675".to_string();
 
-        let connected_contract_instance = MyContract::new(contract_id, wallet);
+        let connected_contract_instance = MyContract::new(contract_id, wallet)?;
         // You can now use the `connected_contract_instance` just as you did above!
         // ANCHOR_END: deployed_contracts
         Ok(())
@@ -377,7 +394,7 @@ mod tests {
         )
     .await?;
 
-        let contract_instance = MyContract::new(contract_id.to_string(), wallet.clone());
+        let contract_instance = MyContract::new(contract_id.to_string(), wallet.clone())?;
 
         // ANCHOR: call_params_gas
         // Set the transaction `gas_limit` to 1000 and `gas_forwarded` to 200 to specify that the
@@ -417,7 +434,7 @@ mod tests {
     .await?;
 
         // ANCHOR: multi_call_prepare
-        let contract_instance = MyContract::new(contract_id.to_string(), wallet.clone());
+        let contract_instance = MyContract::new(contract_id.to_string(), wallet.clone())?;
 
         let call_handler_1 = contract_instance.initialize_counter(42);
         let call_handler_2 = contract_instance.get_array([42; 2].to_vec());
@@ -442,6 +459,20 @@ mod tests {
         assert_eq!(counter, 42);
         assert_eq!(array, [42; 2]);
 
+        // ANCHOR: typed_multi_call
+        let response = TypedMultiCallHandler::new(wallet.clone())
+            .add_call(contract_instance.increment_counter(1))
+            .add_call(contract_instance.get_array([7; 2].to_vec()))
+            .call()
+            .await?;
+
+        // The value holds the type returned by each call, in order
+        let (counter, array) = response.value;
+        // ANCHOR_END: typed_multi_call
+
+        assert_eq!(counter, 43);
+        assert_eq!(array, [7; 2]);
+
         Ok(())
     }
 }
@@ -1,35 +1,57 @@
-pub struct MyContract {
+#[derive(Clone)]
+pub struct MyContract<A = LocalWallet> {
     contract_id: ContractId,
-    wallet: LocalWallet,
+    wallet: Option<A>,
+    provider: Arc<Provider>,
 }
 impl MyContract {
-    pub fn new(contract_id: String, wallet: LocalWallet) -> Self {
-        let contract_id = ContractId::from_str(&contract_id).expect("Invalid contract id");
-        Self {
+    #[doc = "Creates an instance without a wallet, whose calls can only be executed with `read()`."]
+    pub fn new_read_only(contract_id: String, provider: Provider) -> Result<Self, SDKError> {
+        let contract_id = ContractId::from_str(&contract_id).map_err(|err| {
+            SDKError::InvalidData(format!("invalid contract id `{}`: {}", contract_id, err))
+        })?;
+        Ok(Self {
             contract_id,
-            wallet,
+            wallet: None,
+            provider: Arc::new(provider),
+        })
+    }
+}
+impl<A: fuels::signers::Account + Clone> MyContract<A> {
+    #[doc = "Creates an instance calling the contract as `wallet`, which must have a provider."]
+    pub fn new(contract_id: String, wallet: A) -> Result<Self, SDKError> {
+        let provider = wallet.get_provider()?.clone();
+        Ok(MyContract::new_read_only(contract_id, provider)?.with_wallet(wallet))
+    }
+    #[doc = "Returns an instance calling the contract as `wallet`, through this instance's provider. The wallet's own provider is replaced, so that its coins are looked up on the node the calls are sent to."]
+    pub fn with_wallet<B: fuels::signers::Account + Clone>(&self, mut wallet: B) -> MyContract<B> {
+        wallet.set_provider(self.provider.as_ref().clone());
+        MyContract {
+            contract_id: self.contract_id,
+            wallet: Some(wallet),
+            provider: Arc::clone(&self.provider),
         }
     }
     #[doc = "Calls the contract's `initialize_counter` (0x00000000ab64e5f2) function"]
-    pub fn initialize_counter(&self, arg: u64) -> ContractCallHandler<u64> {
+    pub fn initialize_counter(&self, arg: u64) -> ContractCallHandler<u64, A> {
         Contract::method_hash(
-            &self.wallet.get_provider().expect("Provider not set up"),
+            &self.provider,
             self.contract_id,
-            &self.wallet,
+            self.wallet.as_ref(),
             [0, 0, 0, 0, 171, 100, 229, 242],
-            &[ParamType::U64],
+            Some(ParamType::U64),
             &[arg.into_token()],
         )
         .expect("method not found (this should never happen)")
     }
     #[doc = "Calls the contract's `increment_counter` (0x00000000faf90dd3) function"]
-    pub fn increment_counter(&self, arg: u64) -> ContractCallHandler<u64> {
+    pub fn increment_counter(&self, arg: u64) -> ContractCallHandler<u64, A> {
         Contract::method_hash(
-            &self.wallet.get_provider().expect("Provider not set up"),
+            &self.provider,
             self.contract_id,
-            &self.wallet,
+            self.wallet.as_ref(),
             [0, 0, 0, 0, 250, 249, 13, 211],
-            &[ParamType::U64],
+            Some(ParamType::U64),
             &[arg.into_token()],
         )
         .expect("method not found (this should never happen)")
@@ -0,0 +1,426 @@
+//! Implementation of the `Tokenizable` and `Parameterize` derive macros.
+//!
+//! These generate the same kind of impls that `abigen!` produces for the custom
+//! types found in a JSON ABI, but for hand-written Rust structs and enums.
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::{
+    parse_quote, Attribute, Data, DataEnum, DataStruct, DeriveInput, Error, Fields, Generics,
+    Ident, Lit, Meta, NestedMeta, Result,
+};
+
+const ATTRIBUTE_NAME: &str = "fuels";
+
+/// Options that can be given through `#[fuels(...)]` on a type or field.
+#[derive(Default)]
+struct FuelsAttributes {
+    /// Name of the corresponding Sway item, used in error messages.
+    rename: Option<String>,
+    /// Whether the field should be left out of the encoding.
+    ignore: bool,
+}
+
+impl FuelsAttributes {
+    fn parse(attrs: &[Attribute]) -> Result<Self> {
+        let mut parsed = FuelsAttributes::default();
+
+        for attr in attrs.iter().filter(|a| a.path.is_ident(ATTRIBUTE_NAME)) {
+            let list = match attr.parse_meta()? {
+                Meta::List(list) => list,
+                other => {
+                    return Err(Error::new_spanned(
+                        other,
+                        "expected `#[fuels(rename = \"...\")]` or `#[fuels(ignore)]`",
+                    ))
+                }
+            };
+
+            for nested in list.nested {
+                match nested {
+                    NestedMeta::Meta(Meta::Path(path)) if path.is_ident("ignore") => {
+                        parsed.ignore = true;
+                    }
+                    NestedMeta::Meta(Meta::NameValue(name_value))
+                        if name_value.path.is_ident("rename") =>
+                    {
+                        match name_value.lit {
+                            Lit::Str(lit) => parsed.rename = Some(lit.value()),
+                            other => {
+                                return Err(Error::new_spanned(
+                                    other,
+                                    "`rename` expects a string literal",
+                                ))
+                            }
+                        }
+                    }
+                    other => {
+                        return Err(Error::new_spanned(
+                            other,
+                            "unknown `fuels` attribute, expected `rename = \"...\"` or `ignore`",
+                        ))
+                    }
+                }
+            }
+        }
+
+        Ok(parsed)
+    }
+
+    fn name_or(&self, default: &str) -> String {
+        self.rename.clone().unwrap_or_else(|| default.to_string())
+    }
+}
+
+/// A struct field after its `#[fuels(...)]` attributes have been applied.
+struct StructField {
+    /// How to access the field, e.g. `name` or `0`.
+    member: TokenStream,
+    name: String,
+    ignore: bool,
+}
+
+/// An enum variant after its `#[fuels(...)]` attributes have been applied.
+struct EnumVariant {
+    ident: Ident,
+    /// `None` for unit variants, the type of the single field otherwise.
+    inner: Option<syn::Type>,
+    /// Whether the variant was declared as `Variant()` or `Variant(T)`.
+    parenthesized: bool,
+}
+
+pub(crate) fn generate_tokenizable_impl(input: DeriveInput) -> Result<TokenStream> {
+    let type_attrs = FuelsAttributes::parse(&input.attrs)?;
+    let name = &input.ident;
+    let sway_name = type_attrs.name_or(&name.to_string());
+
+    match &input.data {
+        Data::Struct(data) => {
+            tokenizable_for_struct(name, &sway_name, &input.generics, data, input.ident.span())
+        }
+        Data::Enum(data) => tokenizable_for_enum(name, &sway_name, &input.generics, data),
+        Data::Union(_) => Err(Error::new_spanned(
+            &input.ident,
+            "`Tokenizable` cannot be derived for unions",
+        )),
+    }
+}
+
+pub(crate) fn generate_parameterize_impl(input: DeriveInput) -> Result<TokenStream> {
+    let type_attrs = FuelsAttributes::parse(&input.attrs)?;
+    let name = &input.ident;
+    let sway_name = type_attrs.name_or(&name.to_string());
+
+    match &input.data {
+        Data::Struct(data) => {
+            parameterize_for_struct(name, &input.generics, data, input.ident.span())
+        }
+        Data::Enum(data) => parameterize_for_enum(name, &sway_name, &input.generics, data),
+        Data::Union(_) => Err(Error::new_spanned(
+            &input.ident,
+            "`Parameterize` cannot be derived for unions",
+        )),
+    }
+}
+
+fn tokenizable_for_struct(
+    name: &Ident,
+    sway_name: &str,
+    generics: &Generics,
+    data: &DataStruct,
+    span: Span,
+) -> Result<TokenStream> {
+    let fields = struct_fields(&data.fields, span)?;
+    let generics = with_bounds(
+        generics,
+        quote! { ::fuels::core::Tokenizable + ::fuels::core::Parameterize },
+    );
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let into_tokens = fields.iter().filter(|f| !f.ignore).map(|f| {
+        let member = &f.member;
+        quote! { ::fuels::core::Tokenizable::into_token(self.#member) }
+    });
+
+    let from_tokens = fields.iter().map(|f| {
+        let member = &f.member;
+        let field_name = &f.name;
+        if f.ignore {
+            quote! { #member: ::core::default::Default::default() }
+        } else {
+            quote! {
+                #member: ::fuels::core::Tokenizable::from_token(next_token()?).map_err(|e| {
+                    ::fuels::types::errors::Error::InstantiationError(format!(
+                        "Error while constructing field '{}' of '{}': {}", #field_name, #sway_name, e
+                    ))
+                })?
+            }
+        }
+    });
+
+    Ok(quote! {
+        impl #impl_generics ::fuels::core::Tokenizable for #name #ty_generics #where_clause {
+            fn into_token(self) -> ::fuels::core::Token {
+                ::fuels::core::Token::Struct(vec![#( #into_tokens ),*])
+            }
+
+            fn from_token(token: ::fuels::core::Token) -> ::core::result::Result<Self, ::fuels::types::errors::Error> {
+                match token {
+                    ::fuels::core::Token::Struct(tokens) => {
+                        let mut tokens_iter = tokens.into_iter();
+                        let mut next_token = move || {
+                            tokens_iter.next().ok_or_else(|| {
+                                ::fuels::types::errors::Error::InstantiationError(format!(
+                                    "Ran out of tokens before '{}' has finished construction!", #sway_name
+                                ))
+                            })
+                        };
+                        Ok(Self { #( #from_tokens ),* })
+                    }
+                    other => Err(::fuels::types::errors::Error::InstantiationError(format!(
+                        "Error while constructing '{}'. Expected token of type Token::Struct, got {:?}", #sway_name, other
+                    ))),
+                }
+            }
+        }
+    })
+}
+
+fn parameterize_for_struct(
+    name: &Ident,
+    generics: &Generics,
+    data: &DataStruct,
+    span: Span,
+) -> Result<TokenStream> {
+    let field_types = data
+        .fields
+        .iter()
+        .map(|field| Ok((FuelsAttributes::parse(&field.attrs)?, &field.ty)))
+        .collect::<Result<Vec<_>>>()?;
+    // Validates the fields the same way the `Tokenizable` derive does.
+    struct_fields(&data.fields, span)?;
+
+    let generics = with_bounds(generics, quote! { ::fuels::core::Parameterize });
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let param_types = field_types
+        .iter()
+        .filter(|(attrs, _)| !attrs.ignore)
+        .map(|(_, ty)| quote! { <#ty as ::fuels::core::Parameterize>::param_type() });
+
+    Ok(quote! {
+        impl #impl_generics ::fuels::core::Parameterize for #name #ty_generics #where_clause {
+            fn param_type() -> ::fuels::types::param_types::ParamType {
+                ::fuels::types::param_types::ParamType::Struct(vec![#( #param_types ),*])
+            }
+        }
+    })
+}
+
+fn tokenizable_for_enum(
+    name: &Ident,
+    sway_name: &str,
+    generics: &Generics,
+    data: &DataEnum,
+) -> Result<TokenStream> {
+    let variants = enum_variants(name, data)?;
+    let generics = with_bounds(
+        generics,
+        quote! { ::fuels::core::Tokenizable + ::fuels::core::Parameterize },
+    );
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let into_arms = variants.iter().enumerate().map(|(discriminant, variant)| {
+        let dis = discriminant as u8;
+        let ident = &variant.ident;
+        match (&variant.inner, variant.parenthesized) {
+            (Some(_), _) => quote! {
+                Self::#ident(inner) => (#dis, ::fuels::core::Tokenizable::into_token(inner))
+            },
+            (None, true) => quote! { Self::#ident() => (#dis, ::fuels::core::Token::Unit) },
+            (None, false) => quote! { Self::#ident => (#dis, ::fuels::core::Token::Unit) },
+        }
+    });
+
+    let from_arms = variants.iter().enumerate().map(|(discriminant, variant)| {
+        let dis = discriminant as u8;
+        let ident = &variant.ident;
+        match (&variant.inner, variant.parenthesized) {
+            (Some(_), _) => quote! {
+                (#dis, token, _) => Ok(Self::#ident(::fuels::core::Tokenizable::from_token(token)?))
+            },
+            (None, true) => quote! { (#dis, _, _) => Ok(Self::#ident()) },
+            (None, false) => quote! { (#dis, _, _) => Ok(Self::#ident) },
+        }
+    });
+
+    Ok(quote! {
+        impl #impl_generics ::fuels::core::Tokenizable for #name #ty_generics #where_clause {
+            fn into_token(self) -> ::fuels::core::Token {
+                let (dis, tok) = match self {
+                    #( #into_arms, )*
+                };
+
+                let variants = match <Self as ::fuels::core::Parameterize>::param_type() {
+                    ::fuels::types::param_types::ParamType::Enum(variants) => variants,
+                    other => panic!("Calling ::param_type() on a custom enum must return a ParamType::Enum but instead it returned: {}", other)
+                };
+
+                ::fuels::core::Token::Enum(Box::new((dis, tok, variants)))
+            }
+
+            fn from_token(token: ::fuels::core::Token) -> ::core::result::Result<Self, ::fuels::types::errors::Error> {
+                match token {
+                    ::fuels::core::Token::Enum(selector) => match *selector {
+                        #( #from_arms, )*
+                        (dis, _, _) => Err(::fuels::types::errors::Error::InstantiationError(format!(
+                            "Error while constructing '{}'. Discriminant {} doesn't point to any variant", #sway_name, dis
+                        ))),
+                    },
+                    other => Err(::fuels::types::errors::Error::InstantiationError(format!(
+                        "Error while constructing '{}'. Expected token of type Token::Enum, got {:?}", #sway_name, other
+                    ))),
+                }
+            }
+        }
+    })
+}
+
+fn parameterize_for_enum(
+    name: &Ident,
+    sway_name: &str,
+    generics: &Generics,
+    data: &DataEnum,
+) -> Result<TokenStream> {
+    let variants = enum_variants(name, data)?;
+    let generics = with_bounds(generics, quote! { ::fuels::core::Parameterize });
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let param_types = variants.iter().map(|variant| match &variant.inner {
+        Some(ty) => quote! { <#ty as ::fuels::core::Parameterize>::param_type() },
+        None => quote! { ::fuels::types::param_types::ParamType::Unit },
+    });
+
+    Ok(quote! {
+        impl #impl_generics ::fuels::core::Parameterize for #name #ty_generics #where_clause {
+            fn param_type() -> ::fuels::types::param_types::ParamType {
+                let variants = ::fuels::types::param_types::EnumVariants::new(vec![#( #param_types ),*])
+                    .expect(concat!("Enum ", #sway_name, " has no variants! The derive should not have succeeded!"));
+
+                ::fuels::types::param_types::ParamType::Enum(variants)
+            }
+        }
+    })
+}
+
+fn struct_fields(fields: &Fields, span: Span) -> Result<Vec<StructField>> {
+    let parsed = fields
+        .iter()
+        .enumerate()
+        .map(|(index, field)| {
+            let attrs = FuelsAttributes::parse(&field.attrs)?;
+            let (member, default_name) = match &field.ident {
+                Some(ident) => (quote! { #ident }, ident.to_string()),
+                None => {
+                    let index = syn::Index::from(index);
+                    (quote! { #index }, index.index.to_string())
+                }
+            };
+
+            Ok(StructField {
+                member,
+                name: attrs.name_or(&default_name),
+                ignore: attrs.ignore,
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    if parsed.iter().all(|field| field.ignore) {
+        return Err(Error::new(
+            span,
+            "structs must have at least one field that isn't `#[fuels(ignore)]`d",
+        ));
+    }
+
+    Ok(parsed)
+}
+
+fn enum_variants(name: &Ident, data: &DataEnum) -> Result<Vec<EnumVariant>> {
+    if data.variants.is_empty() {
+        return Err(Error::new_spanned(
+            name,
+            "enums must have at least one variant",
+        ));
+    }
+
+    if data.variants.len() > u8::MAX as usize + 1 {
+        return Err(Error::new_spanned(
+            name,
+            "enums can have at most 256 variants",
+        ));
+    }
+
+    data.variants
+        .iter()
+        .map(|variant| {
+            let attrs = FuelsAttributes::parse(&variant.attrs)?;
+            if attrs.ignore {
+                return Err(Error::new_spanned(
+                    variant,
+                    "enum variants cannot be ignored as that would shift the discriminants",
+                ));
+            }
+
+            if attrs.rename.is_some() {
+                return Err(Error::new_spanned(
+                    variant,
+                    "enum variants cannot be renamed as they are encoded by their position",
+                ));
+            }
+
+            if let Some((_, discriminant)) = &variant.discriminant {
+                return Err(Error::new_spanned(
+                    discriminant,
+                    "explicit discriminants are not supported, variants are encoded in declaration order",
+                ));
+            }
+
+            let (inner, parenthesized) = match &variant.fields {
+                Fields::Unit => (None, false),
+                Fields::Unnamed(fields) if fields.unnamed.is_empty() => (None, true),
+                Fields::Unnamed(fields) if fields.unnamed.len() == 1 => {
+                    (Some(fields.unnamed[0].ty.clone()), true)
+                }
+                other => {
+                    return Err(Error::new_spanned(
+                        other,
+                        "enum variants must either be units or hold exactly one unnamed field",
+                    ))
+                }
+            };
+
+            Ok(EnumVariant {
+                ident: variant.ident.clone(),
+                inner,
+                parenthesized,
+            })
+        })
+        .collect()
+}
+
+/// Adds `bounds` to every type parameter of `generics`.
+fn with_bounds(generics: &Generics, bounds: TokenStream) -> Generics {
+    let mut generics = generics.clone();
+    let type_params: Vec<Ident> = generics
+        .type_params()
+        .map(|param| param.ident.clone())
+        .collect();
+
+    let where_clause = generics.make_where_clause();
+    for param in type_params {
+        where_clause
+            .predicates
+            .push(parse_quote! { #param: #bounds });
+    }
+
+    generics
+}
@@ -1,28 +1,135 @@
-use fuels_core::code_gen::abigen::Abigen;
+use fuels_core::code_gen::abigen::{Abigen, MultiAbigen};
 use proc_macro::TokenStream;
 use proc_macro2::Span;
 
+use quote::quote;
+use std::collections::HashMap;
 use std::ops::Deref;
 use syn::parse::{Parse, ParseStream, Result as ParseResult};
-use syn::{parse_macro_input, Ident, LitStr, Token};
+use syn::{braced, parenthesized, parse_macro_input, token, DeriveInput, Ident, LitStr, Token};
+
+mod derive;
 
 /// Abigen proc macro definition and helper functions/types.
+///
+/// Takes either a single contract, `abigen!(MyContract, "abi.json")`, or
+/// several ones, `abigen!(Contract(name = "A", abi = "a.json"), Contract(name
+/// = "B", abi = "b.json"))`, in which case the custom types they have in
+/// common are generated once and shared by their bindings.
 #[proc_macro]
 pub fn abigen(input: TokenStream) -> TokenStream {
-    let args = parse_macro_input!(input as Spanned<ContractArgs>);
-
-    let c = Abigen::new(&args.name, &args.abi).unwrap();
+    let args = parse_macro_input!(input as Spanned<AbigenArgs>);
 
-    c.expand().unwrap().into()
+    expand_abigen(&args, false)
 }
 
 #[proc_macro]
 pub fn wasm_abigen(input: TokenStream) -> TokenStream {
-    let args = parse_macro_input!(input as Spanned<ContractArgs>);
+    let args = parse_macro_input!(input as Spanned<AbigenArgs>);
+
+    expand_abigen(&args, true)
+}
+
+fn expand_abigen(args: &Spanned<AbigenArgs>, no_std: bool) -> TokenStream {
+    try_expand_abigen(args, no_std)
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
+
+/// Generates the bindings, reporting failures at the span of the argument
+/// they relate to, e.g. the ABI's literal for a malformed ABI.
+fn try_expand_abigen(
+    args: &Spanned<AbigenArgs>,
+    no_std: bool,
+) -> ParseResult<proc_macro2::TokenStream> {
+    let mut abigens = args
+        .contracts
+        .iter()
+        .map(|contract| {
+            let mut c = contract_abigen(contract)?;
+            if no_std {
+                c = c.no_std();
+            }
+            Ok(c)
+        })
+        .collect::<ParseResult<Vec<_>>>()?;
+
+    if abigens.len() == 1 {
+        let abi = &args.contracts[0].abi;
+        return abigens
+            .remove(0)
+            .expand()
+            .map_err(|e| syn::Error::new(abi.span(), e));
+    }
+
+    MultiAbigen::new(abigens)
+        .and_then(|c| c.expand())
+        .map_err(|e| syn::Error::new(args.span(), e))
+}
+
+fn contract_abigen(args: &ContractArgs) -> ParseResult<Abigen> {
+    let mut c = Abigen::new(&args.name, args.abi.value())
+        .map_err(|e| syn::Error::new(args.abi.span(), e))?;
+
+    // Apply the optional parameters in a fixed order, as `storage` needs `bin`.
+    for key in CONTRACT_OPTIONS {
+        let param = match args.options.get(key) {
+            Some(param) => param,
+            None => continue,
+        };
+        let value = param.value();
+        c = match key {
+            "bin" => c.bin(value),
+            "storage" => c.storage(value),
+            "derives" => c.derives(value.split(',').filter(|d| !d.trim().is_empty())),
+            "serde_rename_all" => c.serde_rename_all(&value),
+            _ => unreachable!("`parse_params` only accepts the contract options"),
+        }
+        .map_err(|e| syn::Error::new(param.span(), e))?;
+    }
+
+    for (abi_type, rust_type) in &args.types {
+        c = c
+            .substitute_type(&abi_type.value(), &quote!(#rust_type).to_string())
+            .map_err(|e| syn::Error::new(abi_type.span(), e))?;
+    }
+
+    Ok(c)
+}
 
-    let c = Abigen::new(&args.name, &args.abi).unwrap().no_std();
+/// Derives `Tokenizable` for a hand-written struct or enum, so that it can be
+/// passed to and returned from contract calls like the types `abigen!` generates.
+///
+/// Structs are encoded as Sway structs, in field declaration order. Enums are
+/// encoded as Sway enums, where the discriminant is the variant's position and
+/// every variant is either a unit or holds exactly one unnamed field. Enums
+/// must also derive `Parameterize`.
+///
+/// Supported attributes:
+/// - `#[fuels(rename = "SwayName")]` on the type or a field sets the name used in
+///   error messages, for when it differs from the Rust one. Fields and variants
+///   are encoded by their position, so their names aren't part of the encoding,
+///   and variants can't be renamed.
+/// - `#[fuels(ignore)]` on a field leaves it out of the encoding. It is filled
+///   in with `Default::default()` when decoding.
+#[proc_macro_derive(Tokenizable, attributes(fuels))]
+pub fn tokenizable(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
 
-    c.expand().unwrap().into()
+    derive::generate_tokenizable_impl(input)
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
+
+/// Derives `Parameterize` for a hand-written struct or enum. See the
+/// `Tokenizable` derive for the supported layouts and attributes.
+#[proc_macro_derive(Parameterize, attributes(fuels))]
+pub fn parameterize(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    derive::generate_parameterize_impl(input)
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
 }
 
 /// Trait that abstracts functionality for inner data that can be parsed and
@@ -70,13 +177,87 @@ impl<T> Deref for Spanned<T> {
     }
 }
 
-/// Contract procedural macro arguments.
-#[cfg_attr(test, derive(Debug, Eq, PartialEq))]
+/// Abigen procedural macro arguments: the contracts to generate bindings for.
+pub(crate) struct AbigenArgs {
+    contracts: Vec<ContractArgs>,
+}
+
+impl ParseInner for AbigenArgs {
+    fn spanned_parse(input: ParseStream) -> ParseResult<(Span, Self)> {
+        // `MyContract, "abi.json", ...`
+        if !input.peek2(token::Paren) {
+            let (span, contract) = ContractArgs::spanned_parse(input)?;
+            return Ok((
+                span,
+                AbigenArgs {
+                    contracts: vec![contract],
+                },
+            ));
+        }
+
+        // `Contract(name = "MyContract", abi = "abi.json", ...), ...`
+        let span = input.span();
+        let mut contracts = vec![];
+        while !input.is_empty() {
+            let keyword = input.parse::<Ident>()?;
+            if keyword != "Contract" {
+                return Err(syn::Error::new(keyword.span(), "expected `Contract(...)`"));
+            }
+
+            let content;
+            parenthesized!(content in input);
+            let keys = [&["name", "abi"], &CONTRACT_OPTIONS[..]].concat();
+            let (mut params, types) = parse_params(&content, &keys)?;
+            let mut required = |key: &str| {
+                params.remove(key).ok_or_else(|| {
+                    syn::Error::new(keyword.span(), format!("missing parameter `{}`", key))
+                })
+            };
+            let name = required("name")?;
+            let abi = required("abi")?;
+            check_storage(&params)?;
+
+            if contracts
+                .iter()
+                .any(|contract: &ContractArgs| contract.name == name.value())
+            {
+                return Err(syn::Error::new(
+                    name.span(),
+                    format!("contract `{}` is given more than once", name.value()),
+                ));
+            }
+            let name = name.value();
+
+            contracts.push(ContractArgs {
+                name,
+                abi,
+                options: params,
+                types,
+            });
+
+            if !input.is_empty() {
+                input.parse::<Token![,]>()?;
+            }
+        }
+
+        Ok((span, AbigenArgs { contracts }))
+    }
+}
+
+/// Contract procedural macro arguments. The literals are kept to report
+/// errors at their span.
 pub(crate) struct ContractArgs {
     name: String,
-    abi: String,
+    abi: LitStr,
+    /// The optional parameters, keyed by one of `CONTRACT_OPTIONS`.
+    options: ParamsMap,
+    types: TypeSubstitutions,
 }
 
+/// The optional parameters of a contract. They're all `key = "value"`, but
+/// for `types = { "struct Name" = path::to::Type, ... }`.
+const CONTRACT_OPTIONS: [&str; 5] = ["bin", "storage", "derives", "serde_rename_all", "types"];
+
 impl ParseInner for ContractArgs {
     fn spanned_parse(input: ParseStream) -> ParseResult<(Span, Self)> {
         // read the contract name
@@ -85,14 +266,106 @@ impl ParseInner for ContractArgs {
         // skip the comma
         input.parse::<Token![,]>()?;
 
-        let (span, abi) = {
-            let literal = input.parse::<LitStr>()?;
-            (literal.span(), literal.value())
-        };
+        let abi = input.parse::<LitStr>()?;
+        let span = abi.span();
+
+        // read the optional `key = "value"` parameters
+        let (mut options, mut types) = (ParamsMap::new(), TypeSubstitutions::new());
         if !input.is_empty() {
             input.parse::<Token![,]>()?;
+            (options, types) = parse_params(input, &CONTRACT_OPTIONS)?;
         }
+        check_storage(&options)?;
 
-        Ok((span, ContractArgs { name, abi }))
+        Ok((
+            span,
+            ContractArgs {
+                name,
+                abi,
+                options,
+                types,
+            },
+        ))
     }
 }
+
+type ParamsMap = HashMap<String, LitStr>;
+
+/// Custom types of the ABI, e.g. `struct Identity`, along with the Rust types
+/// replacing them.
+type TypeSubstitutions = Vec<(LitStr, syn::Path)>;
+
+/// Parses comma separated `key = "value"` parameters, up to the end of
+/// `input`. Each key must be one of `keys`, and be given at most once. The
+/// `types` parameter, holding type substitutions, is returned on its own.
+fn parse_params(input: ParseStream, keys: &[&str]) -> ParseResult<(ParamsMap, TypeSubstitutions)> {
+    let mut params = ParamsMap::new();
+    let mut types = None;
+
+    while !input.is_empty() {
+        let key = input.parse::<Ident>()?;
+        input.parse::<Token![=]>()?;
+
+        if key == "types" && keys.contains(&"types") {
+            if types.replace(parse_type_substitutions(input)?).is_some() {
+                return Err(syn::Error::new(key.span(), "duplicate parameter"));
+            }
+            if !input.is_empty() {
+                input.parse::<Token![,]>()?;
+            }
+            continue;
+        }
+        let value = input.parse::<LitStr>()?;
+
+        if !keys.iter().any(|k| key == k) {
+            return Err(syn::Error::new(
+                key.span(),
+                format!(
+                    "unknown parameter `{}`, expected one of: {}",
+                    key,
+                    keys.join(", ")
+                ),
+            ));
+        }
+        if params.insert(key.to_string(), value).is_some() {
+            return Err(syn::Error::new(key.span(), "duplicate parameter"));
+        }
+
+        if !input.is_empty() {
+            input.parse::<Token![,]>()?;
+        }
+    }
+
+    Ok((params, types.unwrap_or_default()))
+}
+
+/// Parses `{ "struct Name" = path::to::Type, ... }`.
+fn parse_type_substitutions(input: ParseStream) -> ParseResult<TypeSubstitutions> {
+    let content;
+    braced!(content in input);
+
+    let mut types = TypeSubstitutions::new();
+    while !content.is_empty() {
+        let abi_type = content.parse::<LitStr>()?;
+        content.parse::<Token![=]>()?;
+        let rust_type = content.parse::<syn::Path>()?;
+        types.push((abi_type, rust_type));
+
+        if !content.is_empty() {
+            content.parse::<Token![,]>()?;
+        }
+    }
+
+    Ok(types)
+}
+
+fn check_storage(params: &ParamsMap) -> ParseResult<()> {
+    if let (None, Some(storage)) = (params.get("bin"), params.get("storage")) {
+        return Err(syn::Error::new(
+            storage.span(),
+            "`storage` can only be set along with the contract's `bin`",
+        ));
+    }
+
+    Ok(())
+}
@@ -1,15 +1,19 @@
 use fuel_gql_client::fuel_tx::{AssetId, ContractId, Receipt};
-use fuels::contract::contract::MultiContractCallHandler;
+use fuels::contract::contract::{
+    ContractCallHandler, MultiContractCallHandler, TypedMultiCallHandler,
+};
+use fuels::core::try_from_bytes;
 use fuels::prelude::Error::TransactionError;
 use fuels::prelude::{
-    abigen, launch_provider_and_get_wallet, setup_multiple_assets_coins, setup_single_asset_coins,
-    setup_test_provider, CallParameters, Contract, Error, LocalWallet, Provider, ProviderError,
-    Salt, Signer, TxParameters, DEFAULT_COIN_AMOUNT, DEFAULT_NUM_COINS,
+    abigen, launch_custom_provider_and_get_wallets, launch_provider_and_get_wallet,
+    setup_multiple_assets_coins, setup_single_asset_coins, setup_test_provider, CallParameters,
+    Contract, Error, LocalWallet, Parameterize, Provider, ProviderError, Salt, Tokenizable,
+    TxParameters, WalletsConfig, DEFAULT_COIN_AMOUNT, DEFAULT_NUM_COINS,
 };
 use fuels::test_helpers::produce_blocks;
+use fuels::types::param_types::{EnumVariants, ParamType};
 use fuels_core::parameters::StorageConfiguration;
 use fuels_core::tx::{Address, Bytes32, StorageSlot};
-use fuels_core::Tokenizable;
 use fuels_core::{constants::BASE_ASSET_ID, Token};
 use sha2::{Digest, Sha256};
 use std::str::FromStr;
@@ -32,6 +36,45 @@ fn null_contract_id() -> String {
     String::from("0000000000000000000000000000000000000000000000000000000000000000")
 }
 
+// Bindings generated at module scope, where the items of both modules are
+// re-exported side by side, so that their ABI metadata can't collide.
+abigen!(
+    Thermometer,
+    r#"[{"type":"function","name":"read","inputs":[{"name":"unit","type":"u8"}],"outputs":[{"name":"","type":"u64"}]}]"#
+);
+abigen!(
+    Barometer,
+    r#"[{"type":"function","name":"read","inputs":[{"name":"unit","type":"u16"}],"outputs":[{"name":"","type":"u64"}]}]"#
+);
+
+#[test]
+fn abi_metadata_of_bindings_at_module_scope() -> Result<(), Error> {
+    assert!(Thermometer::ABI.contains("\"u8\""));
+    assert!(Barometer::ABI.contains("\"u16\""));
+
+    assert_eq!(
+        Thermometer::methods().collect::<Vec<_>>(),
+        vec![("read", "read(u8)", Thermometer::READ_SELECTOR)]
+    );
+    assert_eq!(
+        Barometer::methods().collect::<Vec<_>>(),
+        vec![("read", "read(u16)", Barometer::READ_SELECTOR)]
+    );
+    assert_ne!(Thermometer::READ_SELECTOR, Barometer::READ_SELECTOR);
+
+    let data = [0, 0, 0, 0, 0, 0, 0, 1];
+    assert_eq!(
+        Thermometer::decode_call(Thermometer::READ_SELECTOR, &data)?,
+        ThermometerCalls::Read { unit: 1 }
+    );
+    assert_eq!(
+        Barometer::decode_call(Barometer::READ_SELECTOR, &data)?,
+        BarometerCalls::Read { unit: 1 }
+    );
+    assert!(Barometer::decode_call(Thermometer::READ_SELECTOR, &data).is_err());
+    Ok(())
+}
+
 #[tokio::test]
 async fn compile_bindings_from_contract_file() {
     // Generates the bindings from an ABI definition in a JSON file
@@ -44,7 +87,7 @@ async fn compile_bindings_from_contract_file() {
     let wallet = launch_provider_and_get_wallet().await;
 
     // `SimpleContract` is the name of the contract
-    let contract_instance = SimpleContract::new(null_contract_id(), wallet);
+    let contract_instance = SimpleContract::new(null_contract_id(), wallet).unwrap();
 
     let call_handler = contract_instance.takes_ints_returns_bool(42);
 
@@ -88,7 +131,7 @@ async fn compile_bindings_from_inline_contract() -> Result<(), Error> {
 
     let wallet = launch_provider_and_get_wallet().await;
     //`SimpleContract` is the name of the contract
-    let contract_instance = SimpleContract::new(null_contract_id(), wallet);
+    let contract_instance = SimpleContract::new(null_contract_id(), wallet)?;
 
     let call_handler = contract_instance.takes_ints_returns_bool(42_u32);
 
@@ -103,6 +146,105 @@ async fn compile_bindings_from_inline_contract() -> Result<(), Error> {
     Ok(())
 }
 
+// Generated at module scope, where the items of both contracts' modules are
+// re-exported side by side.
+abigen!(
+    Contract(
+        name = "Shop",
+        abi = r#"
+        [
+            {
+                "type": "function",
+                "name": "place",
+                "inputs": [
+                    {
+                        "name": "order",
+                        "type": "struct Order",
+                        "components": [
+                            { "name": "id", "type": "u64" },
+                            { "name": "amount", "type": "u32" }
+                        ]
+                    }
+                ],
+                "outputs": []
+            }
+        ]
+        "#
+    ),
+    Contract(
+        name = "Ledger",
+        abi = r#"
+        [
+            {
+                "type": "function",
+                "name": "record",
+                "inputs": [
+                    {
+                        "name": "order",
+                        "type": "struct Order",
+                        "components": [
+                            { "name": "id", "type": "u64" },
+                            { "name": "amount", "type": "u32" }
+                        ]
+                    }
+                ],
+                "outputs": [
+                    {
+                        "name": "",
+                        "type": "struct Order",
+                        "components": [
+                            { "name": "id", "type": "u64" },
+                            { "name": "amount", "type": "u32" }
+                        ]
+                    }
+                ]
+            }
+        ]
+        "#
+    ),
+);
+
+#[tokio::test]
+async fn compile_bindings_of_several_contracts_sharing_a_type() -> Result<(), Error> {
+    let wallet = launch_provider_and_get_wallet().await;
+    let shop = Shop::new(null_contract_id(), wallet.clone())?;
+    let ledger = Ledger::new(null_contract_id(), wallet)?;
+
+    // Both bindings use the single `Order` type of the shared module, so the same
+    // value can be passed to both of them, and returned by one and passed to the other.
+    let order = Order { id: 1, amount: 2 };
+    let shop_call = shop.place(order.clone());
+    let ledger_call: ContractCallHandler<Order> = ledger.record(order);
+
+    let expected_args = "00000000000000010000000000000002";
+    assert_eq!(
+        hex::encode(shop_call.contract_call.encoded_selector),
+        "00000000de776ad4"
+    );
+    assert_eq!(
+        hex::encode(ledger_call.contract_call.encoded_selector),
+        "00000000875c1b12"
+    );
+    assert_eq!(
+        hex::encode(shop_call.contract_call.encoded_args),
+        expected_args
+    );
+    assert_eq!(
+        hex::encode(ledger_call.contract_call.encoded_args),
+        expected_args
+    );
+
+    // The metadata of each contract is available unqualified
+    assert_eq!(hex::encode(Shop::PLACE_SELECTOR), "00000000de776ad4");
+    assert_eq!(
+        Ledger::decode_call(Ledger::RECORD_SELECTOR, &hex::decode(expected_args)?)?,
+        LedgerCalls::Record {
+            order: Order { id: 1, amount: 2 }
+        }
+    );
+    Ok(())
+}
+
 #[tokio::test]
 async fn compile_bindings_array_input() {
     // Generates the bindings from the an ABI definition inline.
@@ -131,7 +273,7 @@ async fn compile_bindings_array_input() {
     let wallet = launch_provider_and_get_wallet().await;
 
     // `SimpleContract` is the name of the contract
-    let contract_instance = SimpleContract::new(null_contract_id(), wallet);
+    let contract_instance = SimpleContract::new(null_contract_id(), wallet).unwrap();
 
     let input: Vec<u16> = vec![1, 2, 3, 4];
     let call_handler = contract_instance.takes_array(input);
@@ -176,7 +318,7 @@ async fn compile_bindings_bool_array_input() {
     let wallet = launch_provider_and_get_wallet().await;
 
     // `SimpleContract` is the name of the contract
-    let contract_instance = SimpleContract::new(null_contract_id(), wallet);
+    let contract_instance = SimpleContract::new(null_contract_id(), wallet).unwrap();
 
     let input: Vec<bool> = vec![true, false, true];
     let call_handler = contract_instance.takes_array(input);
@@ -221,7 +363,7 @@ async fn compile_bindings_byte_input() {
     let wallet = launch_provider_and_get_wallet().await;
 
     // `SimpleContract` is the name of the contract
-    let contract_instance = SimpleContract::new(null_contract_id(), wallet);
+    let contract_instance = SimpleContract::new(null_contract_id(), wallet).unwrap();
 
     let call_handler = contract_instance.takes_byte(10u8);
 
@@ -262,7 +404,7 @@ async fn compile_bindings_string_input() {
     let wallet = launch_provider_and_get_wallet().await;
 
     // `SimpleContract` is the name of the contract
-    let contract_instance = SimpleContract::new(null_contract_id(), wallet);
+    let contract_instance = SimpleContract::new(null_contract_id(), wallet).unwrap();
 
     let call_handler = contract_instance.takes_string("This is a full sentence".into());
 
@@ -306,7 +448,7 @@ async fn compile_bindings_b256_input() {
     let wallet = launch_provider_and_get_wallet().await;
 
     // `SimpleContract` is the name of the contract
-    let contract_instance = SimpleContract::new(null_contract_id(), wallet);
+    let contract_instance = SimpleContract::new(null_contract_id(), wallet).unwrap();
 
     let mut hasher = Sha256::new();
     hasher.update("test string".as_bytes());
@@ -369,7 +511,7 @@ async fn compile_bindings_struct_input() {
     let wallet = launch_provider_and_get_wallet().await;
 
     // `SimpleContract` is the name of the contract
-    let contract_instance = SimpleContract::new(null_contract_id(), wallet);
+    let contract_instance = SimpleContract::new(null_contract_id(), wallet).unwrap();
 
     let call_handler = contract_instance.takes_struct(input);
 
@@ -434,7 +576,7 @@ async fn compile_bindings_nested_struct_input() {
     let wallet = launch_provider_and_get_wallet().await;
 
     // `SimpleContract` is the name of the contract
-    let contract_instance = SimpleContract::new(null_contract_id(), wallet);
+    let contract_instance = SimpleContract::new(null_contract_id(), wallet).unwrap();
 
     let call_handler = contract_instance.takes_nested_struct(input);
 
@@ -485,7 +627,7 @@ async fn compile_bindings_enum_input() {
     let wallet = launch_provider_and_get_wallet().await;
 
     // `SimpleContract` is the name of the contract
-    let contract_instance = SimpleContract::new(null_contract_id(), wallet);
+    let contract_instance = SimpleContract::new(null_contract_id(), wallet).unwrap();
 
     let call_handler = contract_instance.takes_enum(variant);
 
@@ -546,7 +688,7 @@ async fn create_struct_from_decoded_tokens() -> Result<(), Error> {
     let wallet = launch_provider_and_get_wallet().await;
 
     // `SimpleContract` is the name of the contract
-    let contract_instance = SimpleContract::new(null_contract_id(), wallet);
+    let contract_instance = SimpleContract::new(null_contract_id(), wallet)?;
 
     let call_handler = contract_instance.takes_struct(struct_from_tokens);
 
@@ -620,7 +762,7 @@ async fn create_nested_struct_from_decoded_tokens() -> Result<(), Error> {
     let wallet = launch_provider_and_get_wallet().await;
 
     // `SimpleContract` is the name of the contract
-    let contract_instance = SimpleContract::new(null_contract_id(), wallet);
+    let contract_instance = SimpleContract::new(null_contract_id(), wallet)?;
 
     let call_handler = contract_instance.takes_nested_struct(nested_struct_from_tokens);
 
@@ -654,7 +796,7 @@ async fn type_safe_output_values() -> Result<(), Error> {
     .await?;
     println!("Contract deployed @ {:x}", contract_id);
 
-    let contract_instance = MyContract::new(contract_id.to_string(), wallet);
+    let contract_instance = MyContract::new(contract_id.to_string(), wallet)?;
 
     // `response`'s type matches the return type of `is_event()`
     let response = contract_instance.is_even(10).call().await?;
@@ -706,7 +848,7 @@ async fn call_with_structs() -> Result<(), Error> {
     .await?;
     println!("Contract deployed @ {:x}", contract_id);
 
-    let contract_instance = MyContract::new(contract_id.to_string(), wallet);
+    let contract_instance = MyContract::new(contract_id.to_string(), wallet)?;
 
     let response = contract_instance
         .initialize_counter(counter_config) // Build the ABI call
@@ -741,7 +883,7 @@ async fn call_with_empty_return() -> Result<(), Error> {
     .await?;
     println!("Contract deployed @ {:x}", contract_id);
 
-    let contract_instance = MyContract::new(contract_id.to_string(), wallet);
+    let contract_instance = MyContract::new(contract_id.to_string(), wallet)?;
 
     let _response = contract_instance
         .store_value(42) // Build the ABI call
@@ -768,7 +910,7 @@ async fn abigen_different_structs_same_arg_name() -> Result<(), Error> {
     .await?;
     println!("Contract deployed @ {:x}", contract_id);
 
-    let contract_instance = MyContract::new(contract_id.to_string(), wallet);
+    let contract_instance = MyContract::new(contract_id.to_string(), wallet)?;
 
     let param_one = StructOne { foo: 42 };
     let param_two = StructTwo { bar: 42 };
@@ -800,7 +942,7 @@ async fn test_reverting_transaction() -> Result<(), Error> {
 
     )
         .await?;
-    let contract_instance = RevertingContract::new(contract_id.to_string(), wallet);
+    let contract_instance = RevertingContract::new(contract_id.to_string(), wallet)?;
     println!("Contract deployed @ {:x}", contract_id);
     let response = contract_instance.make_transaction_fail(0).call().await;
     assert!(matches!(response, Err(Error::ContractCallError(..))));
@@ -824,7 +966,7 @@ async fn multiple_read_calls() -> Result<(), Error> {
     )
     .await?;
     println!("Contract deployed @ {:x}", contract_id);
-    let contract_instance = MyContract::new(contract_id.to_string(), wallet);
+    let contract_instance = MyContract::new(contract_id.to_string(), wallet)?;
 
     contract_instance.store(42).call().await?;
 
@@ -861,7 +1003,7 @@ async fn test_methods_typeless_argument() -> Result<(), Error> {
     .await?;
     println!("Contract deployed @ {:x}", contract_id);
 
-    let contract_instance = MyContract::new(contract_id.to_string(), wallet);
+    let contract_instance = MyContract::new(contract_id.to_string(), wallet)?;
 
     let response = contract_instance
         .method_with_empty_argument()
@@ -889,7 +1031,7 @@ async fn test_large_return_data() -> Result<(), Error> {
     .await?;
     println!("Contract deployed @ {:x}", contract_id);
 
-    let contract_instance = MyContract::new(contract_id.to_string(), wallet);
+    let contract_instance = MyContract::new(contract_id.to_string(), wallet)?;
 
     let res = contract_instance.get_id().call().await?;
 
@@ -961,7 +1103,7 @@ async fn test_provider_launch_and_connect() -> Result<(), Error> {
     .await?;
     println!("Contract deployed @ {:x}", contract_id);
 
-    let contract_instance_connected = MyContract::new(contract_id.to_string(), wallet.clone());
+    let contract_instance_connected = MyContract::new(contract_id.to_string(), wallet.clone())?;
 
     let response = contract_instance_connected
         .initialize_counter(42) // Build the ABI call
@@ -970,7 +1112,7 @@ async fn test_provider_launch_and_connect() -> Result<(), Error> {
     assert_eq!(42, response.value);
 
     wallet.set_provider(launched_provider);
-    let contract_instance_launched = MyContract::new(contract_id.to_string(), wallet);
+    let contract_instance_launched = MyContract::new(contract_id.to_string(), wallet)?;
 
     let response = contract_instance_launched
         .increment_counter(10)
@@ -980,6 +1122,82 @@ async fn test_provider_launch_and_connect() -> Result<(), Error> {
     Ok(())
 }
 
+#[tokio::test]
+async fn contract_instance_can_switch_wallets() -> Result<(), Error> {
+    abigen!(
+        MyContract,
+        "packages/fuels-abigen-macro/tests/test_projects/contract_test/out/debug/contract_test-abi.json"
+    );
+
+    let wallets = launch_custom_provider_and_get_wallets(
+        WalletsConfig::new(Some(2), Some(1), Some(1_000_000)),
+        None,
+    )
+    .await;
+
+    let contract_id = Contract::deploy(
+        "tests/test_projects/contract_test/out/debug/contract_test.bin",
+        &wallets[0],
+        TxParameters::default(),
+        StorageConfiguration::default(),
+    )
+    .await?;
+
+    let no_provider = MyContract::new(contract_id.to_string(), LocalWallet::new_random(None));
+    assert!(matches!(no_provider, Err(Error::WalletError(_))));
+
+    let instance = MyContract::new(contract_id.to_string(), wallets[0].clone())?;
+    let response = instance.initialize_counter(42).call().await?;
+    assert_eq!(42, response.value);
+
+    let second_caller = instance.with_wallet(wallets[1].clone());
+    let response = second_caller.increment_counter(10).call().await?;
+    assert_eq!(52, response.value);
+
+    let response = second_caller.clone().increment_counter(1).call().await?;
+    assert_eq!(53, response.value);
+    Ok(())
+}
+
+#[tokio::test]
+async fn read_only_calls_need_no_wallet() -> Result<(), Error> {
+    abigen!(
+        MyContract,
+        "packages/fuels-abigen-macro/tests/test_projects/contract_test/out/debug/contract_test-abi.json"
+    );
+
+    let wallet = launch_provider_and_get_wallet().await;
+    let contract_id = Contract::deploy(
+        "tests/test_projects/contract_test/out/debug/contract_test.bin",
+        &wallet,
+        TxParameters::default(),
+        StorageConfiguration::default(),
+    )
+    .await?;
+    MyContract::new(contract_id.to_string(), wallet.clone())?
+        .initialize_counter(42)
+        .call()
+        .await?;
+
+    let provider = wallet.get_provider()?.clone();
+    let reader = MyContract::new_read_only(contract_id.to_string(), provider.clone())?;
+
+    let response = reader.get_counter().read().await?;
+    assert_eq!(42, response.value);
+
+    let response = reader.get(5, 6).read().await?;
+    assert_eq!(5, response.value);
+
+    // A wallet without coins can't pay for a simulation, but can read
+    let unfunded = reader.with_wallet(LocalWallet::new_random(Some(provider)));
+    let response = unfunded.get_counter().read().await?;
+    assert_eq!(42, response.value);
+
+    let result = reader.increment_counter(1).call().await;
+    assert!(matches!(result, Err(Error::WalletError(_))));
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_contract_calling_contract() -> Result<(), Error> {
     // Tests a contract call that calls another contract (FooCaller calls FooContract underneath)
@@ -1005,7 +1223,7 @@ async fn test_contract_calling_contract() -> Result<(), Error> {
     .await?;
     println!("Foo contract deployed @ {:x}", foo_contract_id);
 
-    let foo_contract_instance = FooContract::new(foo_contract_id.to_string(), wallet.clone());
+    let foo_contract_instance = FooContract::new(foo_contract_id.to_string(), wallet.clone())?;
 
     // Call the contract directly; it just flips the bool value that's passed.
     let res = foo_contract_instance.foo(true).call().await?;
@@ -1025,7 +1243,7 @@ async fn test_contract_calling_contract() -> Result<(), Error> {
     );
 
     let foo_caller_contract_instance =
-        FooCaller::new(foo_caller_contract_id.to_string(), wallet.clone());
+        FooCaller::new(foo_caller_contract_id.to_string(), wallet.clone())?;
 
     // Calls the contract that calls the `FooContract` contract, also just
     // flips the bool value passed to it.
@@ -1060,7 +1278,7 @@ async fn test_gas_errors() -> Result<(), Error> {
     )
     .await?;
 
-    let contract_instance = MyContract::new(contract_id.to_string(), wallet);
+    let contract_instance = MyContract::new(contract_id.to_string(), wallet)?;
 
     // Test for insufficient gas.
     let response = contract_instance
@@ -1110,7 +1328,7 @@ async fn test_call_param_gas_errors() -> Result<(), Error> {
     )
     .await?;
 
-    let contract_instance = MyContract::new(contract_id.to_string(), wallet);
+    let contract_instance = MyContract::new(contract_id.to_string(), wallet)?;
 
     // Transaction gas_limit is sufficient, call gas_forwarded is too small
     let response = contract_instance
@@ -1133,8 +1351,8 @@ async fn test_call_param_gas_errors() -> Result<(), Error> {
         .await
         .expect_err("should error");
 
-    let expected = "Contract call error: OutOfGas, receipts:";
-    assert!(response.to_string().starts_with(expected));
+    let expected = "Transaction error: the calls forward 1000 gas in total, more than the transaction's gas limit of 1";
+    assert_eq!(response.to_string(), expected);
     Ok(())
 }
 
@@ -1155,7 +1373,7 @@ async fn test_amount_and_asset_forwarding() -> Result<(), Error> {
     )
     .await?;
 
-    let instance = TestFuelCoinContract::new(id.to_string(), wallet.clone());
+    let instance = TestFuelCoinContract::new(id.to_string(), wallet.clone())?;
 
     let mut balance_response = instance.get_balance(id, id).call().await?;
     assert_eq!(balance_response.value, 0);
@@ -1242,7 +1460,7 @@ async fn test_multiple_args() -> Result<(), Error> {
     )
     .await?;
 
-    let instance = MyContract::new(id.to_string(), wallet.clone());
+    let instance = MyContract::new(id.to_string(), wallet.clone())?;
 
     // Make sure we can call the contract with multiple arguments
     let response = instance.get(5, 6).call().await?;
@@ -1275,7 +1493,7 @@ async fn test_tuples() -> Result<(), Error> {
     )
     .await?;
 
-    let instance = MyContract::new(id.to_string(), wallet.clone());
+    let instance = MyContract::new(id.to_string(), wallet.clone())?;
 
     let response = instance.returns_tuple((1, 2)).call().await?;
 
@@ -1332,7 +1550,7 @@ async fn test_array() -> Result<(), Error> {
     .await?;
 
     println!("Contract deployed @ {:x}", contract_id);
-    let contract_instance = MyContract::new(contract_id.to_string(), wallet);
+    let contract_instance = MyContract::new(contract_id.to_string(), wallet)?;
 
     assert_eq!(
         contract_instance
@@ -1365,7 +1583,7 @@ async fn test_arrays_with_custom_types() -> Result<(), Error> {
     .await?;
 
     println!("Contract deployed @ {:x}", contract_id);
-    let contract_instance = MyContract::new(contract_id.to_string(), wallet);
+    let contract_instance = MyContract::new(contract_id.to_string(), wallet)?;
 
     let persons = vec![
         Person {
@@ -1410,7 +1628,7 @@ async fn test_auth_msg_sender_from_sdk() -> Result<(), Error> {
     )
     .await?;
 
-    let auth_instance = AuthContract::new(id.to_string(), wallet.clone());
+    let auth_instance = AuthContract::new(id.to_string(), wallet.clone())?;
 
     // Contract returns true if `msg_sender()` matches `wallet.address()`.
     let response = auth_instance
@@ -1439,7 +1657,7 @@ async fn workflow_enum_inside_struct() -> Result<(), Error> {
         StorageConfiguration::default(),
     )
     .await?;
-    let instance = MyContract::new(id.to_string(), wallet.clone());
+    let instance = MyContract::new(id.to_string(), wallet.clone())?;
     let response = instance.return_enum_inside_struct(11).call().await?;
     let expected = Cocktail {
         the_thing_you_mix_in: Shaker::Mojito(222),
@@ -1474,7 +1692,7 @@ async fn test_logd_receipts() -> Result<(), Error> {
         StorageConfiguration::default(),
     )
     .await?;
-    let contract_instance = LoggingContract::new(id.to_string(), wallet.clone());
+    let contract_instance = LoggingContract::new(id.to_string(), wallet.clone())?;
     let mut value = [0u8; 32];
     value[0] = 0xFF;
     value[1] = 0xEE;
@@ -1577,7 +1795,7 @@ async fn sway_native_types_support() -> Result<(), Box<dyn std::error::Error>> {
     )
     .await?;
 
-    let instance = MyContract::new(id.to_string(), wallet.clone());
+    let instance = MyContract::new(id.to_string(), wallet.clone())?;
 
     let user = User {
         weight: 10,
@@ -1614,11 +1832,11 @@ async fn test_transaction_script_workflow() -> Result<(), Error> {
     )
     .await?;
 
-    let contract_instance = MyContract::new(contract_id.to_string(), wallet.clone());
+    let contract_instance = MyContract::new(contract_id.to_string(), wallet.clone())?;
 
     let call_handler = contract_instance.initialize_counter(42);
 
-    let script = call_handler.get_script().await;
+    let script = call_handler.get_script().await?;
     assert!(script.tx.is_script());
 
     let receipts = script.call(client).await?;
@@ -1646,7 +1864,7 @@ async fn enum_coding_w_variable_width_variants() -> Result<(), Error> {
     )
     .await?;
 
-    let instance = EnumTesting::new(id.to_string(), wallet);
+    let instance = EnumTesting::new(id.to_string(), wallet)?;
 
     // If we had a regression on the issue of enum encoding width, then we'll
     // probably end up mangling arg_2 and onward which will fail this test.
@@ -1690,7 +1908,7 @@ async fn enum_coding_w_unit_enums() -> Result<(), Error> {
     )
     .await?;
 
-    let instance = EnumTesting::new(id.to_string(), wallet);
+    let instance = EnumTesting::new(id.to_string(), wallet)?;
 
     // If we had a regression on the issue of unit enum encoding width, then
     // we'll end up mangling arg_2
@@ -1732,7 +1950,7 @@ async fn enum_as_input() -> Result<(), Error> {
     )
     .await?;
 
-    let instance = EnumTesting::new(id.to_string(), wallet);
+    let instance = EnumTesting::new(id.to_string(), wallet)?;
 
     let expected = StandardEnum::Two(12345);
     let actual = instance.get_standard_enum().call().await?.value;
@@ -1782,7 +2000,7 @@ async fn nested_structs() -> Result<(), Error> {
     )
     .await?;
 
-    let instance = NestedStructs::new(id.to_string(), wallet);
+    let instance = NestedStructs::new(id.to_string(), wallet)?;
 
     let expected = AllStruct {
         some_struct: SomeStruct { par_1: 12345 },
@@ -1842,7 +2060,7 @@ async fn test_multi_call() -> Result<(), Error> {
     )
     .await?;
 
-    let contract_instance = MyContract::new(contract_id.to_string(), wallet.clone());
+    let contract_instance = MyContract::new(contract_id.to_string(), wallet.clone())?;
 
     let call_handler_1 = contract_instance.initialize_counter(42);
     let call_handler_2 = contract_instance.get_array([42; 2].to_vec());
@@ -1861,6 +2079,113 @@ async fn test_multi_call() -> Result<(), Error> {
     Ok(())
 }
 
+#[tokio::test]
+async fn test_multi_call_forwards_amounts_of_all_calls() -> Result<(), Error> {
+    abigen!(
+        TestFuelCoinContract,
+        "packages/fuels-abigen-macro/tests/test_projects/token_ops/out/debug/token_ops-abi.json"
+    );
+
+    let wallet = launch_custom_provider_and_get_wallets(
+        WalletsConfig::new_single(Some(4), Some(1_000_000)),
+        None,
+    )
+    .await
+    .pop()
+    .unwrap();
+
+    let id = Contract::deploy(
+        "tests/test_projects/token_ops/out/debug/token_ops.bin",
+        &wallet,
+        TxParameters::default(),
+        StorageConfiguration::default(),
+    )
+    .await?;
+
+    let instance = TestFuelCoinContract::new(id.to_string(), wallet.clone())?;
+
+    // Together, the calls forward more than any single coin of the wallet holds
+    let call_handler_1 = instance.get_msg_amount().call_params(CallParameters::new(
+        Some(1_500_000),
+        None,
+        Some(500_000),
+    ));
+    let call_handler_2 =
+        instance
+            .get_msg_amount()
+            .call_params(CallParameters::new(Some(1_500_000), None, None));
+
+    let mut multi_call_handler = MultiContractCallHandler::new(wallet.clone());
+    multi_call_handler
+        .add_call(call_handler_1)
+        .add_call(call_handler_2);
+
+    let (amount_1, amount_2): (u64, u64) = multi_call_handler.call().await?.value;
+
+    assert_eq!(amount_1, 1_500_000);
+    assert_eq!(amount_2, 1_500_000);
+
+    // The gas forwarded by the calls can't add up to more than the transaction's gas limit
+    let call_handler =
+        instance
+            .get_msg_amount()
+            .call_params(CallParameters::new(None, None, Some(600_000)));
+    multi_call_handler
+        .add_call(call_handler)
+        .tx_params(TxParameters::new(None, Some(1_000_000), None, None));
+
+    let error = multi_call_handler
+        .call::<(u64, u64, u64)>()
+        .await
+        .expect_err("should error");
+
+    let expected = "Transaction error: the calls forward 1100000 gas in total, more than the transaction's gas limit of 1000000";
+    assert_eq!(error.to_string(), expected);
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn test_typed_multi_call() -> Result<(), Error> {
+    abigen!(
+        MyContract,
+        "packages/fuels-abigen-macro/tests/test_projects/contract_test/out/debug/contract_test-abi.json"
+    );
+
+    let wallet = launch_provider_and_get_wallet().await;
+
+    let contract_id = Contract::deploy(
+        "tests/test_projects/contract_test/out/debug/contract_test.bin",
+        &wallet,
+        TxParameters::default(),
+        StorageConfiguration::default(),
+    )
+    .await?;
+
+    let contract_instance = MyContract::new(contract_id.to_string(), wallet.clone())?;
+
+    let (counter, array, incremented) = TypedMultiCallHandler::new(wallet.clone())
+        .add_call(contract_instance.initialize_counter(42))
+        .add_call(contract_instance.get_array([7; 2].to_vec()))
+        .add_call(contract_instance.increment_counter(10))
+        .call()
+        .await?
+        .value;
+
+    assert_eq!(counter, 42);
+    assert_eq!(array, [7; 2]);
+    assert_eq!(incremented, 52);
+
+    let (counter,) = TypedMultiCallHandler::new(wallet.clone())
+        .add_call(contract_instance.get_counter())
+        .read()
+        .await?
+        .value;
+    assert_eq!(counter, 52);
+
+    Ok(())
+}
+
 #[tokio::test]
 async fn test_multi_call_script_workflow() -> Result<(), Error> {
     abigen!(
@@ -1879,7 +2204,7 @@ async fn test_multi_call_script_workflow() -> Result<(), Error> {
     )
     .await?;
 
-    let contract_instance = MyContract::new(contract_id.to_string(), wallet.clone());
+    let contract_instance = MyContract::new(contract_id.to_string(), wallet.clone())?;
 
     let call_handler_1 = contract_instance.initialize_counter(42);
     let call_handler_2 = contract_instance.get_array([42; 2].to_vec());
@@ -1890,7 +2215,7 @@ async fn test_multi_call_script_workflow() -> Result<(), Error> {
         .add_call(call_handler_1)
         .add_call(call_handler_2);
 
-    let script = multi_call_handler.get_script().await;
+    let script = multi_call_handler.get_script().await?;
     let receipts = script.call(client).await.unwrap();
     let (counter, array) = multi_call_handler
         .get_response::<(u64, Vec<u64>)>(receipts)?
@@ -1929,7 +2254,7 @@ async fn test_storage_initialization() -> Result<(), Error> {
     .await?;
     // ANCHOR_END: manual_storage
 
-    let contract_instance = MyContract::new(contract_id.to_string(), wallet.clone());
+    let contract_instance = MyContract::new(contract_id.to_string(), wallet.clone())?;
 
     let result = contract_instance
         .get_value_b256(key.into())
@@ -2019,7 +2344,7 @@ async fn type_inside_enum() -> Result<(), Error> {
     )
     .await?;
 
-    let instance = MyContract::new(id.to_string(), wallet.clone());
+    let instance = MyContract::new(id.to_string(), wallet.clone())?;
 
     // String inside enum
     let enum_string = SomeEnum::SomeStr("asdf".to_owned());
@@ -2089,7 +2414,7 @@ async fn test_init_storage_automatically() -> Result<(), Error> {
         Bytes32::from_str("f383b0ce51358be57daa3b725fe44acdb2d880604e367199080b4379c41bb6ed")
             .unwrap();
 
-    let contract_instance = MyContract::new(contract_id.to_string(), wallet.clone());
+    let contract_instance = MyContract::new(contract_id.to_string(), wallet.clone())?;
 
     let value = contract_instance.get_value_b256(*key1).call().await?.value;
     assert_eq!(value, [1u8; 32]);
@@ -2141,7 +2466,7 @@ async fn contract_method_call_respects_maturity() -> anyhow::Result<()> {
     )
     .await?;
 
-    let instance = MyContract::new(id.to_string(), wallet.clone());
+    let instance = MyContract::new(id.to_string(), wallet.clone())?;
 
     let call_w_maturity = |call_maturity| {
         let mut prepared_call = instance.calling_this_will_produce_a_block();
@@ -2221,7 +2546,7 @@ async fn gql_height_info_is_correct() -> anyhow::Result<()> {
         StorageConfiguration::default(),
     )
     .await?;
-    let instance = MyContract::new(id.to_string(), wallet.clone());
+    let instance = MyContract::new(id.to_string(), wallet.clone())?;
 
     let block_height_from_contract = || async {
         Ok(instance.get_current_height().simulate().await?.value) as Result<u64, Error>
@@ -2236,3 +2561,68 @@ async fn gql_height_info_is_correct() -> anyhow::Result<()> {
     assert_eq!(block_height_from_contract().await?, 4);
     Ok(())
 }
+
+#[test]
+fn can_derive_tokenizable_and_parameterize() -> Result<(), Error> {
+    #[derive(Debug, PartialEq, Tokenizable, Parameterize)]
+    #[fuels(rename = "SwayOrder")]
+    struct Order {
+        id: u64,
+        #[fuels(rename = "is_active")]
+        active: bool,
+        #[fuels(ignore)]
+        local_note: String,
+        kind: Kind<u32>,
+    }
+
+    #[derive(Debug, PartialEq, Tokenizable, Parameterize)]
+    enum Kind<T> {
+        Market,
+        Limit(T),
+        Stop(),
+    }
+
+    #[derive(Debug, PartialEq, Tokenizable, Parameterize)]
+    struct Wrapper(u8, (u16, bool));
+
+    let expected_kind = ParamType::Enum(EnumVariants::new(vec![
+        ParamType::Unit,
+        ParamType::U32,
+        ParamType::Unit,
+    ])?);
+    assert_eq!(
+        Order::param_type(),
+        ParamType::Struct(vec![ParamType::U64, ParamType::Bool, expected_kind])
+    );
+
+    let order = Order {
+        id: 1,
+        active: true,
+        local_note: "not sent".to_string(),
+        kind: Kind::Limit(10),
+    };
+    let token = order.into_token();
+    let decoded = Order::from_token(token)?;
+    assert_eq!(decoded.kind, Kind::Limit(10));
+    assert_eq!(decoded.local_note, "");
+
+    let bytes = [
+        vec![0u8; 7],
+        vec![5],
+        vec![0; 7],
+        vec![7],
+        vec![0; 7],
+        vec![1],
+    ]
+    .concat();
+    let wrapper: Wrapper = try_from_bytes(&bytes)?;
+    assert_eq!(wrapper, Wrapper(5, (7, true)));
+
+    let err = Order::from_token(Token::Struct(vec![Token::U64(1), Token::U8(1)])).unwrap_err();
+    assert!(
+        err.to_string().contains("field 'is_active' of 'SwayOrder'"),
+        "{}",
+        err
+    );
+    Ok(())
+}
@@ -21,7 +21,7 @@ use fuels_core::{
     parameters::{CallParameters, TxParameters},
     Selector, Token, Tokenizable,
 };
-use fuels_signers::{provider::Provider, LocalWallet, Signer};
+use fuels_signers::{provider::Provider, Account, LocalWallet};
 use fuels_types::{
     errors::Error,
     param_types::{ParamType, ReturnLocation},
@@ -109,15 +109,16 @@ impl Contract {
     ///     }
     /// }
     /// For more details see `code_gen/functions_gen.rs`.
-    /// Note that this needs a wallet because the contract instance needs a wallet for the calls
-    pub fn method_hash<D: Tokenizable + Debug>(
+    /// Without a wallet, the returned call can only be executed with `read()`, since the
+    /// wallet pays for and signs the transactions of `call()` and `simulate()`.
+    pub fn method_hash<D: Tokenizable + Debug, A: Account + Clone>(
         provider: &Provider,
         contract_id: ContractId,
-        wallet: &LocalWallet,
+        wallet: Option<&A>,
         signature: Selector,
         output_param: Option<ParamType>,
         args: &[Token],
-    ) -> Result<ContractCallHandler<D>, Error> {
+    ) -> Result<ContractCallHandler<D, A>, Error> {
         let encoded_args = ABIEncoder::encode(args).unwrap();
         let encoded_selector = signature;
 
@@ -140,7 +141,7 @@ impl Contract {
         Ok(ContractCallHandler {
             contract_call,
             tx_parameters,
-            wallet: wallet.clone(),
+            wallet: wallet.cloned(),
             fuel_client: provider.client.clone(),
             datatype: PhantomData,
         })
@@ -167,9 +168,9 @@ impl Contract {
     }
 
     /// Loads a compiled contract and deploys it to a running node
-    pub async fn deploy(
+    pub async fn deploy<A: Account>(
         binary_filepath: &str,
-        wallet: &LocalWallet,
+        wallet: &A,
         params: TxParameters,
         storage_configuration: StorageConfiguration,
     ) -> Result<ContractId, Error> {
@@ -182,9 +183,9 @@ impl Contract {
     }
 
     /// Loads a compiled contract with salt and deploys it to a running node
-    pub async fn deploy_with_parameters(
+    pub async fn deploy_with_parameters<A: Account>(
         binary_filepath: &str,
-        wallet: &LocalWallet,
+        wallet: &A,
         params: TxParameters,
         storage_configuration: StorageConfiguration,
         salt: Salt,
@@ -216,16 +217,16 @@ impl Contract {
     /// Deploys a compiled contract to a running node
     /// To deploy a contract, you need a wallet with enough assets to pay for deployment. This
     /// wallet will also receive the change.
-    pub async fn deploy_loaded(
+    pub async fn deploy_loaded<A: Account>(
         compiled_contract: &CompiledContract,
-        wallet: &LocalWallet,
+        wallet: &A,
         params: TxParameters,
     ) -> Result<ContractId, Error> {
         let (mut tx, contract_id) =
             Self::contract_deployment_transaction(compiled_contract, wallet, params).await?;
-        wallet.sign_transaction(&mut tx).await?;
+        wallet.sign_transaction(&mut tx).await.map_err(Into::into)?;
 
-        match wallet.get_provider().unwrap().client.submit(&tx).await {
+        match wallet.get_provider()?.client.submit(&tx).await {
             Ok(_) => Ok(contract_id),
             Err(e) => Err(Error::TransactionError(e.to_string())),
         }
@@ -287,9 +288,9 @@ impl Contract {
     }
 
     /// Crafts a transaction used to deploy a contract
-    pub async fn contract_deployment_transaction(
+    pub async fn contract_deployment_transaction<A: Account>(
         compiled_contract: &CompiledContract,
-        wallet: &LocalWallet,
+        wallet: &A,
         params: TxParameters,
     ) -> Result<(Transaction, ContractId), Error> {
         let bytecode_witness_index = 0;
@@ -406,22 +407,97 @@ impl ContractCall {
         let decoded_value = ABIDecoder::decode_single(param_type, &encoded_value)?;
         Ok(decoded_value)
     }
+
+    /// Decodes the value returned by each of `calls`, made in this order by a script, from the
+    /// `Return` or `ReturnData` receipt ending the call. Calls without an output parameter are
+    /// decoded as `Token::Unit`. The receipts the values are decoded from are removed from
+    /// `receipts`.
+    pub fn get_decoded_outputs(
+        calls: &[&ContractCall],
+        receipts: &mut Vec<Receipt>,
+    ) -> Result<Vec<Token>, Error> {
+        let return_indices = Self::get_return_receipt_indices(receipts);
+
+        let mut tokens = vec![];
+        let mut decoded_indices = vec![];
+        for (i, call) in calls.iter().enumerate() {
+            let param_type = match call.output_param.as_ref() {
+                Some(param_type) => param_type,
+                None => {
+                    tokens.push(Token::Unit);
+                    continue;
+                }
+            };
+
+            let index = *return_indices.get(i).ok_or_else(|| {
+                Error::InvalidData(format!(
+                    "no receipt holds the value returned by call #{} to contract {}",
+                    i, call.contract_id
+                ))
+            })?;
+            let encoded_value = match (param_type.get_return_location(), &receipts[index]) {
+                (ReturnLocation::Return, Receipt::Return { val, .. }) => val.to_be_bytes().to_vec(),
+                (ReturnLocation::ReturnData, Receipt::ReturnData { data, .. }) => data.clone(),
+                (_, receipt) => {
+                    return Err(Error::InvalidData(format!(
+                        "expected call #{} to contract {} to return a `{}`, got {:?}",
+                        i, call.contract_id, param_type, receipt
+                    )))
+                }
+            };
+
+            tokens.push(ABIDecoder::decode_single(param_type, &encoded_value)?);
+            decoded_indices.push(index);
+        }
+
+        // Indices are increasing, removing the last ones first keeps the others valid
+        for index in decoded_indices.into_iter().rev() {
+            receipts.remove(index);
+        }
+
+        Ok(tokens)
+    }
+
+    /// Returns the indices of the receipts ending the calls made by the script, in order,
+    /// skipping those of the calls the called contracts make themselves.
+    fn get_return_receipt_indices(receipts: &[Receipt]) -> Vec<usize> {
+        let mut depth = 0;
+        let mut indices = vec![];
+
+        for (index, receipt) in receipts.iter().enumerate() {
+            match receipt {
+                Receipt::Call { .. } => depth += 1,
+                // The script's own `Return` happens outside of any call
+                Receipt::Return { .. } | Receipt::ReturnData { .. } if depth > 0 => {
+                    if depth == 1 {
+                        indices.push(index);
+                    }
+                    depth -= 1;
+                }
+                _ => {}
+            }
+        }
+
+        indices
+    }
 }
 
 #[derive(Debug)]
 #[must_use = "contract calls do nothing unless you `call` them"]
 /// Helper that handles submitting a call to a client and formatting the response
-pub struct ContractCallHandler<D> {
+pub struct ContractCallHandler<D, A = LocalWallet> {
     pub contract_call: ContractCall,
     pub tx_parameters: TxParameters,
-    pub wallet: LocalWallet,
+    /// The wallet paying for and signing the call, if it isn't read-only.
+    pub wallet: Option<A>,
     pub fuel_client: FuelClient,
     pub datatype: PhantomData<D>,
 }
 
-impl<D> ContractCallHandler<D>
+impl<D, A> ContractCallHandler<D, A>
 where
     D: Tokenizable + Debug,
+    A: Account,
 {
     /// Sets external contracts as dependencies to this contract's call.
     /// Effectively, this will be used to create Input::Contract/Output::Contract
@@ -479,7 +555,7 @@ where
     /// transaction.
     #[tracing::instrument]
     async fn call_or_simulate(self, simulate: bool) -> Result<CallResponse<D>, Error> {
-        let script = self.get_script().await;
+        let script = self.get_script().await?;
 
         let receipts = if simulate {
             script.simulate(&self.fuel_client).await?
@@ -492,9 +568,15 @@ where
     }
 
     /// Returns the script that executes the contract call
-    pub async fn get_script(&self) -> Script {
-        Script::from_contract_calls(vec![&self.contract_call], &self.tx_parameters, &self.wallet)
-            .await
+    pub async fn get_script(&self) -> Result<Script, Error> {
+        let wallet = self.wallet.as_ref().ok_or_else(|| {
+            Error::WalletError(
+                "the call has no wallet to pay for it, use `read()` for read-only calls"
+                    .to_string(),
+            )
+        })?;
+
+        Script::from_contract_calls(vec![&self.contract_call], &self.tx_parameters, wallet).await
     }
 
     /// Call a contract's method on the node, in a state-modifying manner.
@@ -509,30 +591,41 @@ where
         Self::call_or_simulate(self, true).await
     }
 
+    /// Call a contract's method that only reads the state of the blockchain. Unlike `simulate`,
+    /// it needs no wallet: the call is dry-run in an unsigned transaction spending no coins, so
+    /// it can't forward any amount to the contract, and fails if its call parameters set one.
+    pub async fn read(self) -> Result<CallResponse<D>, Error> {
+        let script =
+            Script::from_contract_calls_read_only(vec![&self.contract_call], &self.tx_parameters)?;
+
+        let receipts = script.read(&self.fuel_client).await?;
+        tracing::debug!(target: "receipts", "{:?}", receipts);
+
+        self.get_response(receipts)
+    }
+
     /// Create a CallResponse from call receipts
     pub fn get_response(&self, mut receipts: Vec<Receipt>) -> Result<CallResponse<D>, Error> {
-        match self.contract_call.output_param.as_ref() {
-            None => Ok(CallResponse::new(D::from_token(Token::Unit)?, receipts)),
-            Some(param_type) => {
-                let token = ContractCall::get_decoded_output(param_type, &mut receipts)?;
-                Ok(CallResponse::new(D::from_token(token)?, receipts))
-            }
-        }
+        let token = ContractCall::get_decoded_outputs(&[&self.contract_call], &mut receipts)?
+            .pop()
+            .expect("a token is decoded per call");
+
+        Ok(CallResponse::new(D::from_token(token)?, receipts))
     }
 }
 
 #[derive(Debug)]
 #[must_use = "contract calls do nothing unless you `call` them"]
 /// Helper that handles bundling multiple calls into a single transaction
-pub struct MultiContractCallHandler {
+pub struct MultiContractCallHandler<A = LocalWallet> {
     pub contract_calls: Option<Vec<ContractCall>>,
     pub tx_parameters: TxParameters,
-    pub wallet: LocalWallet,
+    pub wallet: A,
     pub fuel_client: FuelClient,
 }
 
-impl MultiContractCallHandler {
-    pub fn new(wallet: LocalWallet) -> Self {
+impl<A: Account> MultiContractCallHandler<A> {
+    pub fn new(wallet: A) -> Self {
         Self {
             contract_calls: None,
             tx_parameters: TxParameters::default(),
@@ -543,7 +636,10 @@ impl MultiContractCallHandler {
 
     /// Adds a contract call to be bundled in the transaction
     /// Note that this is a builder method
-    pub fn add_call<D: Tokenizable>(&mut self, call_handler: ContractCallHandler<D>) -> &mut Self {
+    pub fn add_call<D: Tokenizable, B>(
+        &mut self,
+        call_handler: ContractCallHandler<D, B>,
+    ) -> &mut Self {
         match self.contract_calls.as_mut() {
             Some(c) => c.push(call_handler.contract_call),
             None => self.contract_calls = Some(vec![call_handler.contract_call]),
@@ -559,17 +655,8 @@ impl MultiContractCallHandler {
     }
 
     /// Returns the script that executes the contract calls
-    pub async fn get_script(&self) -> Script {
-        Script::from_contract_calls(
-            self.contract_calls
-                .as_ref()
-                .expect("No calls added. Have you used '.add_calls()'?")
-                .iter()
-                .collect(),
-            &self.tx_parameters,
-            &self.wallet,
-        )
-        .await
+    pub async fn get_script(&self) -> Result<Script, Error> {
+        Script::from_contract_calls(self.calls(), &self.tx_parameters, &self.wallet).await
     }
 
     /// Call contract methods on the node, in a state-modifying manner.
@@ -584,21 +671,54 @@ impl MultiContractCallHandler {
         Self::call_or_simulate(self, true).await
     }
 
+    /// Call contract methods that only read the state of the blockchain, in an unsigned
+    /// transaction spending no coins. See `ContractCallHandler::read`.
+    pub async fn read<D: Tokenizable + Debug>(&self) -> Result<CallResponse<D>, Error> {
+        let receipts = self.get_read_receipts().await?;
+
+        self.get_response(receipts)
+    }
+
     #[tracing::instrument]
     async fn call_or_simulate<D: Tokenizable + Debug>(
         &self,
         simulate: bool,
     ) -> Result<CallResponse<D>, Error> {
-        let script = self.get_script().await;
+        let receipts = self.get_receipts(simulate).await?;
+
+        self.get_response(receipts)
+    }
+
+    /// Executes the calls, returning the receipts of the transaction
+    async fn get_receipts(&self, simulate: bool) -> Result<Vec<Receipt>, Error> {
+        let script = self.get_script().await?;
 
         let receipts = if simulate {
-            script.simulate(&self.fuel_client).await.unwrap()
+            script.simulate(&self.fuel_client).await?
         } else {
-            script.call(&self.fuel_client).await.unwrap()
+            script.call(&self.fuel_client).await?
         };
         tracing::debug!(target: "receipts", "{:?}", receipts);
 
-        self.get_response(receipts)
+        Ok(receipts)
+    }
+
+    /// Executes the calls in a read-only transaction, returning its receipts
+    async fn get_read_receipts(&self) -> Result<Vec<Receipt>, Error> {
+        let script = Script::from_contract_calls_read_only(self.calls(), &self.tx_parameters)?;
+
+        let receipts = script.read(&self.fuel_client).await?;
+        tracing::debug!(target: "receipts", "{:?}", receipts);
+
+        Ok(receipts)
+    }
+
+    fn calls(&self) -> Vec<&ContractCall> {
+        self.contract_calls
+            .as_ref()
+            .expect("No calls added. Have you used '.add_calls()'?")
+            .iter()
+            .collect()
     }
 
     /// Create a MultiCallResponse from call receipts
@@ -606,16 +726,16 @@ impl MultiContractCallHandler {
         &self,
         mut receipts: Vec<Receipt>,
     ) -> Result<CallResponse<D>, Error> {
-        let mut final_tokens = vec![];
+        let calls = self.calls();
+        let tokens = ContractCall::get_decoded_outputs(&calls, &mut receipts)?;
 
-        for call in self.contract_calls.as_ref().unwrap().iter() {
-            // We only aggregate the tokens if the contract call has an output parameter
-            if let Some(param_type) = call.output_param.as_ref() {
-                let decoded = ContractCall::get_decoded_output(param_type, &mut receipts)?;
-
-                final_tokens.push(decoded.clone());
-            }
-        }
+        // We only aggregate the tokens if the contract call has an output parameter
+        let final_tokens = calls
+            .iter()
+            .zip(tokens)
+            .filter(|(call, _)| call.output_param.is_some())
+            .map(|(_, token)| token)
+            .collect();
 
         let tokens_as_tuple = Token::Tuple(final_tokens);
         let response = CallResponse::<D>::new(D::from_token(tokens_as_tuple)?, receipts);
@@ -624,12 +744,282 @@ impl MultiContractCallHandler {
     }
 }
 
+#[derive(Debug)]
+#[must_use = "contract calls do nothing unless you `call` them"]
+/// Helper that handles bundling multiple calls into a single transaction while keeping track
+/// of the type each call returns: the value of the response of a handler holding calls
+/// returning `A`, `()` and `B` is an `(A, (), B)`, each decoded from the receipts of its call.
+pub struct TypedMultiCallHandler<T, A = LocalWallet> {
+    pub handler: MultiContractCallHandler<A>,
+    pub datatype: PhantomData<T>,
+}
+
+impl<A: Account> TypedMultiCallHandler<(), A> {
+    pub fn new(wallet: A) -> Self {
+        Self {
+            handler: MultiContractCallHandler::new(wallet),
+            datatype: PhantomData,
+        }
+    }
+}
+
+impl<T, A: Account> TypedMultiCallHandler<T, A> {
+    /// Adds a contract call to be bundled in the transaction, appending the type it returns
+    /// to the value of the response.
+    /// Note that this is a builder method, i.e. use it as a chain:
+    /// `TypedMultiCallHandler::new(wallet).add_call(a).add_call(b).call().await`.
+    pub fn add_call<D: Tokenizable, B>(
+        mut self,
+        call_handler: ContractCallHandler<D, B>,
+    ) -> TypedMultiCallHandler<T::Output, A>
+    where
+        T: AppendOutput<D>,
+    {
+        self.handler.add_call(call_handler);
+
+        TypedMultiCallHandler {
+            handler: self.handler,
+            datatype: PhantomData,
+        }
+    }
+
+    /// Sets the transaction parameters for a given transaction.
+    /// Note that this is a builder method
+    pub fn tx_params(mut self, params: TxParameters) -> Self {
+        self.handler.tx_params(params);
+        self
+    }
+
+    /// Returns the script that executes the contract calls
+    pub async fn get_script(&self) -> Result<Script, Error> {
+        self.handler.get_script().await
+    }
+}
+
+impl<T: Tokenizable + Debug, A: Account> TypedMultiCallHandler<T, A> {
+    /// Call contract methods on the node, in a state-modifying manner.
+    pub async fn call(&self) -> Result<CallResponse<T>, Error> {
+        let receipts = self.handler.get_receipts(false).await?;
+        self.get_response(receipts)
+    }
+
+    /// Call contract methods on the node, in a simulated manner, meaning the state of the
+    /// blockchain is *not* modified but simulated.
+    pub async fn simulate(&self) -> Result<CallResponse<T>, Error> {
+        let receipts = self.handler.get_receipts(true).await?;
+        self.get_response(receipts)
+    }
+
+    /// Call contract methods that only read the state of the blockchain, in an unsigned
+    /// transaction spending no coins. See `ContractCallHandler::read`.
+    pub async fn read(&self) -> Result<CallResponse<T>, Error> {
+        let receipts = self.handler.get_read_receipts().await?;
+        self.get_response(receipts)
+    }
+
+    /// Create a CallResponse holding the value returned by each call from call receipts
+    pub fn get_response(&self, mut receipts: Vec<Receipt>) -> Result<CallResponse<T>, Error> {
+        let tokens = ContractCall::get_decoded_outputs(&self.handler.calls(), &mut receipts)?;
+
+        Ok(CallResponse::new(
+            T::from_token(Token::Tuple(tokens))?,
+            receipts,
+        ))
+    }
+}
+
+/// Appends the type `D` returned by a call to the tuple of the types returned by the calls of
+/// a `TypedMultiCallHandler`.
+pub trait AppendOutput<D> {
+    type Output;
+}
+
+impl<D> AppendOutput<D> for () {
+    type Output = (D,);
+}
+
+// Tokenizable is implemented for tuples of up to 16 elements, which bounds the
+// number of calls a `TypedMultiCallHandler` can decode.
+macro_rules! impl_append_output {
+    ($( $ty: ident ),+) => {
+        impl<$( $ty, )+ D> AppendOutput<D> for ($( $ty, )+) {
+            type Output = ($( $ty, )+ D);
+        }
+    };
+}
+
+impl_append_output!(A);
+impl_append_output!(A, B);
+impl_append_output!(A, B, C);
+impl_append_output!(A, B, C, E);
+impl_append_output!(A, B, C, E, F);
+impl_append_output!(A, B, C, E, F, G);
+impl_append_output!(A, B, C, E, F, G, H);
+impl_append_output!(A, B, C, E, F, G, H, I);
+impl_append_output!(A, B, C, E, F, G, H, I, J);
+impl_append_output!(A, B, C, E, F, G, H, I, J, K);
+impl_append_output!(A, B, C, E, F, G, H, I, J, K, L);
+impl_append_output!(A, B, C, E, F, G, H, I, J, K, L, M);
+impl_append_output!(A, B, C, E, F, G, H, I, J, K, L, M, N);
+impl_append_output!(A, B, C, E, F, G, H, I, J, K, L, M, N, O);
+impl_append_output!(A, B, C, E, F, G, H, I, J, K, L, M, N, O, P);
+
 #[cfg(test)]
 mod test {
-    use fuels_test_helpers::launch_provider_and_get_wallet;
+    use fuel_crypto::{Message, PublicKey, SecretKey, Signature};
+    use fuel_gql_client::fuel_types::bytes::Deserializable;
+    use fuel_gql_client::fuel_vm::prelude::Opcode;
+    use fuels_signers::remote::RemoteSigner;
+    use fuels_signers::ViewOnlyAccount;
+    use fuels_test_helpers::{
+        launch_provider_and_get_wallet, setup_single_asset_coins, setup_test_provider,
+    };
+    use hyper::service::{make_service_fn, service_fn};
+    use hyper::{Body, Request, Response, Server};
+    use serde_json::{json, Value};
+    use std::convert::Infallible;
 
     use super::*;
 
+    fn contract_call(contract_id: ContractId, output_param: Option<ParamType>) -> ContractCall {
+        ContractCall {
+            contract_id,
+            encoded_args: vec![],
+            encoded_selector: [0; 8],
+            call_parameters: CallParameters::default(),
+            compute_custom_input_offset: false,
+            variable_outputs: None,
+            external_contracts: None,
+            output_param,
+        }
+    }
+
+    fn call_receipt(id: ContractId, to: ContractId) -> Receipt {
+        Receipt::call(id, to, 0, AssetId::default(), 0, 0, 0, 0, 0)
+    }
+
+    fn log_data_receipt(id: ContractId) -> Receipt {
+        Receipt::log_data(id, 0, 0, 0, 1, Bytes32::zeroed(), vec![9], 0, 0)
+    }
+
+    #[test]
+    fn decodes_each_output_from_the_receipt_of_its_call() -> Result<(), Error> {
+        let (script, a, b, c) = (
+            ContractId::zeroed(),
+            ContractId::from([1u8; 32]),
+            ContractId::from([2u8; 32]),
+            ContractId::from([3u8; 32]),
+        );
+        let calls = [
+            contract_call(a, None),
+            contract_call(b, Some(ParamType::U64)),
+            contract_call(c, Some(ParamType::B256)),
+        ];
+
+        let mut receipts = vec![
+            // `a` returns nothing but still ends its call with a `Return`
+            call_receipt(script, a),
+            log_data_receipt(a),
+            Receipt::ret(a, 0, 0, 0),
+            // `b` calls `c` before returning its own value
+            call_receipt(script, b),
+            call_receipt(b, c),
+            Receipt::ret(c, 7, 0, 0),
+            Receipt::ret(b, 42, 0, 0),
+            call_receipt(script, c),
+            log_data_receipt(c),
+            Receipt::return_data(c, 0, 32, Bytes32::zeroed(), vec![5; 32], 0, 0),
+            Receipt::ret(script, 1, 0, 0),
+        ];
+
+        let tokens =
+            ContractCall::get_decoded_outputs(&calls.iter().collect::<Vec<_>>(), &mut receipts)?;
+
+        assert_eq!(
+            tokens,
+            vec![Token::Unit, Token::U64(42), Token::B256([5; 32])]
+        );
+        // Only the receipts holding the decoded values are removed
+        assert_eq!(receipts.len(), 9);
+        assert!(!receipts.contains(&Receipt::ret(b, 42, 0, 0)));
+        Ok(())
+    }
+
+    #[test]
+    fn decoding_fails_on_a_mismatched_return_receipt() {
+        let id = ContractId::from([1u8; 32]);
+        let calls = [contract_call(id, Some(ParamType::B256))];
+        let mut receipts = vec![
+            call_receipt(ContractId::zeroed(), id),
+            Receipt::ret(id, 1, 0, 0),
+        ];
+
+        let result =
+            ContractCall::get_decoded_outputs(&calls.iter().collect::<Vec<_>>(), &mut receipts);
+
+        assert!(matches!(result, Err(Error::InvalidData(_))));
+    }
+
+    /// Serves the `fuel_signTransaction` JSON-RPC method of a remote signer signing with
+    /// `secret`, returning the url of the server
+    fn mock_remote_signer(secret: SecretKey) -> String {
+        let make_service = make_service_fn(move |_| async move {
+            Ok::<_, Infallible>(service_fn(move |request: Request<Body>| async move {
+                let body = hyper::body::to_bytes(request.into_body()).await.unwrap();
+                let request: Value = serde_json::from_slice(&body).unwrap();
+                let data = hex::decode(&request["params"][1].as_str().unwrap()[2..]).unwrap();
+
+                let tx = Transaction::from_bytes(&data).unwrap();
+                let message = unsafe { Message::from_bytes_unchecked(*tx.id()) };
+                let response = json!({
+                    "jsonrpc": "2.0",
+                    "id": request["id"],
+                    "result": format!("0x{}", hex::encode(Signature::sign(&secret, &message))),
+                });
+                Ok::<_, Infallible>(Response::new(Body::from(response.to_string())))
+            }))
+        });
+
+        let server = Server::bind(&([127, 0, 0, 1], 0).into()).serve(make_service);
+        let url = format!("http://{}", server.local_addr());
+        tokio::spawn(server);
+
+        url
+    }
+
+    #[tokio::test]
+    async fn remote_signer_deploys_and_calls_contracts() -> Result<(), Error> {
+        let secret = SecretKey::random(&mut rand::thread_rng());
+        let address = Address::new(*PublicKey::from(&secret).hash());
+        let coins =
+            setup_single_asset_coins(address, BASE_ASSET_ID, 2, DEFAULT_SPENDABLE_COIN_AMOUNT);
+        let (provider, _) = setup_test_provider(coins, None).await;
+        let signer = RemoteSigner::new(&mock_remote_signer(secret), address, Some(provider));
+
+        // A contract returning 42 whatever the call
+        let compiled_contract = CompiledContract {
+            raw: vec![Opcode::MOVI(0x10, 42), Opcode::RET(0x10)]
+                .into_iter()
+                .collect(),
+            ..Default::default()
+        };
+        let contract_id =
+            Contract::deploy_loaded(&compiled_contract, &signer, TxParameters::default()).await?;
+
+        let response = Contract::method_hash::<u64, _>(
+            signer.get_provider()?,
+            contract_id,
+            Some(&signer),
+            [0; 8],
+            Some(ParamType::U64),
+            &[],
+        )?
+        .call()
+        .await?;
+        assert_eq!(response.value, 42);
+        Ok(())
+    }
+
     #[tokio::test]
     #[should_panic(expected = "called `Result::unwrap()` on an `Err` value: InvalidData(\"json\")")]
     async fn deploy_panics_on_non_binary_file() {
@@ -9,12 +9,11 @@ use fuel_gql_client::{
     fuel_tx::{ConsensusParameters, Receipt, Transaction},
 };
 
-use fuels_core::constants::{DEFAULT_SPENDABLE_COIN_AMOUNT, WORD_SIZE};
+use fuels_core::constants::{BASE_ASSET_ID, WORD_SIZE};
 use fuels_core::parameters::TxParameters;
-use fuels_signers::{LocalWallet, Signer};
+use fuels_signers::Account;
 use fuels_types::errors::Error;
-use std::collections::HashSet;
-use std::iter;
+use std::collections::{HashMap, HashSet};
 
 use crate::contract::ContractCall;
 
@@ -47,24 +46,93 @@ impl Script {
 
     /// Creates a Script from a contract call. The internal Transaction is initialized
     /// with the actual script instructions, script data needed to perform the call
-    /// and transaction inputs/outputs consisting of assets and contracts
-    pub async fn from_contract_calls(
+    /// and transaction inputs/outputs consisting of assets and contracts.
+    ///
+    /// The coins spent by the transaction cover the amounts forwarded by all the calls, summed
+    /// per asset, and the transaction fee. Fails if the gas explicitly forwarded by the calls
+    /// exceeds the transaction's gas limit. The transaction is paid for and signed by `account`.
+    pub async fn from_contract_calls<A: Account>(
+        calls: Vec<&ContractCall>,
+        tx_parameters: &TxParameters,
+        account: &A,
+    ) -> Result<Self, Error> {
+        Self::validate_gas_forwarded(&calls, tx_parameters)?;
+
+        let data_offset = Self::get_data_offset(calls.len());
+
+        let (script_data, call_param_offsets) =
+            Self::get_script_data(calls.clone(), data_offset, tx_parameters.gas_limit);
+
+        let script = Self::get_instructions(calls.clone(), call_param_offsets);
+
+        let forwarded_amounts = Self::get_forwarded_amounts(&calls)?;
+        let forwarded_base_amount = forwarded_amounts
+            .get(&BASE_ASSET_ID)
+            .copied()
+            .unwrap_or_default();
+
+        // The fee depends on the size of the transaction, and so on the coins it spends: start
+        // with the gas fee and fetch more base asset coins until they cover the whole fee
+        let mut required_amounts = forwarded_amounts;
+        required_amounts.insert(
+            BASE_ASSET_ID,
+            Self::add_amounts(forwarded_base_amount, Self::get_fee(tx_parameters, 0)?)?,
+        );
+
+        loop {
+            let (inputs, outputs) =
+                Self::get_transaction_inputs_outputs(&calls, &required_amounts, account).await?;
+
+            let mut tx = Transaction::script(
+                tx_parameters.gas_price,
+                tx_parameters.gas_limit,
+                tx_parameters.byte_price,
+                tx_parameters.maturity,
+                script.clone(),
+                script_data.clone(),
+                inputs,
+                outputs,
+                vec![],
+            );
+
+            let fee = Self::get_fee(tx_parameters, tx.metered_bytes_size())?;
+            let required_base_amount = Self::add_amounts(forwarded_base_amount, fee)?;
+            if Self::get_base_amount(&tx) >= required_base_amount {
+                account
+                    .sign_transaction(&mut tx)
+                    .await
+                    .map_err(Into::into)?;
+                return Ok(Script::new(tx));
+            }
+
+            required_amounts.insert(BASE_ASSET_ID, required_base_amount);
+        }
+    }
+
+    /// Creates a Script from contract calls that only read the state of the contracts. The
+    /// transaction is neither funded nor signed: it has no coin inputs and a zero gas and byte
+    /// price, so it can only be executed through `read`. Fails if any call forwards an amount,
+    /// since there are no coins to forward it from.
+    pub fn from_contract_calls_read_only(
         calls: Vec<&ContractCall>,
         tx_parameters: &TxParameters,
-        wallet: &LocalWallet,
-    ) -> Self {
+    ) -> Result<Self, Error> {
+        Self::validate_no_amount_forwarded(&calls)?;
+
         let data_offset = Self::get_data_offset(calls.len());
 
-        let (script_data, call_param_offsets) = Self::get_script_data(calls.clone(), data_offset);
+        let (script_data, call_param_offsets) =
+            Self::get_script_data(calls.clone(), data_offset, tx_parameters.gas_limit);
 
         let script = Self::get_instructions(calls.clone(), call_param_offsets);
 
-        let (inputs, outputs) = Self::get_transaction_inputs_outputs(calls.clone(), wallet).await;
+        let (inputs, mut outputs) = Self::get_contract_inputs_outputs(&calls);
+        outputs.extend(Self::get_variable_outputs(&calls));
 
-        let mut tx = Transaction::script(
-            tx_parameters.gas_price,
+        let tx = Transaction::script(
+            0,
             tx_parameters.gas_limit,
-            tx_parameters.byte_price,
+            0,
             tx_parameters.maturity,
             script,
             script_data,
@@ -72,9 +140,8 @@ impl Script {
             outputs,
             vec![],
         );
-        wallet.sign_transaction(&mut tx).await.unwrap();
 
-        Script::new(tx)
+        Ok(Script::new(tx))
     }
 
     /// Given a list of contract calls, create the actual opcodes used to call the contract
@@ -94,7 +161,8 @@ impl Script {
     /// Returns script data, consisting of the following items in the given order:
     /// 1. Asset ID to be forwarded (AmountId::LEN)
     /// 2. Amount to be forwarded (1 * WORD_SIZE)
-    /// 3. Gas to be forwarded (1 * WORD_SIZE)
+    /// 3. Gas to be forwarded (1 * WORD_SIZE), the transaction's gas limit when the call
+    ///    doesn't set it, which forwards all the remaining gas
     /// 4. Contract ID (ContractID::LEN);
     /// 5. Function selector (1 * WORD_SIZE);
     /// 6. Calldata offset (optional) (1 * WORD_SIZE)
@@ -102,6 +170,7 @@ impl Script {
     fn get_script_data(
         calls: Vec<&ContractCall>,
         data_offset: usize,
+        gas_limit: u64,
     ) -> (Vec<u8>, Vec<CallParamOffsets>) {
         let mut script_data = vec![];
         let mut param_offsets = vec![];
@@ -123,7 +192,8 @@ impl Script {
             let amount = call.call_parameters.amount as Word;
             script_data.extend(amount.to_be_bytes());
 
-            script_data.extend(call.call_parameters.gas_forwarded.to_be_bytes());
+            let gas_forwarded = call.call_parameters.gas_forwarded.unwrap_or(gas_limit);
+            script_data.extend(gas_forwarded.to_be_bytes());
 
             script_data.extend(call.contract_id.as_ref());
 
@@ -179,12 +249,139 @@ impl Script {
         instructions.iter().copied().collect::<Vec<u8>>()
     }
 
+    /// Fails if the gas forwarded by the calls setting `gas_forwarded` adds up to more than the
+    /// transaction's gas limit. Calls that don't set it get the gas remaining when they run.
+    fn validate_gas_forwarded(
+        calls: &[&ContractCall],
+        tx_parameters: &TxParameters,
+    ) -> Result<(), Error> {
+        let gas_forwarded = calls
+            .iter()
+            .filter_map(|call| call.call_parameters.gas_forwarded)
+            .try_fold(0u64, |total, gas| total.checked_add(gas));
+
+        match gas_forwarded {
+            Some(gas_forwarded) if gas_forwarded <= tx_parameters.gas_limit => Ok(()),
+            Some(gas_forwarded) => Err(Error::TransactionError(format!(
+                "the calls forward {} gas in total, more than the transaction's gas limit of {}",
+                gas_forwarded, tx_parameters.gas_limit
+            ))),
+            None => Err(Error::TransactionError(
+                "the gas forwarded by the calls overflows".to_string(),
+            )),
+        }
+    }
+
+    /// Fails if any of the calls forwards a non-zero amount, which a transaction spending no
+    /// coins can't do.
+    fn validate_no_amount_forwarded(calls: &[&ContractCall]) -> Result<(), Error> {
+        match calls.iter().find(|call| call.call_parameters.amount > 0) {
+            Some(call) => Err(Error::TransactionError(format!(
+                "a read-only call can't forward an amount, but the call to contract {} forwards {} of asset {}",
+                call.contract_id, call.call_parameters.amount, call.call_parameters.asset_id
+            ))),
+            None => Ok(()),
+        }
+    }
+
+    /// Returns the amount forwarded by the calls for each asset
+    fn get_forwarded_amounts(calls: &[&ContractCall]) -> Result<HashMap<AssetId, u64>, Error> {
+        let mut amounts: HashMap<AssetId, u64> = HashMap::new();
+        for call in calls {
+            let amount = amounts.entry(call.call_parameters.asset_id).or_default();
+            *amount = Self::add_amounts(*amount, call.call_parameters.amount)?;
+        }
+
+        Ok(amounts)
+    }
+
+    fn add_amounts(a: u64, b: u64) -> Result<u64, Error> {
+        a.checked_add(b).ok_or_else(|| {
+            Error::TransactionError("the amount spent by the transaction overflows".to_string())
+        })
+    }
+
+    /// Returns the fee paid by a transaction of `tx_size` metered bytes, computed the way the VM
+    /// does
+    fn get_fee(tx_parameters: &TxParameters, tx_size: usize) -> Result<u64, Error> {
+        let factor = ConsensusParameters::DEFAULT.gas_price_factor;
+        let overflow = || Error::TransactionError("the transaction fee overflows".to_string());
+
+        let bytes = tx_parameters
+            .byte_price
+            .checked_mul(tx_size as u64)
+            .ok_or_else(overflow)?;
+        let gas = tx_parameters
+            .gas_price
+            .checked_mul(tx_parameters.gas_limit)
+            .ok_or_else(overflow)?;
+
+        let div_ceil = |x: u64| {
+            x.checked_add(factor - 1)
+                .map(|x| x / factor)
+                .ok_or_else(overflow)
+        };
+
+        div_ceil(bytes)?
+            .checked_add(div_ceil(gas)?)
+            .ok_or_else(overflow)
+    }
+
+    /// Returns the amount of base asset spent by the coin inputs of the transaction
+    fn get_base_amount(tx: &Transaction) -> u64 {
+        tx.inputs()
+            .iter()
+            .filter_map(|input| match input {
+                Input::CoinSigned {
+                    asset_id, amount, ..
+                } if *asset_id == BASE_ASSET_ID => Some(*amount),
+                _ => None,
+            })
+            .sum()
+    }
+
     /// Returns the assets and contracts that will be consumed (inputs) and created (outputs)
-    /// by the transaction
-    async fn get_transaction_inputs_outputs(
-        calls: Vec<&ContractCall>,
-        wallet: &LocalWallet,
-    ) -> (Vec<Input>, Vec<Output>) {
+    /// by the transaction, spending enough coins to cover `required_amounts` for each asset
+    async fn get_transaction_inputs_outputs<A: Account>(
+        calls: &[&ContractCall],
+        required_amounts: &HashMap<AssetId, u64>,
+        account: &A,
+    ) -> Result<(Vec<Input>, Vec<Output>), Error> {
+        let (mut inputs, mut outputs) = Self::get_contract_inputs_outputs(calls);
+
+        for (asset_id, amount) in required_amounts {
+            // The base asset is always spent, even without fees, so that the transaction has
+            // a coin input making its id unique
+            let amount = match *asset_id == BASE_ASSET_ID {
+                true => (*amount).max(1),
+                false if *amount == 0 => continue,
+                false => *amount,
+            };
+
+            let asset_inputs = account
+                .get_asset_inputs_for_amount(*asset_id, amount, 0)
+                .await
+                .map_err(|e| {
+                    Error::WalletError(format!(
+                        "failed to get coins of asset {:#x} adding up to {}: {}",
+                        asset_id, amount, e
+                    ))
+                })?;
+            inputs.extend(asset_inputs);
+
+            // add asset change since inputs are being spent
+            let change_output = Output::change(account.address(), 0, asset_id.to_owned());
+            outputs.push(change_output);
+        }
+
+        outputs.extend(Self::get_variable_outputs(calls));
+
+        Ok((inputs, outputs))
+    }
+
+    /// Returns the inputs and outputs of the contracts called, or used by the calls, which
+    /// must be part of the transaction
+    fn get_contract_inputs_outputs(calls: &[&ContractCall]) -> (Vec<Input>, Vec<Output>) {
         let mut inputs: Vec<Input> = vec![];
         let mut outputs: Vec<Output> = vec![];
 
@@ -218,51 +415,17 @@ impl Script {
             outputs.push(external_contract_output);
         }
 
-        // Get all unique asset ids
-        let asset_ids: HashSet<AssetId> = calls
-            .iter()
-            .map(|call| call.call_parameters.asset_id)
-            .chain(iter::once(AssetId::default()))
-            .collect();
-
-        let mut spendables = vec![];
-        for asset_id in asset_ids.iter() {
-            spendables.extend(
-                wallet
-                    .get_spendable_coins(asset_id, DEFAULT_SPENDABLE_COIN_AMOUNT as u64)
-                    .await
-                    .unwrap(),
-            );
-        }
-
-        for asset_id in asset_ids.iter() {
-            // add asset change if any inputs are being spent
-            let change_output = Output::change(wallet.address(), 0, asset_id.to_owned());
-            outputs.push(change_output);
-        }
-
-        for coin in spendables {
-            let input_coin = Input::coin_signed(
-                UtxoId::from(coin.utxo_id),
-                coin.owner.into(),
-                coin.amount.0,
-                coin.asset_id.into(),
-                0,
-                0,
-            );
-
-            inputs.push(input_coin);
-        }
-
-        calls.iter().for_each(|call| {
-            if let Some(v) = call.variable_outputs.clone() {
-                outputs.extend(v);
-            };
-        });
-
         (inputs, outputs)
     }
 
+    /// Returns the variable outputs appended to the calls
+    fn get_variable_outputs(calls: &[&ContractCall]) -> Vec<Output> {
+        calls
+            .iter()
+            .flat_map(|call| call.variable_outputs.clone().unwrap_or_default())
+            .collect()
+    }
+
     /// Calculates the length of the script based on the number of contract calls it
     /// has to make and returns the offset at which the script data begins
     fn get_data_offset(num_calls: usize) -> usize {
@@ -296,6 +459,13 @@ impl Script {
         let receipts = fuel_client.dry_run(&self.tx).await?;
         Ok(receipts)
     }
+
+    /// Dry-runs the transaction without validating its inputs, so that a transaction built by
+    /// `from_contract_calls_read_only`, which spends no coins, can be executed.
+    pub async fn read(self, fuel_client: &FuelClient) -> Result<Vec<Receipt>, Error> {
+        let receipts = fuel_client.dry_run_opt(&self.tx, Some(false)).await?;
+        Ok(receipts)
+    }
 }
 
 #[cfg(test)]
@@ -345,7 +515,7 @@ mod test {
             .collect();
 
         // Act
-        let (script_data, param_offsets) = Script::get_script_data(calls.iter().collect(), 0);
+        let (script_data, param_offsets) = Script::get_script_data(calls.iter().collect(), 0, 0);
 
         // Assert
         assert_eq!(param_offsets.len(), NUM_CALLS);
@@ -398,4 +568,103 @@ mod test {
             script_data[custom_input_offset..custom_input_offset + 2 * WORD_SIZE].to_vec();
         assert_eq!(custom_input, args[1]);
     }
+
+    fn call_with_params(call_parameters: CallParameters) -> ContractCall {
+        ContractCall {
+            contract_id: ContractId::from([1u8; 32]),
+            encoded_selector: [0u8; 8],
+            encoded_args: vec![],
+            call_parameters,
+            compute_custom_input_offset: false,
+            variable_outputs: None,
+            external_contracts: None,
+            output_param: None,
+        }
+    }
+
+    #[test]
+    fn forwarded_amounts_are_summed_per_asset() -> Result<(), Error> {
+        let asset_id = AssetId::from([4u8; 32]);
+        let calls = [
+            call_with_params(CallParameters::new(Some(10), None, None)),
+            call_with_params(CallParameters::new(Some(20), Some(asset_id), None)),
+            call_with_params(CallParameters::new(Some(5), None, None)),
+        ];
+
+        let amounts = Script::get_forwarded_amounts(&calls.iter().collect::<Vec<_>>())?;
+
+        assert_eq!(
+            amounts,
+            HashMap::from([(BASE_ASSET_ID, 15), (asset_id, 20)])
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn forwarded_gas_must_fit_in_the_gas_limit() {
+        let tx_parameters = TxParameters::new(None, Some(1000), None, None);
+        let calls = [
+            call_with_params(CallParameters::new(None, None, Some(600))),
+            call_with_params(CallParameters::new(None, None, None)),
+            call_with_params(CallParameters::new(None, None, Some(400))),
+        ];
+        let calls: Vec<_> = calls.iter().collect();
+
+        assert!(Script::validate_gas_forwarded(&calls, &tx_parameters).is_ok());
+
+        let error =
+            Script::validate_gas_forwarded(&calls, &TxParameters::new(None, Some(999), None, None))
+                .expect_err("should error");
+        assert_eq!(
+            error.to_string(),
+            "Transaction error: the calls forward 1000 gas in total, more than the transaction's gas limit of 999"
+        );
+    }
+
+    #[test]
+    fn read_only_calls_cant_forward_an_amount() {
+        let tx_parameters = TxParameters::default();
+        let calls = [
+            call_with_params(CallParameters::new(Some(0), None, None)),
+            call_with_params(CallParameters::new(Some(10), None, None)),
+        ];
+
+        assert!(Script::from_contract_calls_read_only(vec![&calls[0]], &tx_parameters).is_ok());
+
+        let error =
+            match Script::from_contract_calls_read_only(calls.iter().collect(), &tx_parameters) {
+                Err(error) => error,
+                Ok(_) => panic!("should error"),
+            };
+        assert_eq!(
+            error.to_string(),
+            format!(
+                "Transaction error: a read-only call can't forward an amount, but the call to contract {} forwards 10 of asset {}",
+                ContractId::from([1u8; 32]),
+                BASE_ASSET_ID
+            )
+        );
+    }
+
+    #[test]
+    fn fee_is_rounded_up() -> Result<(), Error> {
+        let tx_parameters = TxParameters::new(Some(1), Some(1_000_000), Some(1), None);
+
+        assert_eq!(Script::get_fee(&tx_parameters, 0)?, 1);
+        assert_eq!(Script::get_fee(&tx_parameters, 100)?, 2);
+        assert_eq!(Script::get_fee(&TxParameters::default(), 100)?, 0);
+
+        // Too large to be divided exactly as a float
+        let factor = ConsensusParameters::DEFAULT.gas_price_factor;
+        let gas_limit = factor * (u64::MAX / factor / 2) + 1;
+        let tx_parameters = TxParameters::new(Some(1), Some(gas_limit), None, None);
+        assert_eq!(
+            Script::get_fee(&tx_parameters, 0)?,
+            u64::MAX / factor / 2 + 1
+        );
+
+        let tx_parameters = TxParameters::new(None, None, Some(u64::MAX), None);
+        assert!(Script::get_fee(&tx_parameters, 2).is_err());
+        Ok(())
+    }
 }
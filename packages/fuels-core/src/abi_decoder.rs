@@ -4,16 +4,11 @@ use core::convert::TryInto;
 use core::str;
 use fuel_types::bytes::padded_len;
 use fuels_types::{
-    errors::CodecError,
+    errors::{CodecError, DecodingError},
     param_types::{EnumVariants, ParamType},
+    Property,
 };
 
-#[derive(Debug, Clone)]
-struct DecodeResult {
-    token: Token,
-    bytes_read: usize,
-}
-
 pub struct ABIDecoder;
 
 impl ABIDecoder {
@@ -23,231 +18,339 @@ impl ABIDecoder {
     /// of the expected values/types in `data`.
     /// You can find comprehensive examples in the tests for this module.
     pub fn decode(types: &[ParamType], data: &[u8]) -> Result<Vec<Token>, CodecError> {
-        let mut tokens: Vec<Token> = Vec::new();
-        let mut offset = 0;
-        for param in types {
-            let res = Self::decode_param(param, &data[offset..])?;
-            offset += res.bytes_read;
-            tokens.push(res.token);
-        }
-
-        Ok(tokens)
+        Ok(DecoderCursor::new(data).decode_all(types)?)
     }
 
     pub fn decode_single(param: &ParamType, data: &[u8]) -> Result<Token, CodecError> {
-        Ok(Self::decode_param(param, data)?.token)
-    }
-
-    fn decode_param(param: &ParamType, data: &[u8]) -> Result<DecodeResult, CodecError> {
-        match &*param {
-            ParamType::Unit => Self::decode_unit(data),
-            ParamType::U8 => Self::decode_u8(data),
-            ParamType::U16 => Self::decode_u16(data),
-            ParamType::U32 => Self::decode_u32(data),
-            ParamType::U64 => Self::decode_u64(data),
-            ParamType::Bool => Self::decode_bool(data),
-            ParamType::Byte => Self::decode_byte(data),
-            ParamType::B256 => Self::decode_b256(data),
-            ParamType::String(length) => Self::decode_string(data, length),
-            ParamType::Array(ref t, length) => Self::decode_array(data, t, length),
-            ParamType::Struct(props) => Self::decode_struct(data, props),
-            ParamType::Enum(variants) => Self::decode_enum(data, variants),
-            ParamType::Tuple(types) => Self::decode_tuple(data, types),
-        }
+        Ok(DecoderCursor::new(data).decode(param)?)
     }
+}
 
-    fn decode_tuple(data: &[u8], types: &Vec<ParamType>) -> Result<DecodeResult, CodecError> {
-        let mut tokens = vec![];
-        let mut bytes_read = 0;
-        for t in types {
-            let res = Self::decode_param(t, &data[bytes_read..])?;
-            bytes_read += res.bytes_read;
-            tokens.push(res.token);
+/// One step of the path leading from a decoded argument to one of its elements.
+enum PathSegment<'a> {
+    Arg(usize),
+    Index(usize),
+    /// A struct field, tuple element or enum variant, along with its name if known.
+    Field(usize, Option<&'a str>),
+}
+
+/// A decoder that reads values one after another from a single buffer,
+/// keeping track of how many bytes have been consumed so far.
+///
+/// Errors carry the byte offset and the path of the element that couldn't
+/// be decoded, e.g. `arg[1].field.inner[3]`. Elements are named by their
+/// position unless decoded through `decode_property`, in which case the
+/// names of the `Property` components are used.
+///
+/// # Examples
+/// ```
+/// use fuels_core::abi_decoder::DecoderCursor;
+/// use fuels_core::Token;
+/// use fuels_types::param_types::ParamType;
+///
+/// let data = [0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 2];
+/// let mut cursor = DecoderCursor::new(&data);
+///
+/// assert_eq!(cursor.decode(&ParamType::U8).unwrap(), Token::U8(1));
+/// assert_eq!(cursor.offset(), 8);
+/// assert_eq!(cursor.decode(&ParamType::U64).unwrap(), Token::U64(2));
+/// assert!(cursor.is_empty());
+/// ```
+#[derive(Debug, Clone)]
+pub struct DecoderCursor<'a> {
+    data: &'a [u8],
+    offset: usize,
+    decoded_args: usize,
+}
+
+impl<'a> DecoderCursor<'a> {
+    pub fn new(data: &'a [u8]) -> Self {
+        Self {
+            data,
+            offset: 0,
+            decoded_args: 0,
         }
+    }
 
-        let result = DecodeResult {
-            token: Token::Tuple(tokens),
-            bytes_read,
-        };
+    /// Number of bytes consumed so far.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// The bytes that haven't been consumed yet.
+    pub fn remaining(&self) -> &'a [u8] {
+        self.data.get(self.offset..).unwrap_or_default()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.remaining().is_empty()
+    }
+
+    /// Decodes the next value in the buffer as a `param`.
+    /// On failure, the cursor is left where it was before the call.
+    pub fn decode(&mut self, param: &ParamType) -> Result<Token, DecodingError> {
+        self.decode_arg(param, None)
+    }
 
-        Ok(result)
+    /// Same as `decode`, but uses the names found in `property` (and its
+    /// components) to describe the failing element in errors.
+    pub fn decode_property(
+        &mut self,
+        param: &ParamType,
+        property: &Property,
+    ) -> Result<Token, DecodingError> {
+        self.decode_arg(param, Some(property))
     }
 
-    fn decode_struct(data: &[u8], props: &Vec<ParamType>) -> Result<DecodeResult, CodecError> {
-        let mut tokens = vec![];
+    /// Decodes the next values in the buffer, one for each of `types`.
+    pub fn decode_all(&mut self, types: &[ParamType]) -> Result<Vec<Token>, DecodingError> {
+        types.iter().map(|param| self.decode(param)).collect()
+    }
+
+    fn decode_arg(
+        &mut self,
+        param: &ParamType,
+        property: Option<&Property>,
+    ) -> Result<Token, DecodingError> {
+        let start = self.offset;
+        let mut path = vec![PathSegment::Arg(self.decoded_args)];
 
-        let mut bytes_read = 0;
-        for prop in props {
-            let res = Self::decode_param(prop, &data[bytes_read..])?;
-            bytes_read += res.bytes_read;
-            tokens.push(res.token);
+        match self.decode_param(param, property, &mut path) {
+            Ok(token) => {
+                self.decoded_args += 1;
+                Ok(token)
+            }
+            Err(err) => {
+                self.offset = start;
+                Err(err)
+            }
         }
+    }
 
-        let result = DecodeResult {
-            token: Token::Struct(tokens),
-            bytes_read,
+    fn decode_param<'p>(
+        &mut self,
+        param: &ParamType,
+        property: Option<&'p Property>,
+        path: &mut Vec<PathSegment<'p>>,
+    ) -> Result<Token, DecodingError> {
+        let start = self.offset;
+        let result = match param {
+            ParamType::Unit => self.decode_unit(),
+            ParamType::U8 => self.decode_u8(),
+            ParamType::U16 => self.decode_u16(),
+            ParamType::U32 => self.decode_u32(),
+            ParamType::U64 => self.decode_u64(),
+            ParamType::Bool => self.decode_bool(),
+            ParamType::Byte => self.decode_byte(),
+            ParamType::B256 => self.decode_b256(),
+            ParamType::String(length) => self.decode_string(*length),
+            ParamType::Array(t, length) => return self.decode_array(t, *length, property, path),
+            ParamType::Struct(props) => {
+                let tokens = self.decode_fields(props, property, path)?;
+                return Ok(Token::Struct(tokens));
+            }
+            ParamType::Enum(variants) => return self.decode_enum(variants, property, path),
+            ParamType::Tuple(types) => {
+                let tokens = self.decode_fields(types, property, path)?;
+                return Ok(Token::Tuple(tokens));
+            }
         };
 
-        Ok(result)
+        result.map_err(|error| Self::error_at(start, path, error))
+    }
+
+    /// Decodes the elements of a struct or a tuple.
+    fn decode_fields<'p>(
+        &mut self,
+        types: &[ParamType],
+        property: Option<&'p Property>,
+        path: &mut Vec<PathSegment<'p>>,
+    ) -> Result<Vec<Token>, DecodingError> {
+        let mut tokens = Vec::with_capacity(types.len());
+        for (index, param) in types.iter().enumerate() {
+            let component = component_of(property, index);
+            path.push(PathSegment::Field(
+                index,
+                component.map(|c| c.name.as_str()),
+            ));
+            tokens.push(self.decode_param(param, component, path)?);
+            path.pop();
+        }
+
+        Ok(tokens)
     }
 
-    fn decode_array(
-        data: &[u8],
+    fn decode_array<'p>(
+        &mut self,
         t: &ParamType,
-        length: &usize,
-    ) -> Result<DecodeResult, CodecError> {
-        let mut tokens = vec![];
-        let mut bytes_read = 0;
-
-        for _ in 0..*length {
-            let res = Self::decode_param(t, &data[bytes_read..])?;
-            bytes_read += res.bytes_read;
-            tokens.push(res.token);
+        length: usize,
+        property: Option<&'p Property>,
+        path: &mut Vec<PathSegment<'p>>,
+    ) -> Result<Token, DecodingError> {
+        // Arrays of custom types hold the element's `Property` as their only component.
+        let element = component_of(property, 0);
+
+        let mut tokens = Vec::with_capacity(length);
+        for index in 0..length {
+            path.push(PathSegment::Index(index));
+            tokens.push(self.decode_param(t, element, path)?);
+            path.pop();
         }
 
-        let result = DecodeResult {
-            token: Token::Array(tokens),
-            bytes_read,
+        Ok(Token::Array(tokens))
+    }
+
+    /// The encoding follows the ABI specs defined
+    /// [here](https://github.com/FuelLabs/fuel-specs/blob/1be31f70c757d8390f74b9e1b3beb096620553eb/specs/protocol/abi.md)
+    ///
+    /// # Arguments
+    ///
+    /// * `variants`: all types that this particular enum type could hold
+    /// * `property`: the enum's `Property`, if known, used to name its variants
+    fn decode_enum<'p>(
+        &mut self,
+        variants: &EnumVariants,
+        property: Option<&'p Property>,
+        path: &mut Vec<PathSegment<'p>>,
+    ) -> Result<Token, DecodingError> {
+        let start = self.offset;
+
+        let discriminant =
+            peek_u32(self.remaining()).map_err(|error| Self::error_at(start, path, error))?;
+        let selected_variant = type_of_selected_variant(variants, discriminant as usize)
+            .map_err(|error| Self::error_at(start, path, error))?;
+
+        let enum_width = compute_encoding_width_of_enum(variants);
+
+        // The sway compiler has an optimization where enums that only contain
+        // units for variants have only their discriminant encoded. Because of
+        // this we construct the Token::Unit rather than calling `decode_param`
+        // since that will consume a WORD from `data`.
+        let token = if variants.only_units_inside() {
+            Token::Unit
+        } else {
+            let words_to_skip = enum_width - compute_encoding_width(selected_variant);
+            self.offset = start + words_to_skip * WORD_SIZE;
+
+            let component = component_of(property, discriminant as usize);
+            path.push(PathSegment::Field(
+                discriminant as usize,
+                component.map(|c| c.name.as_str()),
+            ));
+            let token = self.decode_param(selected_variant, component, path)?;
+            path.pop();
+
+            token
         };
 
-        Ok(result)
+        self.offset = start + enum_width * WORD_SIZE;
+
+        let selector = Box::new((discriminant as u8, token, variants.clone()));
+        Ok(Token::Enum(selector))
     }
 
-    fn decode_string(data: &[u8], length: &usize) -> Result<DecodeResult, CodecError> {
-        let encoded_str = peek(data, *length)?;
+    fn decode_string(&mut self, length: usize) -> Result<Token, CodecError> {
+        let encoded_str = peek(self.remaining(), length)?;
 
         let decoded = str::from_utf8(encoded_str)?;
 
-        let result = DecodeResult {
-            token: Token::String(decoded.to_string()),
-            bytes_read: padded_len(encoded_str),
-        };
-
-        Ok(result)
+        self.offset += padded_len(encoded_str);
+        Ok(Token::String(decoded.to_string()))
     }
 
-    fn decode_b256(data: &[u8]) -> Result<DecodeResult, CodecError> {
-        Ok(DecodeResult {
-            token: Token::B256(*peek_fixed::<32>(data)?),
-            bytes_read: 32,
-        })
+    fn decode_b256(&mut self) -> Result<Token, CodecError> {
+        let token = Token::B256(*peek_fixed::<32>(self.remaining())?);
+        self.offset += 32;
+        Ok(token)
     }
 
-    fn decode_byte(data: &[u8]) -> Result<DecodeResult, CodecError> {
-        Ok(DecodeResult {
-            token: Token::Byte(peek_u8(data)?),
-            bytes_read: 8,
-        })
+    fn decode_byte(&mut self) -> Result<Token, CodecError> {
+        let token = Token::Byte(peek_u8(self.remaining())?);
+        self.offset += WORD_SIZE;
+        Ok(token)
     }
 
-    fn decode_bool(data: &[u8]) -> Result<DecodeResult, CodecError> {
+    fn decode_bool(&mut self) -> Result<Token, CodecError> {
         // Grab last byte of the word and compare it to 0x00
-        let b = peek_u8(data)? != 0u8;
-
-        let result = DecodeResult {
-            token: Token::Bool(b),
-            bytes_read: 8,
-        };
-
-        Ok(result)
+        let token = Token::Bool(peek_u8(self.remaining())? != 0u8);
+        self.offset += WORD_SIZE;
+        Ok(token)
     }
 
-    fn decode_u64(data: &[u8]) -> Result<DecodeResult, CodecError> {
-        Ok(DecodeResult {
-            token: Token::U64(peek_u64(data)?),
-            bytes_read: 8,
-        })
+    fn decode_u64(&mut self) -> Result<Token, CodecError> {
+        let token = Token::U64(peek_u64(self.remaining())?);
+        self.offset += WORD_SIZE;
+        Ok(token)
     }
 
-    fn decode_u32(data: &[u8]) -> Result<DecodeResult, CodecError> {
-        Ok(DecodeResult {
-            token: Token::U32(peek_u32(data)?),
-            bytes_read: 8,
-        })
+    fn decode_u32(&mut self) -> Result<Token, CodecError> {
+        let token = Token::U32(peek_u32(self.remaining())?);
+        self.offset += WORD_SIZE;
+        Ok(token)
     }
 
-    fn decode_u16(data: &[u8]) -> Result<DecodeResult, CodecError> {
-        Ok(DecodeResult {
-            token: Token::U16(peek_u16(data)?),
-            bytes_read: 8,
-        })
+    fn decode_u16(&mut self) -> Result<Token, CodecError> {
+        let token = Token::U16(peek_u16(self.remaining())?);
+        self.offset += WORD_SIZE;
+        Ok(token)
     }
 
-    fn decode_u8(data: &[u8]) -> Result<DecodeResult, CodecError> {
-        Ok(DecodeResult {
-            token: Token::U8(peek_u8(data)?),
-            bytes_read: 8,
-        })
+    fn decode_u8(&mut self) -> Result<Token, CodecError> {
+        let token = Token::U8(peek_u8(self.remaining())?);
+        self.offset += WORD_SIZE;
+        Ok(token)
     }
 
-    fn decode_unit(data: &[u8]) -> Result<DecodeResult, CodecError> {
+    fn decode_unit(&mut self) -> Result<Token, CodecError> {
         // We don't need the data, we're doing this purely as a bounds
         // check.
-        peek_fixed::<WORD_SIZE>(data)?;
-        Ok(DecodeResult {
-            token: Token::Unit,
-            bytes_read: WORD_SIZE,
-        })
+        peek_fixed::<WORD_SIZE>(self.remaining())?;
+        self.offset += WORD_SIZE;
+        Ok(Token::Unit)
     }
 
-    /// The encoding follows the ABI specs defined
-    /// [here](https://github.com/FuelLabs/fuel-specs/blob/1be31f70c757d8390f74b9e1b3beb096620553eb/specs/protocol/abi.md)
-    ///
-    /// # Arguments
-    ///
-    /// * `data`: slice of encoded data on whose beginning we're expecting an encoded enum
-    /// * `variants`: all types that this particular enum type could hold
-    fn decode_enum(data: &[u8], variants: &EnumVariants) -> Result<DecodeResult, CodecError> {
-        let discriminant = peek_u32(data)?;
-        let selected_variant = Self::type_of_selected_variant(variants, discriminant as usize)?;
-
-        let enum_width = compute_encoding_width_of_enum(variants);
-        let token = Self::decode_token_in_enum(data, variants, selected_variant, enum_width)?;
-
-        let selector = Box::new((discriminant as u8, token, variants.clone()));
-        Ok(DecodeResult {
-            token: Token::Enum(selector),
-            bytes_read: enum_width * WORD_SIZE,
-        })
-    }
-
-    fn decode_token_in_enum(
-        data: &[u8],
-        variants: &EnumVariants,
-        selected_variant: &ParamType,
-        enum_width: usize,
-    ) -> Result<Token, CodecError> {
-        // The sway compiler has an optimization where enums that only contain
-        // units for variants have only their discriminant encoded. Because of
-        // this we construct the Token::Unit rather than calling `decode_param`
-        // since that will consume a WORD from `data`.
-        if variants.only_units_inside() {
-            Ok(Token::Unit)
-        } else {
-            let words_to_skip = enum_width - compute_encoding_width(selected_variant);
-
-            let res = Self::decode_param(selected_variant, &data[words_to_skip * WORD_SIZE..])?;
-            Ok(res.token)
+    fn error_at(offset: usize, path: &[PathSegment], error: CodecError) -> DecodingError {
+        DecodingError {
+            offset,
+            path: render_path(path),
+            error,
         }
     }
+}
 
-    /// Returns a variant from `variants` pointed to by `discriminant`.
-    /// Will fail if `discriminant` is out of bounds.
-    fn type_of_selected_variant(
-        variants: &EnumVariants,
-        discriminant: usize,
-    ) -> Result<&ParamType, CodecError> {
-        variants.param_types().get(discriminant).ok_or_else(|| {
-            let msg = format!(
-                concat!(
-                    "Error while decoding an enum. The discriminant '{}' doesn't ",
-                    "point to any of the following variants: {:?}"
-                ),
-                discriminant, variants
-            );
-            CodecError::InvalidData(msg)
+fn component_of(property: Option<&Property>, index: usize) -> Option<&Property> {
+    property
+        .and_then(|p| p.components.as_ref())
+        .and_then(|components| components.get(index))
+}
+
+fn render_path(path: &[PathSegment]) -> String {
+    path.iter()
+        .map(|segment| match segment {
+            PathSegment::Arg(index) => format!("arg[{}]", index),
+            PathSegment::Index(index) => format!("[{}]", index),
+            PathSegment::Field(_, Some(name)) if !name.is_empty() => format!(".{}", name),
+            PathSegment::Field(index, _) => format!(".{}", index),
         })
-    }
+        .collect()
+}
+
+/// Returns a variant from `variants` pointed to by `discriminant`.
+/// Will fail if `discriminant` is out of bounds.
+fn type_of_selected_variant(
+    variants: &EnumVariants,
+    discriminant: usize,
+) -> Result<&ParamType, CodecError> {
+    variants.param_types().get(discriminant).ok_or_else(|| {
+        let msg = format!(
+            concat!(
+                "Error while decoding an enum. The discriminant '{}' doesn't ",
+                "point to any of the following variants: {:?}"
+            ),
+            discriminant, variants
+        );
+        CodecError::InvalidData(msg)
+    })
 }
 
 fn peek_u64(data: &[u8]) -> Result<u64, CodecError> {
@@ -647,4 +750,94 @@ mod tests {
         assert!(matches!(error, CodecError::InvalidData(str) if str.starts_with(expected_msg)));
         Ok(())
     }
+
+    #[test]
+    fn cursor_decodes_values_one_after_another() -> Result<(), Error> {
+        let data = [
+            0, 0, 0, 0, 0, 0, 0, 1, // u8
+            0x61, 0x62, 0x63, 0, 0, 0, 0, 0, // "abc" + padding
+            0, 0, 0, 0, 0, 0, 0, 3, // u64
+        ];
+        let mut cursor = DecoderCursor::new(&data);
+
+        assert_eq!(cursor.decode(&ParamType::U8)?, Token::U8(1));
+        assert_eq!(cursor.offset(), 8);
+
+        assert_eq!(
+            cursor.decode(&ParamType::String(3))?,
+            Token::String("abc".to_string())
+        );
+        assert_eq!(cursor.offset(), 16);
+
+        assert_eq!(cursor.decode(&ParamType::U64)?, Token::U64(3));
+        assert!(cursor.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn cursor_errors_report_offset_and_path() {
+        // The second element of the inner array is cut short.
+        let data = [
+            0, 0, 0, 0, 0, 0, 0, 1, // arg[0]: u8
+            0, 0, 0, 0, 0, 0, 0, 1, // arg[1].0: bool
+            0, 0, 0, 0, 0, 0, 0, 2, // arg[1].1[0]: u32
+            0, 0, 0, 0, // arg[1].1[1]: u32, truncated
+        ];
+        let a_struct = ParamType::Struct(vec![
+            ParamType::Bool,
+            ParamType::Array(Box::new(ParamType::U32), 2),
+        ]);
+        let mut cursor = DecoderCursor::new(&data);
+
+        cursor.decode(&ParamType::U8).unwrap();
+        let error = cursor
+            .decode(&a_struct)
+            .expect_err("Should have resulted in an error");
+
+        assert_eq!(error.offset, 24);
+        assert_eq!(error.path, "arg[1].1[1]");
+        assert!(error.to_string().starts_with(
+            "failed to decode `arg[1].1[1]` at byte offset 24: requested data out of bounds"
+        ));
+        // A failed decode doesn't consume anything.
+        assert_eq!(cursor.offset(), 8);
+    }
+
+    #[test]
+    fn cursor_errors_use_property_names() -> Result<(), Error> {
+        let property: Property = serde_json::from_str(
+            r#"{
+                "name": "top_value",
+                "type": "struct MyStruct",
+                "components": [
+                    { "name": "x", "type": "u16" },
+                    {
+                        "name": "kind",
+                        "type": "enum Kind",
+                        "components": [
+                            { "name": "Active", "type": "u64" },
+                            { "name": "Named", "type": "str[4]" }
+                        ]
+                    }
+                ]
+            }"#,
+        )
+        .unwrap();
+        let variants = EnumVariants::new(vec![ParamType::U64, ParamType::String(4)])?;
+        let a_struct = ParamType::Struct(vec![ParamType::U16, ParamType::Enum(variants)]);
+        let data = [
+            0, 0, 0, 0, 0, 0, 0, 1, // x
+            0, 0, 0, 0, 0, 0, 0, 1, // discriminant of `Named`
+            0xff, 0xff, 0xff, 0xff, 0, 0, 0, 0, // invalid utf-8
+        ];
+
+        let error = DecoderCursor::new(&data)
+            .decode_property(&a_struct, &property)
+            .expect_err("Should have resulted in an error");
+
+        assert_eq!(error.offset, 16);
+        assert_eq!(error.path, "arg[0].kind.Named");
+        assert!(matches!(error.error, CodecError::Utf8Error(_)));
+        Ok(())
+    }
 }
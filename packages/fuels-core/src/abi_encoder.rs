@@ -34,6 +34,113 @@ impl ABIEncoder {
         Ok(encoder.buffer)
     }
 
+    /// Same as `encode`, but first checks that every `Token` in `args`,
+    /// including the ones nested inside structs, enums, arrays and tuples,
+    /// matches the `ParamType` declared for it in `types`.
+    ///
+    /// # Examples
+    /// ```
+    /// use fuels_core::{abi_encoder::ABIEncoder, Token};
+    /// use fuels_types::{errors::CodecError, param_types::ParamType};
+    ///
+    /// let encoded = ABIEncoder::encode_with_types(&[ParamType::U64], &[Token::U64(10)]).unwrap();
+    /// assert_eq!(encoded, [0, 0, 0, 0, 0, 0, 0, 10]);
+    ///
+    /// let error = ABIEncoder::encode_with_types(&[ParamType::U64], &[Token::U32(10)]).unwrap_err();
+    /// assert!(matches!(
+    ///     error,
+    ///     CodecError::InvalidData(msg) if msg == "type mismatch at `arg[0]`: expected U64, got U32(10)"
+    /// ));
+    /// ```
+    pub fn encode_with_types(types: &[ParamType], args: &[Token]) -> Result<Vec<u8>, CodecError> {
+        if types.len() != args.len() {
+            return Err(CodecError::InvalidData(format!(
+                "expected {} arguments, got {}",
+                types.len(),
+                args.len()
+            )));
+        }
+
+        for (index, (param, arg)) in types.iter().zip(args).enumerate() {
+            Self::type_check(param, arg, &format!("arg[{}]", index))?;
+        }
+
+        Self::encode(args)
+    }
+
+    fn type_check(param: &ParamType, arg: &Token, path: &str) -> Result<(), CodecError> {
+        let mismatch = |reason: String| {
+            CodecError::InvalidData(format!("type mismatch at `{}`: {}", path, reason))
+        };
+
+        match (param, arg) {
+            (ParamType::Unit, Token::Unit)
+            | (ParamType::U8, Token::U8(_))
+            | (ParamType::U16, Token::U16(_))
+            | (ParamType::U32, Token::U32(_))
+            | (ParamType::U64, Token::U64(_))
+            | (ParamType::Bool, Token::Bool(_))
+            | (ParamType::Byte, Token::Byte(_))
+            | (ParamType::B256, Token::B256(_)) => Ok(()),
+            (ParamType::String(length), Token::String(string)) => {
+                if string.len() != *length {
+                    return Err(mismatch(format!(
+                        "expected a string of length {}, got one of length {}",
+                        length,
+                        string.len()
+                    )));
+                }
+                Ok(())
+            }
+            (ParamType::Array(element, length), Token::Array(tokens)) => {
+                if tokens.len() != *length {
+                    return Err(mismatch(format!(
+                        "expected an array of {} elements, got {}",
+                        length,
+                        tokens.len()
+                    )));
+                }
+                tokens.iter().enumerate().try_for_each(|(index, token)| {
+                    Self::type_check(element, token, &format!("{}[{}]", path, index))
+                })
+            }
+            (ParamType::Struct(types), Token::Struct(tokens))
+            | (ParamType::Tuple(types), Token::Tuple(tokens)) => {
+                if tokens.len() != types.len() {
+                    return Err(mismatch(format!(
+                        "expected {} fields, got {}",
+                        types.len(),
+                        tokens.len()
+                    )));
+                }
+                types
+                    .iter()
+                    .zip(tokens)
+                    .enumerate()
+                    .try_for_each(|(index, (param, token))| {
+                        Self::type_check(param, token, &format!("{}.{}", path, index))
+                    })
+            }
+            (ParamType::Enum(variants), Token::Enum(selector)) => {
+                let (discriminant, token, token_variants) = selector.as_ref();
+                if token_variants != variants {
+                    return Err(mismatch(format!(
+                        "expected an enum with variants {:?}, got one with variants {:?}",
+                        variants.param_types(),
+                        token_variants.param_types()
+                    )));
+                }
+                if variants.only_units_inside() {
+                    // Only the discriminant gets encoded in this case.
+                    return Self::type_of_chosen_variant(discriminant, variants).map(|_| ());
+                }
+                let variant = Self::type_of_chosen_variant(discriminant, variants)?;
+                Self::type_check(variant, token, &format!("{}.{}", path, discriminant))
+            }
+            _ => Err(mismatch(format!("expected {:?}, got {:?}", param, arg))),
+        }
+    }
+
     fn new() -> Self {
         ABIEncoder {
             buffer: Default::default(),
@@ -897,4 +1004,73 @@ mod tests {
         assert_eq!(actual, expected);
         Ok(())
     }
+
+    #[test]
+    fn encode_with_types_accepts_matching_tokens() -> Result<(), Error> {
+        let variants = EnumVariants::new(vec![ParamType::U64, ParamType::Bool])?;
+        let types = [
+            ParamType::Struct(vec![
+                ParamType::Array(Box::new(ParamType::U8), 2),
+                ParamType::String(3),
+            ]),
+            ParamType::Enum(variants.clone()),
+        ];
+        let args = [
+            Token::Struct(vec![
+                Token::Array(vec![Token::U8(1), Token::U8(2)]),
+                Token::String("abc".to_string()),
+            ]),
+            Token::Enum(Box::new((1, Token::Bool(true), variants))),
+        ];
+
+        let actual = ABIEncoder::encode_with_types(&types, &args)?;
+
+        assert_eq!(actual, ABIEncoder::encode(&args)?);
+        Ok(())
+    }
+
+    #[test]
+    fn encode_with_types_detects_nested_mismatches() -> Result<(), Error> {
+        let assert_mismatch = |types: &[ParamType], args: &[Token], expected: &str| {
+            let error = ABIEncoder::encode_with_types(types, args)
+                .expect_err("Should have resulted in an error");
+            assert!(
+                matches!(&error, CodecError::InvalidData(msg) if msg == expected),
+                "unexpected error: {}",
+                error
+            );
+        };
+
+        assert_mismatch(&[ParamType::U64], &[], "expected 1 arguments, got 0");
+
+        let a_struct = ParamType::Struct(vec![
+            ParamType::Bool,
+            ParamType::Array(Box::new(ParamType::U64), 2),
+        ]);
+        assert_mismatch(
+            &[ParamType::U8, a_struct],
+            &[
+                Token::U8(1),
+                Token::Struct(vec![
+                    Token::Bool(true),
+                    Token::Array(vec![Token::U64(1), Token::U32(2)]),
+                ]),
+            ],
+            "type mismatch at `arg[1].1[1]`: expected U64, got U32(2)",
+        );
+
+        assert_mismatch(
+            &[ParamType::String(4)],
+            &[Token::String("abc".to_string())],
+            "type mismatch at `arg[0]`: expected a string of length 4, got one of length 3",
+        );
+
+        let variants = EnumVariants::new(vec![ParamType::U64, ParamType::Bool])?;
+        assert_mismatch(
+            &[ParamType::Enum(variants.clone())],
+            &[Token::Enum(Box::new((1, Token::U64(1), variants)))],
+            "type mismatch at `arg[0].1`: expected Bool, got U64(1)",
+        );
+        Ok(())
+    }
 }
@@ -1,16 +1,20 @@
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::env;
 
 use crate::code_gen::bindings::ContractBindings;
 use crate::code_gen::custom_types_gen::{
     expand_custom_enum, expand_custom_struct, extract_custom_type_name_from_abi_property,
+    CustomTypeOptions,
 };
 use crate::code_gen::functions_gen::expand_function;
+use crate::code_gen::metadata_gen::expand_abi_metadata;
 use crate::constants::{ADDRESS_SWAY_NATIVE_TYPE, CONTRACT_ID_SWAY_NATIVE_TYPE};
-use crate::json_abi::ABIParser;
+use crate::json_abi::{parse_param, ABIParser};
 use crate::source::Source;
-use crate::utils::ident;
+use crate::utils::{ident, safe_ident};
 use fuels_types::errors::Error;
 use fuels_types::{JsonABI, Property};
+use inflector::Inflector;
 use proc_macro2::{Ident, TokenStream};
 use quote::quote;
 
@@ -18,6 +22,12 @@ pub struct Abigen {
     /// The parsed ABI.
     abi: JsonABI,
 
+    /// The ABI as JSON, embedded in the generated bindings.
+    abi_json: String,
+
+    /// Where the ABI comes from, used in error messages.
+    origin: String,
+
     /// The parser used to transform the JSON format into `JsonABI`
     abi_parser: ABIParser,
 
@@ -28,18 +38,42 @@ pub struct Abigen {
 
     custom_enums: HashMap<String, Property>,
 
-    /// Format the code using a locally installed copy of `rustfmt`.
+    /// Derives and serde attributes of the generated custom types.
+    custom_type_options: CustomTypeOptions,
+
+    /// Custom types replaced by existing Rust types, mapping the name of the
+    /// custom type to the path of the Rust type.
+    type_substitutions: BTreeMap<String, String>,
+
+    /// Format the generated code when writing it.
     rustfmt: bool,
 
     /// Generate no-std safe code
     no_std: bool,
+
+    /// Absolute path to the contract's binary, used to generate `deploy()`.
+    bin_path: Option<String>,
+
+    /// Absolute path to the contract's storage slots, used by `deploy()`.
+    storage_path: Option<String>,
 }
 
 impl Abigen {
     /// Creates a new contract with the given ABI JSON source.
     pub fn new<S: AsRef<str>>(contract_name: &str, abi_source: S) -> Result<Self, Error> {
-        let source = Source::parse(abi_source).unwrap();
-        let mut parsed_abi: JsonABI = serde_json::from_str(&source.get().unwrap())?;
+        let source = Source::parse(abi_source)
+            .map_err(|err| Error::InvalidData(format!("failed to locate the ABI: {:#}", err)))?;
+        let abi_json = source
+            .get()
+            .map_err(|err| Error::InvalidData(format!("{:#}", err)))?;
+        let origin = match &source {
+            Source::Local(path) => format!("`{}`", path.display()),
+            Source::String(_) => "the inline ABI".to_string(),
+        };
+
+        let mut parsed_abi: JsonABI = serde_json::from_str(&abi_json).map_err(|err| {
+            Error::InvalidData(format!("failed to parse {} as a JSON ABI: {}", origin, err))
+        })?;
 
         // Filter out outputs with empty returns. These are
         // generated by forc's json abi as `"name": ""` and `"type": "()"`
@@ -54,7 +88,7 @@ impl Abigen {
                 None => continue,
             };
         }
-        let custom_types = Abigen::get_custom_types(&parsed_abi);
+        let custom_types = Abigen::get_custom_types(&parsed_abi)?;
         Ok(Self {
             custom_structs: custom_types
                 .clone()
@@ -66,10 +100,91 @@ impl Abigen {
                 .filter(|(_, p)| p.is_enum_type())
                 .collect(),
             abi: parsed_abi,
-            contract_name: ident(contract_name),
+            abi_json,
+            origin,
+            contract_name: ident(contract_name)?,
             abi_parser: ABIParser::new(),
             rustfmt: true,
             no_std: false,
+            bin_path: None,
+            storage_path: None,
+            custom_type_options: CustomTypeOptions::default(),
+            type_substitutions: BTreeMap::new(),
+        })
+    }
+
+    /// Checks that every type of the ABI can be parsed, and that every name
+    /// the bindings use is a valid identifier, so that a malformed type or
+    /// name is reported along with its location in the ABI rather than as a
+    /// panic while generating the bindings.
+    fn check_types(abi: &JsonABI, origin: &str) -> Result<(), Error> {
+        for (i, function) in abi.iter().enumerate() {
+            let path = format!("$[{}]", i);
+            if !function.name.is_empty() {
+                let name = safe_ident(&function.name);
+                Abigen::check_name(name, &function.name, &path, origin)?;
+            }
+
+            for (j, input) in function.inputs.iter().enumerate() {
+                let path = format!("{}.inputs[{}]", path, j);
+                if !input.name.is_empty() {
+                    let name = safe_ident(&input.name.to_snake_case());
+                    Abigen::check_name(name, &input.name, &path, origin)?;
+                }
+                Abigen::check_property_types(input, &path, origin)?;
+            }
+            for (j, output) in function.outputs.iter().enumerate() {
+                let path = format!("{}.outputs[{}]", path, j);
+                Abigen::check_property_types(output, &path, origin)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    // Checks the innermost types first, so that the reported location is
+    // the most precise one.
+    fn check_property_types(prop: &Property, path: &str, origin: &str) -> Result<(), Error> {
+        for (i, component) in prop.components.iter().flatten().enumerate() {
+            let path = format!("{}.components[{}]", path, i);
+            // The fields of a struct and the variants of an enum are named
+            // after their components.
+            if prop.is_struct_type() {
+                let name = ident(&component.name.to_snake_case());
+                Abigen::check_name(name, &component.name, &path, origin)?;
+            } else if prop.is_enum_type() {
+                Abigen::check_name(ident(&component.name), &component.name, &path, origin)?;
+            }
+            Abigen::check_property_types(component, &path, origin)?;
+        }
+
+        let type_error = |err: Error| {
+            Error::InvalidType(format!(
+                "`{}` at `{}` in {}: {}",
+                prop.type_field, path, origin, err
+            ))
+        };
+        parse_param(prop).map_err(type_error)?;
+        if prop.is_struct_type() || prop.is_enum_type() {
+            let name =
+                extract_custom_type_name_from_abi_property(prop, None).map_err(type_error)?;
+            ident(&name).map_err(type_error)?;
+        }
+
+        Ok(())
+    }
+
+    fn check_name(
+        ident: Result<Ident, Error>,
+        name: &str,
+        path: &str,
+        origin: &str,
+    ) -> Result<(), Error> {
+        ident.map(|_| ()).map_err(|err| {
+            Error::InvalidType(format!(
+                "`{}` at `{}.name` in {}: {}",
+                name, path, origin, err
+            ))
         })
     }
 
@@ -78,6 +193,92 @@ impl Abigen {
         self
     }
 
+    /// Ties the bindings to the contract's binary, generating a `deploy()`
+    /// function returning an instance of the deployed contract. Like the ABI's,
+    /// a relative path is rooted in the current working directory.
+    pub fn bin<S: AsRef<str>>(mut self, bin_path: S) -> Result<Self, Error> {
+        self.bin_path = Some(Abigen::resolve_path(bin_path.as_ref())?);
+        Ok(self)
+    }
+
+    /// Sets the storage slots `deploy()` initializes the contract with, unless
+    /// its `StorageConfiguration` points to another file.
+    pub fn storage<S: AsRef<str>>(mut self, storage_path: S) -> Result<Self, Error> {
+        self.storage_path = Some(Abigen::resolve_path(storage_path.as_ref())?);
+        Ok(self)
+    }
+
+    /// Adds derives to the generated structs and enums, on top of `Clone`,
+    /// `Debug`, `Eq` and `PartialEq`, e.g. `Hash` or `serde::Serialize`.
+    pub fn derives<I, S>(mut self, derives: I) -> Result<Self, Error>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.custom_type_options.add_derives(derives)?;
+        Ok(self)
+    }
+
+    /// Adds a `#[serde(rename_all = "<rule>")]` attribute to the generated
+    /// structs and enums, which must then derive serde's traits.
+    pub fn serde_rename_all(mut self, rule: &str) -> Result<Self, Error> {
+        self.custom_type_options.set_serde_rename_all(rule)?;
+        Ok(self)
+    }
+
+    /// Uses an existing Rust type instead of generating one for a custom type
+    /// of the ABI, given as `struct Name` or `enum Name`. The Rust type must
+    /// implement `Tokenizable` and `Parameterize`, and `rust_type` must be a
+    /// path valid from within the generated module, such as `crate::Identity`
+    /// or `my_crate::Identity`.
+    pub fn substitute_type(mut self, abi_type: &str, rust_type: &str) -> Result<Self, Error> {
+        let name = match abi_type.split_whitespace().collect::<Vec<_>>()[..] {
+            ["struct", name] if self.custom_structs.contains_key(name) => name,
+            ["enum", name] if self.custom_enums.contains_key(name) => name,
+            ["struct", _] | ["enum", _] => {
+                return Err(Error::InvalidType(format!(
+                    "the ABI has no `{}` to substitute",
+                    abi_type
+                )))
+            }
+            _ => {
+                return Err(Error::InvalidType(format!(
+                    "expected the type to substitute as `struct Name` or `enum Name`, found `{}`",
+                    abi_type
+                )))
+            }
+        };
+        if syn::parse_str::<syn::Path>(rust_type).is_err() {
+            return Err(Error::InvalidType(format!(
+                "`{}` is not a valid Rust type path",
+                rust_type
+            )));
+        }
+
+        self.type_substitutions
+            .insert(name.to_string(), rust_type.to_string());
+        Ok(self)
+    }
+
+    /// Whether the ABI has the custom type `abi_type`, given as `struct Name`
+    /// or `enum Name`.
+    pub fn has_custom_type(&self, abi_type: &str) -> bool {
+        match abi_type.split_whitespace().collect::<Vec<_>>()[..] {
+            ["struct", name] => self.custom_structs.contains_key(name),
+            ["enum", name] => self.custom_enums.contains_key(name),
+            _ => false,
+        }
+    }
+
+    fn resolve_path(path: &str) -> Result<String, Error> {
+        let path = env::current_dir()?.join(path);
+        let path = path.canonicalize().map_err(|err| {
+            Error::InvalidData(format!("couldn't find `{}`: {}", path.display(), err))
+        })?;
+
+        Ok(path.display().to_string())
+    }
+
     /// Generates the contract bindings.
     pub fn generate(self) -> Result<ContractBindings, Error> {
         let rustfmt = self.rustfmt;
@@ -96,58 +297,89 @@ impl Abigen {
     /// set of `TokenStream`. This generated Rust code is the brought into scope
     /// after it is called through a procedural macro (`abigen!()` in our case).
     pub fn expand(&self) -> Result<TokenStream, Error> {
-        let name = &self.contract_name;
-        let name_mod = ident(&format!(
+        let name_mod = self.name_mod()?;
+        let module = self.expand_module(&HashSet::new())?;
+
+        Ok(quote! {
+            pub use #name_mod::*;
+
+            #module
+        })
+    }
+
+    fn name_mod(&self) -> Result<Ident, Error> {
+        ident(&format!(
             "{}_mod",
             self.contract_name.to_string().to_lowercase()
-        ));
+        ))
+    }
+
+    /// Expands the contract's module, leaving out the custom types named in
+    /// `shared_types`, which are imported from a sibling `shared_types` module
+    /// instead.
+    fn expand_module(&self, shared_types: &HashSet<String>) -> Result<TokenStream, Error> {
+        Abigen::check_types(&self.abi, &self.origin)?;
+
+        let name = &self.contract_name;
+        let name_mod = self.name_mod()?;
 
         let contract_functions = self.functions()?;
-        let abi_structs = self.abi_structs()?;
-        let abi_enums = self.abi_enums()?;
-
-        let (includes, code) = if self.no_std {
-            (
-                quote! {
-                    use alloc::{vec, vec::Vec};
-                    use fuels_core::{EnumSelector, Parameterize, Tokenizable, Token, try_from_bytes};
-                    use fuels_types::errors::Error as SDKError;
-                    use fuels_types::param_types::{ParamType, EnumVariants};
-                },
-                quote! {},
-            )
+        let abi_structs = self.abi_structs(shared_types)?;
+        let abi_enums = self.abi_enums(shared_types)?;
+        let abi_metadata = self.abi_metadata()?;
+        let deploy = self.deploy();
+
+        let includes = Abigen::includes(self.no_std);
+        let code = if self.no_std {
+            quote! {
+                #[doc = "Holds the contract's ABI metadata."]
+                pub struct #name;
+            }
         } else {
-            (
-                quote! {
-                    use fuels::contract::contract::{Contract, ContractCallHandler};
-                    use fuels::core::{EnumSelector, Parameterize, Tokenizable, Token, try_from_bytes};
-                    use fuels::signers::LocalWallet;
-                    use fuels::tx::{ContractId, Address};
-                    use fuels::types::errors::Error as SDKError;
-                    use fuels::types::param_types::{EnumVariants, ParamType};
-                    use std::str::FromStr;
-                },
-                quote! {
-                    pub struct #name {
-                        contract_id: ContractId,
-                        wallet: LocalWallet
+            quote! {
+                #[derive(Clone)]
+                pub struct #name<A = LocalWallet> {
+                    contract_id: ContractId,
+                    wallet: Option<A>,
+                    provider: Arc<Provider>
+                }
+
+                impl #name {
+                    #[doc = "Creates an instance without a wallet, whose calls can only be executed with `read()`."]
+                    pub fn new_read_only(contract_id: String, provider: Provider) -> Result<Self, SDKError> {
+                        let contract_id = ContractId::from_str(&contract_id).map_err(|err| {
+                            SDKError::InvalidData(format!("invalid contract id `{}`: {}", contract_id, err))
+                        })?;
+                        Ok(Self{ contract_id, wallet: None, provider: Arc::new(provider) })
                     }
+                }
 
-                    impl #name {
-                        pub fn new(contract_id: String, wallet: LocalWallet)
-                        -> Self {
-                            let contract_id = ContractId::from_str(&contract_id).expect("Invalid contract id");
-                            Self{ contract_id, wallet }
-                        }
-                        #contract_functions
+                impl<A: fuels::signers::Account + Clone> #name<A> {
+                    #[doc = "Creates an instance calling the contract as `wallet`, which must have a provider."]
+                    pub fn new(contract_id: String, wallet: A) -> Result<Self, SDKError> {
+                        let provider = wallet.get_provider()?.clone();
+                        Ok(#name::new_read_only(contract_id, provider)?.with_wallet(wallet))
                     }
-                },
-            )
+
+                    #[doc = "Returns an instance calling the contract as `wallet`, through this instance's provider. The wallet's own provider is replaced, so that its coins are looked up on the node the calls are sent to."]
+                    pub fn with_wallet<B: fuels::signers::Account + Clone>(&self, mut wallet: B) -> #name<B> {
+                        wallet.set_provider(self.provider.as_ref().clone());
+                        #name{ contract_id: self.contract_id, wallet: Some(wallet), provider: Arc::clone(&self.provider) }
+                    }
+                    #deploy
+                    #contract_functions
+                }
+            }
         };
 
-        Ok(quote! {
-            pub use #name_mod::*;
+        let shared_types_import = if shared_types.is_empty() {
+            quote! {}
+        } else {
+            quote! { use super::shared_types::*; }
+        };
+        let substitutions = Abigen::expand_type_substitutions(&self.type_substitutions)?;
 
+        Ok(quote! {
             #[allow(clippy::too_many_arguments)]
             pub mod #name_mod {
                 #![allow(clippy::enum_variant_names)]
@@ -155,15 +387,63 @@ impl Abigen {
                 #![allow(unused_imports)]
 
                 #includes
+                #shared_types_import
+                #substitutions
 
                 #code
 
+                #abi_metadata
+
                 #abi_structs
                 #abi_enums
             }
         })
     }
 
+    // Brings the substituted types in scope under the name of the custom
+    // type they replace, which the generated code refers to them by.
+    fn expand_type_substitutions(
+        substitutions: &BTreeMap<String, String>,
+    ) -> Result<TokenStream, Error> {
+        let mut imports = TokenStream::new();
+
+        for (name, rust_type) in substitutions {
+            let name = ident(name)?;
+            let rust_type = syn::parse_str::<syn::Path>(rust_type)
+                .map_err(|err| Error::InvalidType(err.to_string()))?;
+            imports.extend(quote! { use #rust_type as #name; });
+        }
+
+        Ok(imports)
+    }
+
+    fn includes(no_std: bool) -> TokenStream {
+        if no_std {
+            quote! {
+                use alloc::{format, vec, vec::Vec};
+                use fuels_core::abi_decoder::ABIDecoder;
+                use fuels_core::{EnumSelector, Parameterize, Tokenizable, Token, try_from_bytes};
+                use fuels_types::errors::Error as SDKError;
+                use fuels_types::param_types::{ParamType, EnumVariants};
+            }
+        } else {
+            quote! {
+                use fuels::contract::contract::{Contract, ContractCallHandler};
+                use fuels::core::abi_decoder::ABIDecoder;
+                use fuels::core::parameters::{StorageConfiguration, TxParameters};
+                use fuels::core::{EnumSelector, Parameterize, Tokenizable, Token, try_from_bytes};
+                use fuels::signers::LocalWallet;
+                use fuels::signers::ViewOnlyAccount as _;
+                use fuels::signers::provider::Provider;
+                use fuels::tx::{ContractId, Address};
+                use fuels::types::errors::Error as SDKError;
+                use fuels::types::param_types::{EnumVariants, ParamType};
+                use std::str::FromStr;
+                use std::sync::Arc;
+            }
+        }
+    }
+
     pub fn functions(&self) -> Result<TokenStream, Error> {
         let mut tokenized_functions = Vec::new();
 
@@ -180,22 +460,69 @@ impl Abigen {
         Ok(quote! { #( #tokenized_functions )* })
     }
 
-    fn abi_structs(&self) -> Result<TokenStream, Error> {
+    fn deploy(&self) -> TokenStream {
+        let bin_path = match &self.bin_path {
+            Some(bin_path) => bin_path,
+            None => return quote! {},
+        };
+
+        let storage_configuration = match &self.storage_path {
+            Some(storage_path) => quote! {
+                let storage_configuration = StorageConfiguration::new(
+                    storage_configuration
+                        .storage_path
+                        .or_else(|| Some(#storage_path.to_string())),
+                    storage_configuration.manual_storage_vec,
+                );
+            },
+            None => quote! {},
+        };
+
+        quote! {
+            #[doc = "Deploys the contract's binary and returns an instance of the deployed contract."]
+            pub async fn deploy(
+                wallet: &A,
+                params: TxParameters,
+                storage_configuration: StorageConfiguration,
+            ) -> Result<Self, SDKError> {
+                #storage_configuration
+                let contract_id = Contract::deploy(#bin_path, wallet, params, storage_configuration).await?;
+                let provider = Arc::new(wallet.get_provider()?.clone());
+                Ok(Self { contract_id, wallet: Some(wallet.clone()), provider })
+            }
+        }
+    }
+
+    fn abi_metadata(&self) -> Result<TokenStream, Error> {
+        expand_abi_metadata(
+            &self.contract_name,
+            &self.abi_json,
+            &self.abi,
+            &self.abi_parser,
+            &self.custom_enums,
+            &self.custom_structs,
+        )
+    }
+
+    fn abi_structs(&self, shared_types: &HashSet<String>) -> Result<TokenStream, Error> {
         let mut structs = TokenStream::new();
 
         // Prevent expanding the same struct more than once
         let mut seen_struct: Vec<&str> = vec![];
 
-        for prop in self.custom_structs.values() {
+        for (name, prop) in &self.custom_structs {
             // Skip custom type generation if the custom type is a Sway-native type.
             // This means ABI methods receiving or returning a Sway-native type
             // can receive or return that native type directly.
-            if Abigen::is_sway_native_type(&prop.type_field) {
+            if Abigen::is_sway_native_type(&prop.type_field)
+                || shared_types.contains(name)
+                || self.type_substitutions.contains_key(name)
+            {
                 continue;
             }
 
             if !seen_struct.contains(&prop.type_field.as_str()) {
-                structs.extend(expand_custom_struct(prop)?);
+                structs.extend(expand_custom_struct(prop, &self.custom_type_options)?);
                 seen_struct.push(&prop.type_field);
             }
         }
@@ -220,11 +547,14 @@ impl Abigen {
         false
     }
 
-    fn abi_enums(&self) -> Result<TokenStream, Error> {
+    fn abi_enums(&self, shared_types: &HashSet<String>) -> Result<TokenStream, Error> {
         let mut enums = TokenStream::new();
 
         for (name, prop) in &self.custom_enums {
-            enums.extend(expand_custom_enum(name, prop)?);
+            if shared_types.contains(name) || self.type_substitutions.contains_key(name) {
+                continue;
+            }
+            enums.extend(expand_custom_enum(name, prop, &self.custom_type_options)?);
         }
 
         Ok(enums)
@@ -245,47 +575,46 @@ impl Abigen {
 
     // Extracts the custom type from a `Property`. This custom type lives
     // inside an array, in the form of `[struct | enum; length]`.
-    fn get_custom_type_in_array(prop: &Property) -> HashMap<String, &Property> {
+    fn get_custom_type_in_array(prop: &Property) -> Result<HashMap<String, &Property>, Error> {
         let mut custom_types = HashMap::new();
 
         // Custom type in an array looks like `[struct Person; 2]`.
         // The `components` will hold only one element, which is the custom type.
-        let array_custom_type = prop
-            .components
-            .as_ref()
-            .expect("array should have components")
-            .first()
-            .expect("components in array should have at least one component");
+        let array_custom_type = Abigen::components_of(prop)?.first().ok_or_else(|| {
+            Error::InvalidType(format!(
+                "array `{}` should have a component holding its element type",
+                prop.type_field
+            ))
+        })?;
 
-        let custom_type_name = extract_custom_type_name_from_abi_property(array_custom_type, None)
-            .expect("failed to extract custom type name");
+        let custom_type_name = extract_custom_type_name_from_abi_property(array_custom_type, None)?;
 
         custom_types.insert(custom_type_name, array_custom_type);
 
-        custom_types
+        Ok(custom_types)
     }
 
     // Extracts the custom type from a `Property`. These custom types live
     // inside a tuple, in the form of `((struct | enum) <custom_type_name>, *)`.
-    fn get_custom_types_in_tuple(prop: &Property) -> HashMap<String, &Property> {
+    fn get_custom_types_in_tuple(prop: &Property) -> Result<HashMap<String, &Property>, Error> {
         let mut custom_types = HashMap::new();
 
         // Tuples can have `n` custom types within them.
-        for tuple_type in prop.components.as_ref().unwrap().iter() {
+        for tuple_type in Abigen::components_of(prop)? {
             if tuple_type.is_struct_type() || tuple_type.is_enum_type() {
-                let custom_type_name = extract_custom_type_name_from_abi_property(tuple_type, None)
-                    .expect("failed to extract custom type name");
+                let custom_type_name =
+                    extract_custom_type_name_from_abi_property(tuple_type, None)?;
                 custom_types.insert(custom_type_name, tuple_type);
             }
         }
 
-        custom_types
+        Ok(custom_types)
     }
 
     /// Reads the parsed ABI and returns the custom types (either `struct` or `enum`) found in it.
     /// Custom types can be in the free form (`Struct Person`, `Enum State`), inside arrays (`[struct Person; 2]`, `[enum State; 2]`)), or
     /// inside tuples (`(struct Person, struct Address)`, `(enum State, enum Country)`).
-    fn get_custom_types(abi: &JsonABI) -> HashMap<String, Property> {
+    fn get_custom_types(abi: &JsonABI) -> Result<HashMap<String, Property>, Error> {
         let mut custom_types = HashMap::new();
         let mut nested_custom_types: Vec<Property> = Vec::new();
 
@@ -298,17 +627,16 @@ impl Abigen {
         for prop in all_custom_properties {
             let custom_type = match prop.has_custom_type_in_array() {
                 // Custom type lives inside array.
-                true => Abigen::get_custom_type_in_array(prop),
+                true => Abigen::get_custom_type_in_array(prop)?,
                 false => match prop.has_custom_type_in_tuple() {
                     // Custom type lives inside tuple.
-                    true => Abigen::get_custom_types_in_tuple(prop),
+                    true => Abigen::get_custom_types_in_tuple(prop)?,
                     // Free form custom type.
                     false => {
                         let mut custom_types = HashMap::new();
 
                         let custom_type_name =
-                            extract_custom_type_name_from_abi_property(prop, None)
-                                .expect("failed to extract custom type name");
+                            extract_custom_type_name_from_abi_property(prop, None)?;
 
                         custom_types.insert(custom_type_name, prop);
 
@@ -324,13 +652,9 @@ impl Abigen {
                     .or_insert_with(|| custom_type.clone());
 
                 // Find inner {structs, enums} in case of nested custom types
-                for inner_component in custom_type
-                    .components
-                    .as_ref()
-                    .expect("Custom type should have components")
-                {
+                for inner_component in Abigen::components_of(custom_type)? {
                     nested_custom_types
-                        .extend(Abigen::get_nested_custom_properties(inner_component));
+                        .extend(Abigen::get_nested_custom_properties(inner_component)?);
                 }
             }
         }
@@ -338,40 +662,272 @@ impl Abigen {
         for nested_custom_type in nested_custom_types {
             // A {struct, enum} can contain another {struct, enum}
             let nested_custom_type_name =
-                extract_custom_type_name_from_abi_property(&nested_custom_type, None)
-                    .expect("failed to extract nested custom type name");
+                extract_custom_type_name_from_abi_property(&nested_custom_type, None)?;
             custom_types
                 .entry(nested_custom_type_name)
                 .or_insert(nested_custom_type);
         }
 
-        custom_types
+        Ok(custom_types)
     }
 
     // Recursively gets inner properties defined in nested structs or nested enums
-    fn get_nested_custom_properties(prop: &Property) -> Vec<Property> {
+    fn get_nested_custom_properties(prop: &Property) -> Result<Vec<Property>, Error> {
         let mut props = Vec::new();
 
         if prop.is_custom_type() {
             props.push(prop.clone());
 
-            for inner_prop in prop
-                .components
-                .as_ref()
-                .expect("(inner) custom type should have components")
-            {
-                let inner = Abigen::get_nested_custom_properties(inner_prop);
+            for inner_prop in Abigen::components_of(prop)? {
+                let inner = Abigen::get_nested_custom_properties(inner_prop)?;
                 props.extend(inner);
             }
         }
 
-        props
+        Ok(props)
+    }
+
+    fn components_of(prop: &Property) -> Result<&Vec<Property>, Error> {
+        prop.components.as_ref().ok_or_else(|| {
+            Error::InvalidType(format!(
+                "custom type `{}` of `{}` should have components",
+                prop.type_field, prop.name
+            ))
+        })
+    }
+}
+
+/// A custom type generated once for several contracts.
+struct SharedType<'a> {
+    prop: &'a Property,
+    is_struct: bool,
+    options: &'a CustomTypeOptions,
+}
+
+/// Generates the bindings of several contracts at once. The custom types that
+/// are structurally identical across contracts are generated once, in a
+/// `shared_types` module, so that the contracts' bindings use the same Rust
+/// types for them.
+pub struct MultiAbigen {
+    abigens: Vec<Abigen>,
+
+    /// Generate no-std safe code
+    no_std: bool,
+}
+
+impl MultiAbigen {
+    pub fn new(abigens: Vec<Abigen>) -> Result<Self, Error> {
+        if abigens.is_empty() {
+            return Err(Error::InvalidData(
+                "no contract to generate bindings for".to_string(),
+            ));
+        }
+
+        let mut names = HashSet::new();
+        for abigen in &abigens {
+            if !names.insert(abigen.contract_name.to_string()) {
+                return Err(Error::InvalidName(format!(
+                    "contract `{}` is given more than once",
+                    abigen.contract_name
+                )));
+            }
+        }
+
+        Ok(Self {
+            abigens,
+            no_std: false,
+        })
+    }
+
+    pub fn no_std(mut self) -> Self {
+        self.no_std = true;
+        self.abigens = self.abigens.into_iter().map(Abigen::no_std).collect();
+        self
+    }
+
+    /// Generates the bindings of all the contracts.
+    pub fn generate(self) -> Result<ContractBindings, Error> {
+        let tokens = self.expand()?;
+
+        Ok(ContractBindings {
+            tokens,
+            rustfmt: true,
+        })
+    }
+
+    /// Expands the contracts' modules, along with the `shared_types` module
+    /// they import their common custom types from. The modules are nested in
+    /// a `<contract>_<contract>_bindings` module and re-exported from it.
+    pub fn expand(&self) -> Result<TokenStream, Error> {
+        for abigen in &self.abigens {
+            Abigen::check_types(&abigen.abi, &abigen.origin)?;
+        }
+
+        let shared_types = self.shared_types();
+        let shared_names = shared_types.keys().cloned().collect::<HashSet<_>>();
+
+        let mut shared_structs = TokenStream::new();
+        let mut shared_enums = TokenStream::new();
+        for (name, shared) in &shared_types {
+            if shared.is_struct {
+                shared_structs.extend(expand_custom_struct(shared.prop, shared.options)?);
+            } else {
+                shared_enums.extend(expand_custom_enum(name, shared.prop, shared.options)?);
+            }
+        }
+
+        let name_mods = self
+            .abigens
+            .iter()
+            .map(Abigen::name_mod)
+            .collect::<Result<Vec<_>, _>>()?;
+        let modules = self
+            .abigens
+            .iter()
+            .map(|abigen| abigen.expand_module(&shared_names))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let bindings_mod = ident(&format!(
+            "{}_bindings",
+            self.abigens
+                .iter()
+                .map(|abigen| abigen.contract_name.to_string().to_lowercase())
+                .collect::<Vec<_>>()
+                .join("_")
+        ))?;
+        let includes = Abigen::includes(self.no_std);
+        let substitutions = Abigen::expand_type_substitutions(&self.common_substitutions())?;
+
+        Ok(quote! {
+            pub use #bindings_mod::{shared_types, #( #name_mods ),*};
+            pub use #bindings_mod::shared_types::*;
+            #( pub use #bindings_mod::#name_mods::*; )*
+
+            pub mod #bindings_mod {
+                pub mod shared_types {
+                    #![allow(clippy::enum_variant_names)]
+                    #![allow(dead_code)]
+                    #![allow(unused_imports)]
+
+                    #includes
+                    #substitutions
+
+                    #shared_structs
+                    #shared_enums
+                }
+
+                #( #modules )*
+            }
+        })
+    }
+
+    /// The type substitutions every contract makes, which the `shared_types`
+    /// module makes too.
+    fn common_substitutions(&self) -> BTreeMap<String, String> {
+        let (first, others) = self.abigens.split_first().expect("at least one contract");
+
+        first
+            .type_substitutions
+            .iter()
+            .filter(|(name, rust_type)| {
+                others
+                    .iter()
+                    .all(|abigen| abigen.type_substitutions.get(*name) == Some(rust_type))
+            })
+            .map(|(name, rust_type)| (name.clone(), rust_type.clone()))
+            .collect()
+    }
+
+    /// Finds the custom types that are defined identically, and generated
+    /// with the same options, by at least two of the contracts and by no
+    /// contract differently. Types substituted by any contract aren't shared.
+    fn shared_types(&self) -> BTreeMap<String, SharedType<'_>> {
+        let substituted = self
+            .abigens
+            .iter()
+            .flat_map(|abigen| abigen.type_substitutions.keys())
+            .collect::<HashSet<_>>();
+        let common_substitutions = self.common_substitutions();
+
+        let mut definitions: BTreeMap<&String, Vec<SharedType<'_>>> = BTreeMap::new();
+        for abigen in &self.abigens {
+            let structs = abigen.custom_structs.iter().map(|(n, p)| (n, p, true));
+            let enums = abigen.custom_enums.iter().map(|(n, p)| (n, p, false));
+            for (name, prop, is_struct) in structs.chain(enums) {
+                if !Abigen::is_sway_native_type(&prop.type_field) && !substituted.contains(name) {
+                    definitions.entry(name).or_default().push(SharedType {
+                        prop,
+                        is_struct,
+                        options: &abigen.custom_type_options,
+                    });
+                }
+            }
+        }
+
+        let mut shared: BTreeMap<String, SharedType<'_>> = definitions
+            .into_iter()
+            .filter(|(_, defs)| {
+                defs.len() > 1
+                    && defs.iter().all(|def| {
+                        def.is_struct == defs[0].is_struct
+                            && def.prop.components == defs[0].prop.components
+                            && def.options == defs[0].options
+                    })
+            })
+            .map(|(name, mut defs)| (name.clone(), defs.remove(0)))
+            .collect();
+
+        // A shared type can only reference other shared types, or types that
+        // every contract substitutes the same way, since it can't see the types
+        // generated in each contract's module.
+        loop {
+            let unresolved = shared
+                .iter()
+                .filter(|(_, shared_type)| {
+                    MultiAbigen::referenced_custom_types(shared_type.prop)
+                        .iter()
+                        .any(|name| {
+                            !shared.contains_key(name) && !common_substitutions.contains_key(name)
+                        })
+                })
+                .map(|(name, _)| name.clone())
+                .collect::<Vec<_>>();
+            if unresolved.is_empty() {
+                return shared;
+            }
+            for name in unresolved {
+                shared.remove(&name);
+            }
+        }
+    }
+
+    // Recursively gets the names of the (non Sway-native) custom types used
+    // by the components of a custom type.
+    fn referenced_custom_types(prop: &Property) -> Vec<String> {
+        let mut names = vec![];
+
+        for component in prop.components.iter().flatten() {
+            let is_named_custom_type = component.is_struct_type()
+                || component.is_enum_type()
+                || component.has_custom_type_in_array();
+            if is_named_custom_type {
+                if let Ok(name) = extract_custom_type_name_from_abi_property(component, None) {
+                    if name != CONTRACT_ID_SWAY_NATIVE_TYPE && name != ADDRESS_SWAY_NATIVE_TYPE {
+                        names.push(name);
+                    }
+                }
+            }
+            names.extend(MultiAbigen::referenced_custom_types(component));
+        }
+
+        names
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::fs;
 
     #[test]
     fn generates_bindings() -> Result<(), Error> {
@@ -1079,4 +1635,238 @@ mod tests {
         );
         Ok(())
     }
+
+    #[test]
+    fn generates_deploy_for_the_given_binary() -> Result<(), Error> {
+        let contract = r#"
+        [
+            {
+                "type":"contract",
+                "inputs":[],
+                "name":"unused",
+                "outputs":[]
+            }
+        ]
+        "#;
+        let dir = tempfile::tempdir()?;
+        let dir = dir.path();
+        fs::write(dir.join("abigen_deploy.bin"), [0u8; 8])?;
+        fs::write(dir.join("abigen_deploy-storage_slots.json"), "[]")?;
+
+        let without_bin = Abigen::new("test", contract)?.expand()?.to_string();
+        assert!(!without_bin.contains("pub async fn deploy"));
+
+        let bindings = Abigen::new("test", contract)?
+            .bin(dir.join("abigen_deploy.bin").to_str().unwrap())?
+            .storage(
+                dir.join("abigen_deploy-storage_slots.json")
+                    .to_str()
+                    .unwrap(),
+            )?
+            .expand()?
+            .to_string();
+        assert!(bindings.contains("pub async fn deploy"));
+        assert!(bindings.contains("abigen_deploy.bin"));
+        assert!(bindings.contains("abigen_deploy-storage_slots.json"));
+
+        let missing_bin = Abigen::new("test", contract)?
+            .bin("missing.bin")
+            .err()
+            .expect("Expected an error because the binary doesn't exist");
+        assert!(missing_bin.to_string().contains("couldn't find"));
+        Ok(())
+    }
+
+    #[test]
+    fn errors_locate_the_malformed_part_of_the_abi() -> Result<(), Error> {
+        let contract = r#"
+        [
+            {
+                "type":"contract",
+                "inputs":[
+                    {
+                        "name":"input",
+                        "type":"struct MyStruct",
+                        "components":[
+                            {
+                                "name":"b",
+                                "type":"u8[2]"
+                            }
+                        ]
+                    }
+                ],
+                "name":"takes_struct",
+                "outputs":[]
+            }
+        ]
+        "#;
+        let error = Abigen::new("test", contract)?
+            .expand()
+            .err()
+            .expect("Expected an error because `u8[2]` isn't a valid type");
+        assert_eq!(
+            error.to_string(),
+            "Invalid type: `u8[2]` at `$[0].inputs[0].components[0]` in the inline ABI: Invalid type: Expected parameter type `[T; n]`, found `u8[2]`"
+        );
+
+        let error = Abigen::new("test", "[{\"type\":\"contract\"}]")
+            .err()
+            .expect("Expected an error because the function has no inputs");
+        assert!(error.to_string().starts_with(
+            "Invalid data: failed to parse the inline ABI as a JSON ABI: missing field `inputs`"
+        ));
+
+        let error = Abigen::new("test", "missing-abi.json")
+            .err()
+            .expect("Expected an error because the file doesn't exist");
+        assert!(error.to_string().contains("missing-abi.json"));
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_names_are_errors_located_in_the_abi() -> Result<(), Error> {
+        let abi_with = |name: &str, arg_type: &str, field: &str| {
+            format!(
+                r#"[{{"type":"contract","inputs":[{{"name":"input","type":"{}","components":[{{"name":"{}","type":"u64"}}]}}],"name":"{}","outputs":[]}}]"#,
+                arg_type, field, name
+            )
+        };
+        let error_of = |abi: String| -> Result<Error, Error> {
+            Ok(Abigen::new("test", abi)?
+                .expand()
+                .err()
+                .expect("Expected an error because of an invalid name"))
+        };
+
+        let error = error_of(abi_with("takes_struct", "struct my-type", "a"))?;
+        assert_eq!(
+            error.to_string(),
+            "Invalid type: `struct my-type` at `$[0].inputs[0]` in the inline ABI: Invalid type: `my-type` is not a valid identifier"
+        );
+
+        let error = error_of(abi_with("takes_struct", "struct MyStruct", "1a"))?;
+        assert_eq!(
+            error.to_string(),
+            "Invalid type: `1a` at `$[0].inputs[0].components[0].name` in the inline ABI: Invalid type: `1a` is not a valid identifier"
+        );
+
+        let error = error_of(abi_with("takes-struct", "struct MyStruct", "a"))?;
+        assert_eq!(
+            error.to_string(),
+            "Invalid type: `takes-struct` at `$[0].name` in the inline ABI: Invalid type: `takes-struct` is not a valid identifier"
+        );
+
+        let abigens = vec![Abigen::new(
+            "test",
+            abi_with("takes_struct", "struct my-type", "a"),
+        )?];
+        let error = MultiAbigen::new(abigens)?
+            .expand()
+            .err()
+            .expect("Expected an error because of an invalid type name");
+        assert!(error.to_string().contains("at `$[0].inputs[0]`"));
+        Ok(())
+    }
+
+    #[test]
+    fn substituted_types_are_imported_instead_of_generated() -> Result<(), Error> {
+        let contract = r#"
+        [
+            {
+                "type":"contract",
+                "inputs":[
+                    {
+                        "name":"who",
+                        "type":"enum Identity",
+                        "components":[
+                            {
+                                "name":"Address",
+                                "type":"b256"
+                            }
+                        ]
+                    }
+                ],
+                "name":"takes_identity",
+                "outputs":[]
+            }
+        ]
+        "#;
+
+        let bindings = Abigen::new("test", contract)?
+            .substitute_type("enum Identity", "my_crate::Who")?
+            .expand()?
+            .to_string();
+        assert!(bindings.contains("use my_crate :: Who as Identity ;"));
+        assert!(!bindings.contains("pub enum Identity"));
+
+        let error = Abigen::new("test", contract)?
+            .substitute_type("struct Identity", "my_crate::Who")
+            .err()
+            .expect("Expected an error because `Identity` is an enum");
+        assert_eq!(
+            error.to_string(),
+            "Invalid type: the ABI has no `struct Identity` to substitute"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn multi_abigen_shares_identical_custom_types() -> Result<(), Error> {
+        let order = r#"{"name":"order","type":"struct Order","components":[{"name":"id","type":"u64"},{"name":"item","type":"struct Item","components":[{"name":"sku","type":"u32"}]}]}"#;
+        let kind_a =
+            r#"{"name":"kind","type":"enum Kind","components":[{"name":"A","type":"()"}]}"#;
+        let kind_b =
+            r#"{"name":"kind","type":"enum Kind","components":[{"name":"B","type":"u64"}]}"#;
+        let item_c =
+            r#"{"name":"item","type":"struct Item","components":[{"name":"sku","type":"u64"}]}"#;
+        let abi = |inputs: &str| {
+            format!(
+                r#"[{{"type":"function","name":"f","inputs":[{}],"outputs":[]}}]"#,
+                inputs
+            )
+        };
+
+        let contracts = MultiAbigen::new(vec![
+            Abigen::new("A", abi(&format!("{},{}", order, kind_a)))?,
+            Abigen::new("B", abi(&format!("{},{}", order, kind_b)))?,
+        ])?;
+        let shared_types = contracts.shared_types();
+        assert_eq!(
+            shared_types.keys().collect::<Vec<_>>(),
+            vec!["Item", "Order"]
+        );
+        let bindings = contracts.expand()?.to_string();
+        assert!(bindings.contains("pub mod a_b_bindings"));
+        assert!(bindings.contains("pub mod shared_types"));
+        assert_eq!(bindings.matches("pub struct Order").count(), 1);
+        assert_eq!(bindings.matches("pub enum Kind").count(), 2);
+
+        // `Order` can't be shared anymore if `Item` differs in another contract.
+        let contracts = MultiAbigen::new(vec![
+            Abigen::new("A", abi(order))?,
+            Abigen::new("B", abi(order))?,
+            Abigen::new("C", abi(item_c))?,
+        ])?;
+        assert!(contracts.shared_types().is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn multi_abigen_rejects_duplicate_contracts() -> Result<(), Error> {
+        let contract = r#"[{"type":"function","name":"f","inputs":[],"outputs":[]}]"#;
+
+        let error = MultiAbigen::new(vec![
+            Abigen::new("A", contract)?,
+            Abigen::new("A", contract)?,
+        ])
+        .err()
+        .expect("Expected an error because the contract is given twice");
+
+        assert_eq!(
+            error.to_string(),
+            "Invalid name: contract `A` is given more than once"
+        );
+        Ok(())
+    }
 }
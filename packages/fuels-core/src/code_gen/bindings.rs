@@ -1,4 +1,3 @@
-use crate::rustfmt;
 use fuels_types::errors::Error;
 use proc_macro2::TokenStream;
 use std::{fs::File, io::Write, path::Path};
@@ -8,7 +7,7 @@ use std::{fs::File, io::Write, path::Path};
 pub struct ContractBindings {
     /// The TokenStream representing the contract bindings.
     pub tokens: TokenStream,
-    /// The output options used for serialization.
+    /// Whether to format the bindings when writing them.
     pub rustfmt: bool,
 }
 
@@ -18,17 +17,13 @@ impl ContractBindings {
     where
         W: Write,
     {
-        let source = {
-            let raw = self.tokens.to_string();
-
-            if self.rustfmt {
-                rustfmt::format(&raw).unwrap_or(raw)
-            } else {
-                raw
-            }
+        let source = if self.rustfmt {
+            format_tokens(&self.tokens)
+        } else {
+            self.tokens.to_string()
         };
 
-        w.write_all(source.as_bytes()).unwrap();
+        w.write_all(source.as_bytes())?;
         Ok(())
     }
 
@@ -37,7 +32,7 @@ impl ContractBindings {
     where
         P: AsRef<Path>,
     {
-        let file = File::create(path).unwrap();
+        let file = File::create(path)?;
         self.write(file)
     }
 
@@ -47,3 +42,13 @@ impl ContractBindings {
         self.tokens
     }
 }
+
+/// Formats the tokens as a Rust file with `prettyplease`, which doesn't need
+/// `rustfmt` to be installed. Tokens that aren't a valid Rust file are
+/// returned unformatted.
+fn format_tokens(tokens: &TokenStream) -> String {
+    match syn::parse2::<syn::File>(tokens.clone()) {
+        Ok(file) => prettyplease::unparse(&file),
+        Err(_) => tokens.to_string(),
+    }
+}
@@ -0,0 +1,208 @@
+use crate::code_gen::abigen::Abigen;
+use fuels_types::errors::Error;
+use inflector::Inflector;
+use std::collections::HashMap;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Generates contract bindings from a build script, writing them to one
+/// module file per contract:
+///
+/// ```no_run
+/// # use fuels_core::code_gen::builder::AbigenBuilder;
+/// // build.rs
+/// fn main() {
+///     AbigenBuilder::new()
+///         .abi_file("contracts/out/debug/my_contract-abi.json")
+///         .derives(["Hash"])
+///         .generate()
+///         .unwrap();
+/// }
+/// ```
+///
+/// The bindings can then be included with
+/// `include!(concat!(env!("OUT_DIR"), "/my_contract.rs"));`.
+#[derive(Debug, Default)]
+pub struct AbigenBuilder {
+    /// The name and ABI path of each contract.
+    contracts: Vec<(String, PathBuf)>,
+
+    /// The directory the modules are written to, `OUT_DIR` by default.
+    out_dir: Option<PathBuf>,
+
+    /// Module names to use instead of the snake cased contract names.
+    module_names: HashMap<String, String>,
+
+    derives: Vec<String>,
+
+    serde_rename_all: Option<String>,
+
+    /// Custom types of the ABIs replaced by existing Rust types.
+    type_substitutions: Vec<(String, String)>,
+
+    /// Generate no-std safe code
+    no_std: bool,
+}
+
+impl AbigenBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a contract, named after its ABI file: `my_contract-abi.json`
+    /// generates `MyContract`, written to `my_contract.rs`.
+    pub fn abi_file<P: AsRef<Path>>(mut self, abi_path: P) -> Self {
+        let abi_path = abi_path.as_ref();
+        let stem = abi_path
+            .file_stem()
+            .map(|stem| stem.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let name = stem.trim_end_matches("-abi").to_pascal_case();
+
+        self.contracts.push((name, abi_path.to_path_buf()));
+        self
+    }
+
+    /// Adds a contract with the given name.
+    pub fn contract<P: AsRef<Path>>(mut self, name: &str, abi_path: P) -> Self {
+        self.contracts
+            .push((name.to_string(), abi_path.as_ref().to_path_buf()));
+        self
+    }
+
+    /// Sets the directory the modules are written to. Defaults to `OUT_DIR`,
+    /// set by cargo when running build scripts.
+    pub fn out_dir<P: AsRef<Path>>(mut self, out_dir: P) -> Self {
+        self.out_dir = Some(out_dir.as_ref().to_path_buf());
+        self
+    }
+
+    /// Writes the bindings of `contract` to `<module>.rs` instead of the
+    /// snake cased name of the contract.
+    pub fn module_name(mut self, contract: &str, module: &str) -> Self {
+        self.module_names
+            .insert(contract.to_string(), module.to_string());
+        self
+    }
+
+    /// Adds derives to the generated structs and enums. See `Abigen::derives`.
+    pub fn derives<I, S>(mut self, derives: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.derives
+            .extend(derives.into_iter().map(|d| d.as_ref().to_string()));
+        self
+    }
+
+    /// See `Abigen::serde_rename_all`.
+    pub fn serde_rename_all(mut self, rule: &str) -> Self {
+        self.serde_rename_all = Some(rule.to_string());
+        self
+    }
+
+    /// Replaces a custom type, in the contracts whose ABI has it, with an
+    /// existing Rust type. See `Abigen::substitute_type`.
+    pub fn substitute_type(mut self, abi_type: &str, rust_type: &str) -> Self {
+        self.type_substitutions
+            .push((abi_type.to_string(), rust_type.to_string()));
+        self
+    }
+
+    pub fn no_std(mut self) -> Self {
+        self.no_std = true;
+        self
+    }
+
+    /// Generates and writes the bindings of every contract, returning the
+    /// paths of the written modules. Cargo is told to rerun the build script
+    /// when one of the ABI files changes.
+    pub fn generate(self) -> Result<Vec<PathBuf>, Error> {
+        let out_dir = match self.out_dir.clone() {
+            Some(out_dir) => out_dir,
+            None => env::var_os("OUT_DIR").map(PathBuf::from).ok_or_else(|| {
+                Error::InvalidData(
+                    "`OUT_DIR` isn't set, set the output directory with `out_dir`".to_string(),
+                )
+            })?,
+        };
+        fs::create_dir_all(&out_dir)?;
+
+        let mut written = vec![];
+        for (name, abi_path) in &self.contracts {
+            println!("cargo:rerun-if-changed={}", abi_path.display());
+
+            let bindings = self.abigen(name, abi_path)?.generate()?;
+
+            let module = self
+                .module_names
+                .get(name)
+                .cloned()
+                .unwrap_or_else(|| name.to_snake_case());
+            let path = out_dir.join(format!("{}.rs", module));
+            bindings.write_to_file(&path)?;
+            written.push(path);
+        }
+
+        Ok(written)
+    }
+
+    fn abigen(&self, name: &str, abi_path: &Path) -> Result<Abigen, Error> {
+        let abi = abi_path.to_str().ok_or_else(|| {
+            Error::InvalidData(format!("`{}` isn't a valid UTF-8 path", abi_path.display()))
+        })?;
+
+        let mut abigen = Abigen::new(name, abi)?.derives(&self.derives)?;
+        if let Some(rule) = &self.serde_rename_all {
+            abigen = abigen.serde_rename_all(rule)?;
+        }
+        for (abi_type, rust_type) in &self.type_substitutions {
+            if abigen.has_custom_type(abi_type) {
+                abigen = abigen.substitute_type(abi_type, rust_type)?;
+            }
+        }
+        if self.no_std {
+            abigen = abigen.no_std();
+        }
+
+        Ok(abigen)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn writes_a_module_per_contract() -> Result<(), Error> {
+        let dir = tempfile::tempdir()?;
+        let dir = dir.path();
+        let abi = r#"[{"type":"function","name":"get","inputs":[{"name":"p","type":"struct Point","components":[{"name":"x","type":"u64"}]}],"outputs":[]}]"#;
+        fs::write(dir.join("my_contract-abi.json"), abi)?;
+        fs::write(dir.join("other.json"), abi)?;
+
+        let written = AbigenBuilder::new()
+            .abi_file(dir.join("my_contract-abi.json"))
+            .contract("Other", dir.join("other.json"))
+            .module_name("Other", "other_bindings")
+            .derives(["Hash"])
+            .out_dir(dir.join("out"))
+            .generate()?;
+
+        assert_eq!(
+            written,
+            vec![
+                dir.join("out").join("my_contract.rs"),
+                dir.join("out").join("other_bindings.rs")
+            ]
+        );
+        let source = fs::read_to_string(&written[0])?;
+        assert!(source.contains("pub mod mycontract_mod {"));
+        assert!(
+            source.contains("#[derive(Clone, Debug, Eq, PartialEq, Hash)]\n    pub struct Point {")
+        );
+        Ok(())
+    }
+}
@@ -13,13 +13,15 @@ use std::str::FromStr;
 
 /// Transforms a custom type defined in [`Property`] into a [`TokenStream`]
 /// that represents that same type as a Rust-native struct.
-pub fn expand_custom_struct(prop: &Property) -> Result<TokenStream, Error> {
+pub fn expand_custom_struct(
+    prop: &Property,
+    options: &CustomTypeOptions,
+) -> Result<TokenStream, Error> {
     let struct_name = &extract_custom_type_name_from_abi_property(prop, Some(CustomType::Struct))?;
-    let struct_ident = ident(struct_name);
-    let components = prop
-        .components
-        .as_ref()
-        .expect("Fail to extract components from custom type");
+    let struct_ident = ident(struct_name)?;
+    let components = prop.components.as_ref().ok_or_else(|| {
+        Error::InvalidType(format!("struct `{}` should have components", struct_name))
+    })?;
     let mut fields = Vec::with_capacity(components.len());
 
     // Holds a TokenStream representing the process of
@@ -37,7 +39,7 @@ pub fn expand_custom_struct(prop: &Property) -> Result<TokenStream, Error> {
     // 1. A struct field declaration like `pub #field_name: #component_name`
     // 2. The creation of a token and its insertion into a vector of Tokens.
     for component in components {
-        let field_name = ident(&component.name.to_snake_case());
+        let field_name = ident(&component.name.to_snake_case())?;
         let param_type = parse_param(component)?;
 
         match param_type {
@@ -46,7 +48,7 @@ pub fn expand_custom_struct(prop: &Property) -> Result<TokenStream, Error> {
                 let inner_struct_ident = ident(&extract_custom_type_name_from_abi_property(
                     component,
                     Some(CustomType::Struct),
-                )?);
+                )?)?;
 
                 fields.push(quote! {pub #field_name: #inner_struct_ident});
                 args.push(quote! {#field_name: #inner_struct_ident::from_token(next_token()?)?});
@@ -58,7 +60,7 @@ pub fn expand_custom_struct(prop: &Property) -> Result<TokenStream, Error> {
                 let enum_name = ident(&extract_custom_type_name_from_abi_property(
                     component,
                     Some(CustomType::Enum),
-                )?);
+                )?)?;
                 fields.push(quote! {pub #field_name: #enum_name});
                 args.push(quote! {#field_name: #enum_name::from_token(next_token()?)?});
                 struct_fields_tokens.push(quote! { tokens.push(self.#field_name.into_token()) });
@@ -71,7 +73,7 @@ pub fn expand_custom_struct(prop: &Property) -> Result<TokenStream, Error> {
                 let mut param_type_string = param_type.to_string();
 
                 let param_type_string_ident_tok: proc_macro2::TokenStream =
-                    param_type_string.parse().unwrap();
+                    param_type_string.parse()?;
 
                 param_types.push(quote! { types.push(ParamType::#param_type_string_ident_tok) });
 
@@ -82,7 +84,7 @@ pub fn expand_custom_struct(prop: &Property) -> Result<TokenStream, Error> {
                     param_type_string = "String".to_string();
                 }
 
-                let param_type_string_ident = ident(&param_type_string);
+                let param_type_string_ident = ident(&param_type_string)?;
 
                 // Field declaration
                 fields.push(quote! { pub #field_name: #ty});
@@ -112,8 +114,10 @@ pub fn expand_custom_struct(prop: &Property) -> Result<TokenStream, Error> {
 
     // Actual creation of the struct, using the inner TokenStreams from above to produce the
     // TokenStream that represents the whole struct + methods declaration.
+    let attributes = options.expand_attributes()?;
+
     Ok(quote! {
-        #[derive(Clone, Debug, Eq, PartialEq)]
+        #attributes
         pub struct #struct_ident {
             #( #fields ),*
         }
@@ -171,7 +175,11 @@ pub fn expand_custom_struct(prop: &Property) -> Result<TokenStream, Error> {
 
 /// Transforms a custom enum defined in [`Property`] into a [`TokenStream`]
 /// that represents that same type as a Rust-native enum.
-pub fn expand_custom_enum(enum_name: &str, prop: &Property) -> Result<TokenStream, Error> {
+pub fn expand_custom_enum(
+    enum_name: &str,
+    prop: &Property,
+    options: &CustomTypeOptions,
+) -> Result<TokenStream, Error> {
     let components = match &prop.components {
         Some(components) if !components.is_empty() => Ok(components),
         _ => Err(Error::InvalidType(format!(
@@ -189,11 +197,11 @@ pub fn expand_custom_enum(enum_name: &str, prop: &Property) -> Result<TokenStrea
     // Used when creating a struct from tokens with `Tokenizable::from_token()`.
     let mut args = Vec::new();
 
-    let enum_ident = ident(enum_name);
+    let enum_ident = ident(enum_name)?;
     let mut param_types = Vec::new();
 
     for (discriminant, component) in components.iter().enumerate() {
-        let variant_name = ident(&component.name);
+        let variant_name = ident(&component.name)?;
         let dis = discriminant as u8;
 
         let param_type = parse_param(component)?;
@@ -203,7 +211,7 @@ pub fn expand_custom_enum(enum_name: &str, prop: &Property) -> Result<TokenStrea
                 let inner_enum_name =
                     &extract_custom_type_name_from_abi_property(component, Some(CustomType::Enum))?;
 
-                let inner_enum_ident = ident(inner_enum_name);
+                let inner_enum_ident = ident(inner_enum_name)?;
                 // Enum variant declaration
                 enum_variants.push(quote! { #variant_name(#inner_enum_ident)});
 
@@ -227,7 +235,7 @@ pub fn expand_custom_enum(enum_name: &str, prop: &Property) -> Result<TokenStrea
                     component,
                     Some(CustomType::Struct),
                 )?;
-                let inner_struct_ident = ident(inner_struct_name);
+                let inner_struct_ident = ident(inner_struct_name)?;
                 // Enum variant declaration
                 enum_variants.push(quote! { #variant_name(#inner_struct_ident)});
 
@@ -271,7 +279,7 @@ pub fn expand_custom_enum(enum_name: &str, prop: &Property) -> Result<TokenStrea
                     ParamType::String(..) => "String".to_string(),
                     _ => param_type.to_string(),
                 };
-                let param_type_string_ident = ident(&param_type_string);
+                let param_type_string_ident = ident(&param_type_string)?;
 
                 // Enum variant declaration
                 enum_variants.push(quote! { #variant_name(#ty)});
@@ -301,8 +309,10 @@ pub fn expand_custom_enum(enum_name: &str, prop: &Property) -> Result<TokenStrea
     // Actual creation of the enum, using the inner TokenStreams from above
     // to produce the TokenStream that represents the whole enum + methods
     // declaration.
+    let attributes = options.expand_attributes()?;
+
     Ok(quote! {
-        #[derive(Clone, Debug, Eq, PartialEq)]
+        #attributes
         pub enum #enum_ident {
             #( #enum_variants ),*
         }
@@ -367,6 +377,93 @@ pub fn expand_custom_enum(enum_name: &str, prop: &Property) -> Result<TokenStrea
     })
 }
 
+/// The derives every generated custom type has.
+const DEFAULT_DERIVES: [&str; 4] = ["Clone", "Debug", "Eq", "PartialEq"];
+
+/// The `rename_all` rules supported by serde.
+const SERDE_RENAME_RULES: [&str; 8] = [
+    "lowercase",
+    "UPPERCASE",
+    "PascalCase",
+    "camelCase",
+    "snake_case",
+    "SCREAMING_SNAKE_CASE",
+    "kebab-case",
+    "SCREAMING-KEBAB-CASE",
+];
+
+/// Options tuning the attributes of the generated custom types.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct CustomTypeOptions {
+    /// Derives added to the default `Clone, Debug, Eq, PartialEq`, e.g.
+    /// `Hash` or `serde::Serialize`.
+    pub extra_derives: Vec<String>,
+
+    /// Serde rule renaming the fields of structs and the variants of enums,
+    /// e.g. `camelCase`. Requires deriving serde's traits.
+    pub serde_rename_all: Option<String>,
+}
+
+impl CustomTypeOptions {
+    /// Adds derives to the generated custom types. Derives they already have
+    /// are ignored.
+    pub fn add_derives<I, S>(&mut self, derives: I) -> Result<(), Error>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        for derive in derives {
+            let derive = derive.as_ref().trim();
+            if syn::parse_str::<syn::Path>(derive).is_err() {
+                return Err(Error::InvalidData(format!(
+                    "`{}` is not a valid derive path",
+                    derive
+                )));
+            }
+
+            let derive = derive.to_string();
+            if !DEFAULT_DERIVES.contains(&derive.as_str()) && !self.extra_derives.contains(&derive)
+            {
+                self.extra_derives.push(derive);
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn set_serde_rename_all(&mut self, rule: &str) -> Result<(), Error> {
+        if !SERDE_RENAME_RULES.contains(&rule) {
+            return Err(Error::InvalidData(format!(
+                "unknown serde rename rule `{}`, expected one of: {}",
+                rule,
+                SERDE_RENAME_RULES.join(", ")
+            )));
+        }
+
+        self.serde_rename_all = Some(rule.to_string());
+        Ok(())
+    }
+
+    fn expand_attributes(&self) -> Result<TokenStream, Error> {
+        let extra_derives = self
+            .extra_derives
+            .iter()
+            .map(|derive| syn::parse_str::<syn::Path>(derive))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|err| Error::InvalidData(err.to_string()))?;
+
+        let serde_attribute = match &self.serde_rename_all {
+            Some(rule) => quote! { #[serde(rename_all = #rule)] },
+            None => quote! {},
+        };
+
+        Ok(quote! {
+            #[derive(Clone, Debug, Eq, PartialEq #(, #extra_derives)*)]
+            #serde_attribute
+        })
+    }
+}
+
 // A custom type name should be passed to this function as `{struct,enum} $name`,
 // or inside an array, like `[{struct,enum} $name; $length]`.
 // This function extracts the `$name`.
@@ -499,7 +596,7 @@ mod tests {
                 },
             ]),
         };
-        let actual = expand_custom_enum("MatchaTea", &p)?.to_string();
+        let actual = expand_custom_enum("MatchaTea", &p, &Default::default())?.to_string();
         let expected = TokenStream::from_str(
             r#"
             # [derive (Clone , Debug , Eq , PartialEq)] pub enum MatchaTea { LongIsland (u64) , MoscowMule (bool) } impl Parameterize for MatchaTea { fn param_type () -> ParamType { let mut types = Vec :: new () ; types . push (ParamType :: U64) ; types . push (ParamType :: Bool) ; let variants = EnumVariants :: new (types) . expect (concat ! ("Enum " , "MatchaTea" , " has no variants! 'abigen!' should not have succeeded!")) ; ParamType :: Enum (variants) } } impl Tokenizable for MatchaTea { fn into_token (self) -> Token { let (dis , tok) = match self { MatchaTea :: LongIsland (value) => (0u8 , Token :: U64 (value)) , MatchaTea :: MoscowMule (value) => (1u8 , Token :: Bool (value)) , } ; let variants = match Self :: param_type () { ParamType :: Enum (variants) => variants , other => panic ! ("Calling ::param_type() on a custom enum must return a ParamType::Enum but instead it returned: {}" , other) } ; let selector = (dis , tok , variants) ; Token :: Enum (Box :: new (selector)) } fn from_token (token : Token) -> Result < Self , SDKError > { if let Token :: Enum (enum_selector) = token { match * enum_selector { (0u8 , token , _) => Ok (MatchaTea :: LongIsland (< u64 > :: from_token (token) ?)) , (1u8 , token , _) => Ok (MatchaTea :: MoscowMule (< bool > :: from_token (token) ?)) , (_ , _ , _) => Err (SDKError :: InstantiationError (format ! ("Could not construct '{}'. Failed to match with discriminant selector {:?}" , "MatchaTea" , enum_selector))) } } else { Err (SDKError :: InstantiationError (format ! ("Could not construct '{}'. Expected a token of type Token::Enum, got {:?}" , "MatchaTea" , token))) } } } impl TryFrom < & [u8] > for MatchaTea { type Error = SDKError ; fn try_from (bytes : & [u8]) -> Result < Self , Self :: Error > { try_from_bytes (bytes) } } impl TryFrom < & Vec < u8 >> for MatchaTea { type Error = SDKError ; fn try_from (bytes : & Vec < u8 >) -> Result < Self , Self :: Error > { try_from_bytes (bytes) } } impl TryFrom < Vec < u8 >> for MatchaTea { type Error = SDKError ; fn try_from (bytes : Vec < u8 >) -> Result < Self , Self :: Error > { try_from_bytes (& bytes) } }
@@ -540,7 +637,7 @@ mod tests {
             ..Property::default()
         };
 
-        let err = expand_custom_enum("TheEmptyEnum", &property)
+        let err = expand_custom_enum("TheEmptyEnum", &property, &Default::default())
             .err()
             .ok_or_else(|| anyhow!("Was able to construct an enum without variants"))?;
 
@@ -583,7 +680,7 @@ mod tests {
             type_field: String::from("enum CityComponent"),
             components: Some(enum_components),
         };
-        let actual = expand_custom_enum("Amsterdam", &p)?.to_string();
+        let actual = expand_custom_enum("Amsterdam", &p, &Default::default())?.to_string();
         let expected = TokenStream::from_str(
             r#"
             # [derive (Clone , Debug , Eq , PartialEq)] pub enum Amsterdam { Infrastructure (Building) , Service (u32) } impl Parameterize for Amsterdam { fn param_type () -> ParamType { let mut types = Vec :: new () ; types . push (Building :: param_type ()) ; types . push (ParamType :: U32) ; let variants = EnumVariants :: new (types) . expect (concat ! ("Enum " , "Amsterdam" , " has no variants! 'abigen!' should not have succeeded!")) ; ParamType :: Enum (variants) } } impl Tokenizable for Amsterdam { fn into_token (self) -> Token { let (dis , tok) = match self { Amsterdam :: Infrastructure (inner_struct) => (0u8 , inner_struct . into_token ()) , Amsterdam :: Service (value) => (1u8 , Token :: U32 (value)) , } ; let variants = match Self :: param_type () { ParamType :: Enum (variants) => variants , other => panic ! ("Calling ::param_type() on a custom enum must return a ParamType::Enum but instead it returned: {}" , other) } ; let selector = (dis , tok , variants) ; Token :: Enum (Box :: new (selector)) } fn from_token (token : Token) -> Result < Self , SDKError > { if let Token :: Enum (enum_selector) = token { match * enum_selector { (0u8 , token , _) => { let variant_content = < Building > :: from_token (token) ? ; Ok (Amsterdam :: Infrastructure (variant_content)) } (1u8 , token , _) => Ok (Amsterdam :: Service (< u32 > :: from_token (token) ?)) , (_ , _ , _) => Err (SDKError :: InstantiationError (format ! ("Could not construct '{}'. Failed to match with discriminant selector {:?}" , "Amsterdam" , enum_selector))) } } else { Err (SDKError :: InstantiationError (format ! ("Could not construct '{}'. Expected a token of type Token::Enum, got {:?}" , "Amsterdam" , token))) } } } impl TryFrom < & [u8] > for Amsterdam { type Error = SDKError ; fn try_from (bytes : & [u8]) -> Result < Self , Self :: Error > { try_from_bytes (bytes) } } impl TryFrom < & Vec < u8 >> for Amsterdam { type Error = SDKError ; fn try_from (bytes : & Vec < u8 >) -> Result < Self , Self :: Error > { try_from_bytes (bytes) } } impl TryFrom < Vec < u8 >> for Amsterdam { type Error = SDKError ; fn try_from (bytes : Vec < u8 >) -> Result < Self , Self :: Error > { try_from_bytes (& bytes) } }
@@ -606,7 +703,7 @@ mod tests {
             type_field: String::from("unused"),
             components: Some(enum_components),
         };
-        let actual = expand_custom_enum("SomeEnum", &p)?.to_string();
+        let actual = expand_custom_enum("SomeEnum", &p, &Default::default())?.to_string();
         let expected = TokenStream::from_str(
             r#"
             # [derive (Clone , Debug , Eq , PartialEq)] pub enum SomeEnum { SomeArr (:: std :: vec :: Vec < u64 >) } impl Parameterize for SomeEnum { fn param_type () -> ParamType { let mut types = Vec :: new () ; types . push (ParamType :: Array (Box :: new (ParamType :: U64) , 7)) ; let variants = EnumVariants :: new (types) . expect (concat ! ("Enum " , "SomeEnum" , " has no variants! 'abigen!' should not have succeeded!")) ; ParamType :: Enum (variants) } } impl Tokenizable for SomeEnum { fn into_token (self) -> Token { let (dis , tok) = match self { SomeEnum :: SomeArr (value) => (0u8 , Token :: Array (vec ! [value . into_token ()])) , } ; let variants = match Self :: param_type () { ParamType :: Enum (variants) => variants , other => panic ! ("Calling ::param_type() on a custom enum must return a ParamType::Enum but instead it returned: {}" , other) } ; let selector = (dis , tok , variants) ; Token :: Enum (Box :: new (selector)) } fn from_token (token : Token) -> Result < Self , SDKError > { if let Token :: Enum (enum_selector) = token { match * enum_selector { (0u8 , token , _) => Ok (SomeEnum :: SomeArr (< :: std :: vec :: Vec < u64 > > :: from_token (token) ?)) , (_ , _ , _) => Err (SDKError :: InstantiationError (format ! ("Could not construct '{}'. Failed to match with discriminant selector {:?}" , "SomeEnum" , enum_selector))) } } else { Err (SDKError :: InstantiationError (format ! ("Could not construct '{}'. Expected a token of type Token::Enum, got {:?}" , "SomeEnum" , token))) } } } impl TryFrom < & [u8] > for SomeEnum { type Error = SDKError ; fn try_from (bytes : & [u8]) -> Result < Self , Self :: Error > { try_from_bytes (bytes) } } impl TryFrom < & Vec < u8 >> for SomeEnum { type Error = SDKError ; fn try_from (bytes : & Vec < u8 >) -> Result < Self , Self :: Error > { try_from_bytes (bytes) } } impl TryFrom < Vec < u8 >> for SomeEnum { type Error = SDKError ; fn try_from (bytes : Vec < u8 >) -> Result < Self , Self :: Error > { try_from_bytes (& bytes) } }
@@ -636,7 +733,7 @@ mod tests {
                 }]),
             }]),
         };
-        let actual = expand_custom_enum("EnumLevel3", &p)?.to_string();
+        let actual = expand_custom_enum("EnumLevel3", &p, &Default::default())?.to_string();
         let expected = TokenStream::from_str(
             r#"
             # [derive (Clone , Debug , Eq , PartialEq)] pub enum EnumLevel3 { El2 (EnumLevel2) } impl Parameterize for EnumLevel3 { fn param_type () -> ParamType { let mut types = Vec :: new () ; types . push (EnumLevel2 :: param_type ()) ; let variants = EnumVariants :: new (types) . expect (concat ! ("Enum " , "EnumLevel3" , " has no variants! 'abigen!' should not have succeeded!")) ; ParamType :: Enum (variants) } } impl Tokenizable for EnumLevel3 { fn into_token (self) -> Token { let (dis , tok) = match self { EnumLevel3 :: El2 (inner_enum) => (0u8 , inner_enum . into_token ()) , } ; let variants = match Self :: param_type () { ParamType :: Enum (variants) => variants , other => panic ! ("Calling ::param_type() on a custom enum must return a ParamType::Enum but instead it returned: {}" , other) } ; let selector = (dis , tok , variants) ; Token :: Enum (Box :: new (selector)) } fn from_token (token : Token) -> Result < Self , SDKError > { if let Token :: Enum (enum_selector) = token { match * enum_selector { (0u8 , token , _) => { let variant_content = < EnumLevel2 > :: from_token (token) ? ; Ok (EnumLevel3 :: El2 (variant_content)) } (_ , _ , _) => Err (SDKError :: InstantiationError (format ! ("Could not construct '{}'. Failed to match with discriminant selector {:?}" , "EnumLevel3" , enum_selector))) } } else { Err (SDKError :: InstantiationError (format ! ("Could not construct '{}'. Expected a token of type Token::Enum, got {:?}" , "EnumLevel3" , token))) } } } impl TryFrom < & [u8] > for EnumLevel3 { type Error = SDKError ; fn try_from (bytes : & [u8]) -> Result < Self , Self :: Error > { try_from_bytes (bytes) } } impl TryFrom < & Vec < u8 >> for EnumLevel3 { type Error = SDKError ; fn try_from (bytes : & Vec < u8 >) -> Result < Self , Self :: Error > { try_from_bytes (bytes) } } impl TryFrom < Vec < u8 >> for EnumLevel3 { type Error = SDKError ; fn try_from (bytes : Vec < u8 >) -> Result < Self , Self :: Error > { try_from_bytes (& bytes) } }
@@ -670,7 +767,7 @@ mod tests {
                 },
             ]),
         };
-        let actual = expand_custom_struct(&p)?.to_string();
+        let actual = expand_custom_struct(&p, &Default::default())?.to_string();
         let expected = TokenStream::from_str(
             r#"
             # [derive (Clone , Debug , Eq , PartialEq)] pub struct Cocktail { pub long_island : bool , pub cosmopolitan : u64 , pub mojito : u32 } impl Parameterize for Cocktail { fn param_type () -> ParamType { let mut types = Vec :: new () ; types . push (ParamType :: Bool) ; types . push (ParamType :: U64) ; types . push (ParamType :: U32) ; ParamType :: Struct (types) } } impl Tokenizable for Cocktail { fn into_token (self) -> Token { let mut tokens = Vec :: new () ; tokens . push (Token :: Bool (self . long_island)) ; tokens . push (Token :: U64 (self . cosmopolitan)) ; tokens . push (Token :: U32 (self . mojito)) ; Token :: Struct (tokens) } fn from_token (token : Token) -> Result < Self , SDKError > { match token { Token :: Struct (tokens) => { let mut tokens_iter = tokens . into_iter () ; let mut next_token = move || { tokens_iter . next () . ok_or_else (|| { SDKError :: InstantiationError (format ! ("Ran out of tokens before '{}' has finished construction!" , "Cocktail")) }) } ; Ok (Self { long_island : < bool > :: from_token (next_token () ?) ? , cosmopolitan : < u64 > :: from_token (next_token () ?) ? , mojito : < u32 > :: from_token (next_token () ?) ? }) } , other => Err (SDKError :: InstantiationError (format ! ("Error while constructing '{}'. Expected token of type Token::Struct, got {:?}" , "Cocktail" , other))) , } } } impl TryFrom < & [u8] > for Cocktail { type Error = SDKError ; fn try_from (bytes : & [u8]) -> Result < Self , Self :: Error > { try_from_bytes (bytes) } } impl TryFrom < & Vec < u8 >> for Cocktail { type Error = SDKError ; fn try_from (bytes : & Vec < u8 >) -> Result < Self , Self :: Error > { try_from_bytes (bytes) } } impl TryFrom < Vec < u8 >> for Cocktail { type Error = SDKError ; fn try_from (bytes : Vec < u8 >) -> Result < Self , Self :: Error > { try_from_bytes (& bytes) } }
@@ -710,7 +807,7 @@ mod tests {
                 },
             ]),
         };
-        let actual = expand_custom_struct(&p)?.to_string();
+        let actual = expand_custom_struct(&p, &Default::default())?.to_string();
         let expected = TokenStream::from_str(
             r#"
             # [derive (Clone , Debug , Eq , PartialEq)] pub struct Cocktail { pub long_island : Shaker , pub mojito : u32 } impl Parameterize for Cocktail { fn param_type () -> ParamType { let mut types = Vec :: new () ; types . push (Shaker :: param_type ()) ; types . push (ParamType :: U32) ; ParamType :: Struct (types) } } impl Tokenizable for Cocktail { fn into_token (self) -> Token { let mut tokens = Vec :: new () ; tokens . push (self . long_island . into_token ()) ; tokens . push (Token :: U32 (self . mojito)) ; Token :: Struct (tokens) } fn from_token (token : Token) -> Result < Self , SDKError > { match token { Token :: Struct (tokens) => { let mut tokens_iter = tokens . into_iter () ; let mut next_token = move || { tokens_iter . next () . ok_or_else (|| { SDKError :: InstantiationError (format ! ("Ran out of tokens before '{}' has finished construction!" , "Cocktail")) }) } ; Ok (Self { long_island : Shaker :: from_token (next_token () ?) ? , mojito : < u32 > :: from_token (next_token () ?) ? }) } , other => Err (SDKError :: InstantiationError (format ! ("Error while constructing '{}'. Expected token of type Token::Struct, got {:?}" , "Cocktail" , other))) , } } } impl TryFrom < & [u8] > for Cocktail { type Error = SDKError ; fn try_from (bytes : & [u8]) -> Result < Self , Self :: Error > { try_from_bytes (bytes) } } impl TryFrom < & Vec < u8 >> for Cocktail { type Error = SDKError ; fn try_from (bytes : & Vec < u8 >) -> Result < Self , Self :: Error > { try_from_bytes (bytes) } } impl TryFrom < Vec < u8 >> for Cocktail { type Error = SDKError ; fn try_from (bytes : Vec < u8 >) -> Result < Self , Self :: Error > { try_from_bytes (& bytes) } }
@@ -720,4 +817,41 @@ mod tests {
         assert_eq!(actual, expected);
         Ok(())
     }
+
+    #[test]
+    fn test_expand_custom_type_options() -> Result<(), Error> {
+        let p = Property {
+            name: String::from("unused"),
+            type_field: String::from("struct Cocktail"),
+            components: Some(vec![Property {
+                name: String::from("long_island"),
+                type_field: String::from("bool"),
+                components: None,
+            }]),
+        };
+        let mut options = CustomTypeOptions::default();
+        options.add_derives(["Hash", " serde::Serialize", "Debug"])?;
+        options.set_serde_rename_all("camelCase")?;
+
+        let actual = expand_custom_struct(&p, &options)?.to_string();
+        assert!(actual.starts_with(
+            r#"# [derive (Clone , Debug , Eq , PartialEq , Hash , serde :: Serialize)] # [serde (rename_all = "camelCase")] pub struct Cocktail"#
+        ));
+
+        let err = options
+            .set_serde_rename_all("Title Case")
+            .expect_err("should have failed because the rule is unknown");
+        assert!(err
+            .to_string()
+            .contains("unknown serde rename rule `Title Case`"));
+
+        let err = options
+            .add_derives(["serde::"])
+            .expect_err("should have failed because the derive isn't a path");
+        assert_eq!(
+            err.to_string(),
+            "Invalid data: `serde::` is not a valid derive path"
+        );
+        Ok(())
+    }
 }
@@ -33,14 +33,14 @@ pub fn expand_function(
         return Err(Error::InvalidData("Function name can not be empty".into()));
     }
 
-    let name = safe_ident(&function.name);
+    let name = safe_ident(&function.name)?;
     let fn_signature = abi_parser.build_fn_selector(&function.name, &function.inputs)?;
 
     let encoded = ABIEncoder::encode_function_selector(&fn_signature);
 
     let tokenized_signature = expand_selector(encoded);
     let tokenized_output = expand_fn_outputs(&function.outputs)?;
-    let result = quote! { ContractCallHandler<#tokenized_output> };
+    let result = quote! { ContractCallHandler<#tokenized_output, A> };
 
     let (input, arg) = expand_function_arguments(function, custom_enums, custom_structs)?;
 
@@ -55,14 +55,14 @@ pub fn expand_function(
     // be used to be tokenized and passed onto `method_hash()`.
     let output_param = match &function.outputs[..] {
         [output] => {
-            let param_type = parse_param(output).unwrap();
+            let param_type = parse_param(output)?;
 
             let tok: proc_macro2::TokenStream =
-                format!("Some(ParamType::{})", param_type).parse().unwrap();
+                format!("Some(ParamType::{})", param_type).parse()?;
 
             Ok(tok)
         }
-        [] => Ok("None".parse().unwrap()),
+        [] => Ok(quote! { None }),
         &_ => Err(Error::CompilationError(
             "A function cannot have multiple outputs!".to_string(),
         )),
@@ -71,13 +71,13 @@ pub fn expand_function(
     Ok(quote! {
         #doc
         pub fn #name(&self #input) -> #result {
-            Contract::method_hash(&self.wallet.get_provider().expect("Provider not set up"), self.contract_id, &self.wallet,
+            Contract::method_hash(&self.provider, self.contract_id, self.wallet.as_ref(),
                 #tokenized_signature, #output_param, #arg).expect("method not found (this should never happen)")
         }
     })
 }
 
-fn expand_selector(selector: Selector) -> TokenStream {
+pub(crate) fn expand_selector(selector: Selector) -> TokenStream {
     let bytes = selector.iter().copied().map(Literal::u8_unsuffixed);
     quote! { [#( #bytes ),*] }
 }
@@ -95,41 +95,33 @@ fn expand_fn_outputs(outputs: &[Property]) -> Result<TokenStream, Error> {
             // If it's a {struct, enum} as the type of a function's output, use its tokenized name only.
             match output.is_struct_type() {
                 true => {
-                    let parsed_custom_type_name = extract_custom_type_name_from_abi_property(
+                    let name = extract_custom_type_name_from_abi_property(
                         output,
                         Some(CustomType::Struct),
-                    )?
-                    .parse()
-                    .expect("Custom type name should be a valid Rust identifier");
+                    )?;
+                    let ident = ident(&name)?;
 
-                    Ok(parsed_custom_type_name)
+                    Ok(quote! { #ident })
                 }
                 false => match output.is_enum_type() {
                     true => {
-                        let parsed_custom_type_name = extract_custom_type_name_from_abi_property(
+                        let name = extract_custom_type_name_from_abi_property(
                             output,
                             Some(CustomType::Enum),
-                        )?
-                        .parse()
-                        .expect("Custom type name should be a valid Rust identifier");
+                        )?;
+                        let ident = ident(&name)?;
 
-                        Ok(parsed_custom_type_name)
+                        Ok(quote! { #ident })
                     }
                     false => match output.has_custom_type_in_array() {
                         true => {
-                            let parsed_custom_type_name: TokenStream =
-                                extract_custom_type_name_from_abi_property(
-                                    output,
-                                    Some(
-                                        output
-                                            .get_custom_type()
-                                            .expect("Custom type in array should be set"),
-                                    ),
-                                )?
-                                .parse()
-                                .unwrap();
-
-                            Ok(quote! { ::std::vec::Vec<#parsed_custom_type_name> })
+                            let name = extract_custom_type_name_from_abi_property(
+                                output,
+                                output.get_custom_type(),
+                            )?;
+                            let ident = ident(&name)?;
+
+                            Ok(quote! { ::std::vec::Vec<#ident> })
                         }
                         false => expand_tuple_w_custom_types(output),
                     },
@@ -144,7 +136,10 @@ fn expand_fn_outputs(outputs: &[Property]) -> Result<TokenStream, Error> {
 
 fn expand_tuple_w_custom_types(output: &Property) -> Result<TokenStream, Error> {
     if !output.has_custom_type_in_tuple() {
-        panic!("Output is of custom type, but not an enum, struct or enum/struct inside an array/tuple. This shouldn't never happen. Output received: {:?}", output);
+        return Err(Error::InvalidType(format!(
+            "`{}` is neither a struct, an enum, nor an array or tuple of them",
+            output.type_field
+        )));
     }
 
     // If custom type is inside a tuple `(struct | enum <name>, ...)`,
@@ -153,9 +148,7 @@ fn expand_tuple_w_custom_types(output: &Property) -> Result<TokenStream, Error>
 
     let keywords_removed = remove_words(&output.type_field, &[STRUCT_KEYWORD, ENUM_KEYWORD]);
 
-    let tuple_type_signature = expand_b256_into_array_form(&keywords_removed)
-        .parse()
-        .expect("could not parse tuple type signature");
+    let tuple_type_signature = expand_b256_into_array_form(&keywords_removed).parse()?;
 
     Ok(tuple_type_signature)
 }
@@ -182,8 +175,31 @@ fn expand_function_arguments(
     custom_enums: &HashMap<String, Property>,
     custom_structs: &HashMap<String, Property>,
 ) -> Result<(TokenStream, TokenStream), Error> {
+    let (names, types): (Vec<_>, Vec<_>) =
+        expand_function_argument_types(fun, custom_enums, custom_structs)?
+            .into_iter()
+            .unzip();
+
+    // The final TokenStream of the argument declaration in a function declaration
+    let args = quote! { #( , #names: #types )* };
+
+    // The final TokenStream of the arguments being passed in a function call
+    // It'll look like `&[my_arg.into_token(), another_arg.into_token()]`
+    // as the [`Contract`] `method_hash` function expects a slice of Tokens
+    // in order to encode the call.
+    let call_args = quote! { &[ #(#names.into_token(), )* ] };
+
+    Ok((args, call_args))
+}
+
+/// Expands the name and the type of each argument of a function,
+/// e.g. `my_arg` and `u32` for `my_arg: u32`.
+pub(crate) fn expand_function_argument_types(
+    fun: &Function,
+    custom_enums: &HashMap<String, Property>,
+    custom_structs: &HashMap<String, Property>,
+) -> Result<Vec<(TokenStream, TokenStream)>, Error> {
     let mut args = vec![];
-    let mut call_args = vec![];
 
     for param in &fun.inputs {
         // For each [`Property`] in a function input we expand:
@@ -200,13 +216,13 @@ fn expand_function_arguments(
             true => {
                 if param.is_enum_type() {
                     let name =
-                        extract_custom_type_name_from_abi_property(param, Some(CustomType::Enum))
-                            .expect("couldn't extract enum name from ABI property");
+                        extract_custom_type_name_from_abi_property(param, Some(CustomType::Enum))?;
                     custom_enums.get(&name)
                 } else if param.is_struct_type() {
-                    let name =
-                        extract_custom_type_name_from_abi_property(param, Some(CustomType::Struct))
-                            .expect("couldn't extract struct name from ABI property");
+                    let name = extract_custom_type_name_from_abi_property(
+                        param,
+                        Some(CustomType::Struct),
+                    )?;
                     custom_structs.get(&name)
                 } else {
                     match param.has_custom_type_in_array() {
@@ -215,8 +231,7 @@ fn expand_function_arguments(
                                 let name = extract_custom_type_name_from_abi_property(
                                     param,
                                     Some(custom_type),
-                                )
-                                .expect("couldn't extract custom type name from ABI property");
+                                )?;
 
                                 match custom_type {
                                     CustomType::Enum => custom_enums.get(&name),
@@ -241,31 +256,15 @@ fn expand_function_arguments(
 
         // If it's a tuple, don't expand it, just use the type signature as it is (minus the string "struct " | "enum ").
         let tok = if let ParamType::Tuple(_tuple) = kind {
-            let toks = build_expanded_tuple_params(param)
-                .expect("failed to build expanded tuple parameters");
-
-            toks.parse::<TokenStream>().unwrap()
+            build_expanded_tuple_params(param)?.parse::<TokenStream>()?
         } else {
             expand_input_param(fun, &param.name, &parse_param(param)?, &custom_property)?
         };
 
-        // Add the TokenStream to argument declarations
-        args.push(quote! { #name: #tok });
-
-        // This `name` TokenStream is also added to the call arguments
-        call_args.push(name);
+        args.push((name, tok));
     }
 
-    // The final TokenStream of the argument declaration in a function declaration
-    let args = quote! { #( , #args )* };
-
-    // The final TokenStream of the arguments being passed in a function call
-    // It'll look like `&[my_arg.into_token(), another_arg.into_token()]`
-    // as the [`Contract`] `method_hash` function expects a slice of Tokens
-    // in order to encode the call.
-    let call_args = quote! { &[ #(#call_args.into_token(), )* ] };
-
-    Ok((args, call_args))
+    Ok(args)
 }
 
 // Builds a string "(type_1,type_2,type_3,...,type_n,)"
@@ -275,11 +274,13 @@ fn expand_function_arguments(
 // Should be expanded as "([u8; 32], Person,)".
 fn build_expanded_tuple_params(tuple_param: &Property) -> Result<String, Error> {
     let mut toks: String = "(".to_string();
-    for component in tuple_param
-        .components
-        .as_ref()
-        .expect("tuple parameter should have components")
-    {
+    let components = tuple_param.components.as_ref().ok_or_else(|| {
+        Error::InvalidType(format!(
+            "tuple `{}` should have components",
+            tuple_param.type_field
+        ))
+    })?;
+    for component in components {
         if !component.is_custom_type() {
             let p = parse_param(component)?;
             let tok = expand_type(&p)?;
@@ -307,7 +308,7 @@ pub fn expand_input_name(name: &str) -> Result<TokenStream, Error> {
             "Function arguments can not have empty names".into(),
         ));
     }
-    let name = safe_ident(&name.to_snake_case());
+    let name = safe_ident(&name.to_snake_case())?;
     Ok(quote! { #name })
 }
 
@@ -329,16 +330,16 @@ fn expand_input_param(
         }
         ParamType::Enum(_) => {
             let ident = ident(&extract_custom_type_name_from_abi_property(
-                custom_type_property.expect("Custom type property not found for enum"),
+                custom_type_property.ok_or_else(|| missing_custom_type(fun, param))?,
                 Some(CustomType::Enum),
-            )?);
+            )?)?;
             Ok(quote! { #ident })
         }
         ParamType::Struct(_) => {
             let ident = ident(&extract_custom_type_name_from_abi_property(
-                custom_type_property.expect("Custom type property not found for struct"),
+                custom_type_property.ok_or_else(|| missing_custom_type(fun, param))?,
                 Some(CustomType::Struct),
-            )?);
+            )?)?;
             Ok(quote! { #ident })
         }
         // Primitive type
@@ -346,6 +347,13 @@ fn expand_input_param(
     }
 }
 
+fn missing_custom_type(fun: &Function, param: &str) -> Error {
+    Error::InvalidType(format!(
+        "the custom type of argument `{}` of `{}` is missing from the ABI",
+        param, fun.name
+    ))
+}
+
 // Regarding string->TokenStream->string, refer to `custom_types_gen` tests for more details.
 #[cfg(test)]
 mod tests {
@@ -377,11 +385,11 @@ mod tests {
         let expected = TokenStream::from_str(
             r#"
             #[doc = "Calls the contract's `HelloWorld` (0x0000000097d4de45) function"]
-            pub fn HelloWorld(&self, bimbam: bool) -> ContractCallHandler<()> {
+            pub fn HelloWorld(&self, bimbam: bool) -> ContractCallHandler<(), A> {
                 Contract::method_hash(
-                    &self.wallet.get_provider().expect("Provider not set up"),
+                    &self.provider,
                     self.contract_id,
-                    &self.wallet,
+                    self.wallet.as_ref(),
                     [0, 0, 0, 0, 151, 212, 222, 69],
                     None,
                     &[bimbam.into_token() ,]
@@ -470,11 +478,11 @@ mod tests {
             pub fn hello_world(
                 &self,
                 the_only_allowed_input: SomeWeirdFrenchCuisine
-            ) -> ContractCallHandler<EntropyCirclesEnum> {
+            ) -> ContractCallHandler<EntropyCirclesEnum, A> {
                 Contract::method_hash(
-                    &self.wallet.get_provider().expect("Provider not set up"),
+                    &self.provider,
                     self.contract_id,
-                    &self.wallet,
+                    self.wallet.as_ref(),
                     [0, 0, 0, 0, 118, 178, 90, 36],
                     Some(ParamType::Enum(EnumVariants::new(vec![ParamType::Bool, ParamType::U64]).unwrap())),
                     &[the_only_allowed_input.into_token() ,]
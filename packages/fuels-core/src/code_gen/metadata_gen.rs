@@ -0,0 +1,160 @@
+use crate::abi_encoder::ABIEncoder;
+use crate::code_gen::docs_gen::expand_doc;
+use crate::code_gen::functions_gen::{expand_function_argument_types, expand_selector};
+use crate::json_abi::{parse_param, ABIParser};
+use crate::utils::ident;
+use fuels_types::errors::Error;
+use fuels_types::{Function, Property};
+use inflector::Inflector;
+use proc_macro2::{Ident, Literal, TokenStream};
+use quote::quote;
+use std::collections::HashMap;
+
+/// Expands the metadata of a contract's ABI, letting users route and decode
+/// calls without parsing the JSON ABI at runtime. The metadata are associated
+/// items of the contract's struct, so that the bindings of several contracts
+/// can be brought in the same scope:
+/// 1. `ABI`, the JSON ABI the bindings were generated from;
+/// 2. A `<FUNCTION>_SELECTOR` constant for each function;
+/// 3. `methods()`, iterating over the name, signature and selector of each function;
+/// 4. `decode_call()`, decoding the arguments of a call into a `<Contract>Calls` enum
+///    holding a variant per function.
+pub fn expand_abi_metadata(
+    contract_name: &Ident,
+    abi_json: &str,
+    functions: &[Function],
+    abi_parser: &ABIParser,
+    custom_enums: &HashMap<String, Property>,
+    custom_structs: &HashMap<String, Property>,
+) -> Result<TokenStream, Error> {
+    let calls_enum = ident(&format!("{}Calls", contract_name))?;
+
+    let mut selectors = vec![];
+    let mut methods = vec![];
+    let mut variants = vec![];
+    let mut decode_arms = vec![];
+
+    for function in functions {
+        let signature = abi_parser.build_fn_selector(&function.name, &function.inputs)?;
+        let selector = ABIEncoder::encode_function_selector(&signature);
+
+        let selector_name = ident(&format!(
+            "{}_SELECTOR",
+            function.name.to_screaming_snake_case()
+        ))?;
+        let tokenized_selector = expand_selector(selector);
+        let doc = expand_doc(&format!(
+            "Selector of the contract's `{}` function",
+            signature
+        ));
+        selectors.push(quote! {
+            #doc
+            pub const #selector_name: [u8; 8] = #tokenized_selector;
+        });
+
+        let name = Literal::string(&function.name);
+        let signature = Literal::string(&signature);
+        methods.push(quote! { (#name, #signature, #tokenized_selector) });
+
+        let variant = ident(&function.name.to_pascal_case())?;
+        let (arg_names, arg_types): (Vec<_>, Vec<_>) =
+            expand_function_argument_types(function, custom_enums, custom_structs)?
+                .into_iter()
+                .unzip();
+        variants.push(quote! { #variant { #( #arg_names: #arg_types ),* } });
+
+        if function.inputs.is_empty() {
+            decode_arms.push(quote! { Self::#selector_name => Ok(#calls_enum::#variant {}), });
+            continue;
+        }
+
+        let param_types = function
+            .inputs
+            .iter()
+            .map(|input| {
+                let param_type = parse_param(input)?;
+                Ok(format!("ParamType::{}", param_type)
+                    .parse::<TokenStream>()
+                    .expect("ParamType should be a valid Rust expression"))
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+        decode_arms.push(quote! {
+            Self::#selector_name => {
+                let mut tokens = ABIDecoder::decode(&[ #( #param_types ),* ], data)?.into_iter();
+                Ok(#calls_enum::#variant {
+                    #( #arg_names: <#arg_types>::from_token(
+                        tokens.next().expect("the decoder returns a token per type")
+                    )?, )*
+                })
+            }
+        });
+    }
+
+    let abi_json = Literal::string(abi_json);
+
+    Ok(quote! {
+        #[doc = "The arguments of a call to one of the contract's functions."]
+        #[derive(Clone, Debug, Eq, PartialEq)]
+        pub enum #calls_enum {
+            #( #variants ),*
+        }
+
+        impl #contract_name {
+            #[doc = "The JSON ABI these bindings were generated from."]
+            pub const ABI: &'static str = #abi_json;
+
+            #( #selectors )*
+
+            #[doc = "Iterates over the name, signature and selector of each of the contract's functions."]
+            pub fn methods() -> impl Iterator<Item = (&'static str, &'static str, [u8; 8])> {
+                const METHODS: &[(&str, &str, [u8; 8])] = &[ #( #methods ),* ];
+                METHODS.iter().copied()
+            }
+
+            #[doc = "Decodes the ABI-encoded arguments of a call to the function identified by `selector`."]
+            #[allow(unused_variables)]
+            pub fn decode_call(selector: [u8; 8], data: &[u8]) -> Result<#calls_enum, SDKError> {
+                match selector {
+                    #( #decode_arms )*
+                    _ => Err(SDKError::InvalidData(format!(
+                        "no function has the selector {:?}",
+                        selector
+                    ))),
+                }
+            }
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expands_abi_metadata() -> Result<(), Error> {
+        let function = Function {
+            type_field: "function".to_string(),
+            name: "set_value".to_string(),
+            inputs: vec![Property {
+                name: "new_value".to_string(),
+                type_field: "u64".to_string(),
+                components: None,
+            }],
+            outputs: vec![],
+        };
+
+        let result = expand_abi_metadata(
+            &ident("MyContract")?,
+            "[]",
+            &[function],
+            &ABIParser::new(),
+            &Default::default(),
+            &Default::default(),
+        )?;
+
+        let expected = r#"# [doc = "The arguments of a call to one of the contract's functions."] # [derive (Clone , Debug , Eq , PartialEq)] pub enum MyContractCalls { SetValue { new_value : u64 } } impl MyContract { # [doc = "The JSON ABI these bindings were generated from."] pub const ABI : & 'static str = "[]" ; # [doc = "Selector of the contract's `set_value(u64)` function"] pub const SET_VALUE_SELECTOR : [u8 ; 8] = [0 , 0 , 0 , 0 , 224 , 255 , 56 , 143] ; # [doc = "Iterates over the name, signature and selector of each of the contract's functions."] pub fn methods () -> impl Iterator < Item = (& 'static str , & 'static str , [u8 ; 8]) > { const METHODS : & [(& str , & str , [u8 ; 8])] = & [("set_value" , "set_value(u64)" , [0 , 0 , 0 , 0 , 224 , 255 , 56 , 143])] ; METHODS . iter () . copied () } # [doc = "Decodes the ABI-encoded arguments of a call to the function identified by `selector`."] # [allow (unused_variables)] pub fn decode_call (selector : [u8 ; 8] , data : & [u8]) -> Result < MyContractCalls , SDKError > { match selector { Self :: SET_VALUE_SELECTOR => { let mut tokens = ABIDecoder :: decode (& [ParamType :: U64] , data) ? . into_iter () ; Ok (MyContractCalls :: SetValue { new_value : < u64 > :: from_token (tokens . next () . expect ("the decoder returns a token per type")) ? , }) } _ => Err (SDKError :: InvalidData (format ! ("no function has the selector {:?}" , selector))) , } } }"#;
+
+        assert_eq!(result.to_string(), expected);
+        Ok(())
+    }
+}
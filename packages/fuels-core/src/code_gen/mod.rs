@@ -2,6 +2,8 @@
 //! from a fuel/sway ABI.
 pub mod abigen;
 pub mod bindings;
+pub mod builder;
 pub mod custom_types_gen;
 pub mod docs_gen;
 pub mod functions_gen;
+pub mod metadata_gen;
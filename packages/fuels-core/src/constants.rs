@@ -12,13 +12,18 @@ pub const WORD_SIZE: usize = core::mem::size_of::<Word>();
 pub const ENUM_DISCRIMINANT_WORD_WIDTH: usize = 1;
 
 // ANCHOR: default_call_parameters
-// Limit for the actual contract call
-pub const DEFAULT_FORWARDED_GAS: u64 = 1_000_000;
 // Lower limit when querying spendable UTXOs
 pub const DEFAULT_SPENDABLE_COIN_AMOUNT: u64 = 1_000_000;
 // Bytes representation of the asset ID of the "base" asset used for gas fees.
 pub const BASE_ASSET_ID: AssetId = AssetId::new([0u8; 32]);
 // ANCHOR_END: default_call_parameters
 
+/// Gas that `CallParameters` used to forward to a call by default.
+#[deprecated(
+    since = "0.17.0",
+    note = "`CallParameters::gas_forwarded` is now an `Option<u64>`, a call forwards all the gas remaining in the transaction when it's `None`"
+)]
+pub const DEFAULT_FORWARDED_GAS: u64 = 1_000_000;
+
 pub const CONTRACT_ID_SWAY_NATIVE_TYPE: &str = "ContractId";
 pub const ADDRESS_SWAY_NATIVE_TYPE: &str = "Address";
@@ -1,13 +1,16 @@
+use crate::value_parser::{parse_array, parse_value};
 use crate::Token;
-use crate::{abi_decoder::ABIDecoder, abi_encoder::ABIEncoder};
+use crate::{
+    abi_decoder::{ABIDecoder, DecoderCursor},
+    abi_encoder::ABIEncoder,
+};
 use fuels_types::{
     errors::Error,
     param_types::{EnumVariants, ParamType},
     JsonABI, Property,
 };
-use hex::FromHex;
 use itertools::Itertools;
-use serde_json;
+use serde_json::{self, Value};
 use std::str;
 use std::str::FromStr;
 
@@ -79,16 +82,29 @@ impl ABIParser {
         // Update the fn_selector field with the encoded selector.
         self.fn_selector = Some(ABIEncoder::encode_function_selector(&fn_selector).to_vec());
 
-        let params: Vec<_> = entry
+        if entry.inputs.len() != values.len() {
+            return Err(Error::InvalidData(format!(
+                "function `{}` expects {} arguments, got {}",
+                fn_name,
+                entry.inputs.len(),
+                values.len()
+            )));
+        }
+
+        let types = entry
             .inputs
             .iter()
-            .map(|param| parse_param(param).unwrap())
-            .zip(values.iter().map(|v| v as &str))
-            .collect();
+            .map(parse_param)
+            .collect::<Result<Vec<_>, _>>()?;
 
-        let tokens = self.parse_tokens(&params)?;
+        let tokens = entry
+            .inputs
+            .iter()
+            .zip(values)
+            .map(|(input, value)| self.tokenize_property(input, value))
+            .collect::<Result<Vec<_>, _>>()?;
 
-        Ok(hex::encode(ABIEncoder::encode(&tokens)?))
+        Ok(hex::encode(ABIEncoder::encode_with_types(&types, &tokens)?))
     }
 
     /// Similar to `encode`, but includes the function selector in the
@@ -148,6 +164,80 @@ impl ABIParser {
         Ok(format!("{}{}", encoded_function_selector, encoded_params))
     }
 
+    /// Same as `encode`, but the arguments are given as JSON, either as an
+    /// array holding them in order or as an object keyed by parameter name.
+    /// See [`Token::from_json`] for how each argument is converted.
+    ///
+    /// # Examples
+    /// ```
+    /// use fuels_core::json_abi::ABIParser;
+    /// use serde_json::json;
+    ///
+    /// let json_abi = r#"
+    ///     [
+    ///         {
+    ///             "type":"contract",
+    ///             "inputs":[
+    ///                 {
+    ///                     "name":"arg",
+    ///                     "type":"u32"
+    ///                 }
+    ///             ],
+    ///             "name":"takes_u32_returns_bool",
+    ///             "outputs":[]
+    ///         }
+    ///     ]
+    ///     "#;
+    ///
+    /// let mut abi = ABIParser::new();
+    ///
+    /// let function_name = "takes_u32_returns_bool";
+    /// let by_position = abi.encode_json(json_abi, function_name, &json!([10])).unwrap();
+    /// let by_name = abi.encode_json(json_abi, function_name, &json!({"arg": 10})).unwrap();
+    /// assert_eq!(by_position, "000000000000000a");
+    /// assert_eq!(by_name, "000000000000000a");
+    /// ```
+    pub fn encode_json(&mut self, abi: &str, fn_name: &str, args: &Value) -> Result<String, Error> {
+        let parsed_abi: JsonABI = serde_json::from_str(abi)?;
+
+        let entry = parsed_abi
+            .iter()
+            .find(|e| e.name == fn_name)
+            .ok_or_else(|| {
+                Error::InvalidName(format!("couldn't find function name: {}", fn_name))
+            })?;
+
+        let fn_selector = self.build_fn_selector(fn_name, &entry.inputs)?;
+        self.fn_selector = Some(ABIEncoder::encode_function_selector(&fn_selector).to_vec());
+
+        let types = entry
+            .inputs
+            .iter()
+            .map(parse_param)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let tokens = self.tokenize_json_args(&entry.inputs, args)?;
+
+        Ok(hex::encode(ABIEncoder::encode_with_types(&types, &tokens)?))
+    }
+
+    /// Similar to `encode_json`, but includes the function selector in the
+    /// final encoded string.
+    pub fn encode_json_with_function_selector(
+        &mut self,
+        abi: &str,
+        fn_name: &str,
+        args: &Value,
+    ) -> Result<String, Error> {
+        let encoded_params = self.encode_json(abi, fn_name, args)?;
+
+        Ok(format!(
+            "{}{}",
+            self.get_encoded_function_selector(),
+            encoded_params
+        ))
+    }
+
     /// Helper function to return the encoded function selector.
     /// It must already be encoded.
     pub fn get_encoded_function_selector(&self) -> String {
@@ -180,8 +270,12 @@ impl ABIParser {
         }
 
         let tokens = self.parse_tokens(&param_type_pairs)?;
+        let types: Vec<_> = param_type_pairs
+            .into_iter()
+            .map(|(param, _)| param)
+            .collect();
 
-        let encoded = ABIEncoder::encode(&tokens)?;
+        let encoded = ABIEncoder::encode_with_types(&types, &tokens)?;
 
         Ok(hex::encode(encoded))
     }
@@ -200,342 +294,99 @@ impl ABIParser {
     /// Takes a ParamType and a value string and joins them as a single
     /// Token that holds the value within it. This Token is used
     /// in the encoding process.
+    /// See [`crate::value_parser`] for the syntax of the value string.
     pub fn tokenize(&self, param: &ParamType, value: String) -> Result<Token, Error> {
-        let trimmed_value = value.trim();
-        match &*param {
-            ParamType::Unit => Ok(Token::Unit),
-            ParamType::U8 => Ok(Token::U8(trimmed_value.parse::<u8>()?)),
-            ParamType::U16 => Ok(Token::U16(trimmed_value.parse::<u16>()?)),
-            ParamType::U32 => Ok(Token::U32(trimmed_value.parse::<u32>()?)),
-            ParamType::U64 => Ok(Token::U64(trimmed_value.parse::<u64>()?)),
-            ParamType::Bool => Ok(Token::Bool(trimmed_value.parse::<bool>()?)),
-            ParamType::Byte => Ok(Token::Byte(trimmed_value.parse::<u8>()?)),
-            ParamType::B256 => {
-                const B256_HEX_ENC_LENGTH: usize = 64;
-                if trimmed_value.len() != B256_HEX_ENC_LENGTH {
-                    return Err(Error::InvalidData(format!(
-                        "the hex encoding of the b256 must have {} characters",
-                        B256_HEX_ENC_LENGTH
-                    )));
-                }
-                let v = Vec::from_hex(trimmed_value)?;
-                let s: [u8; 32] = v.as_slice().try_into().unwrap();
-                Ok(Token::B256(s))
-            }
-            ParamType::Array(t, _) => Ok(self.tokenize_array(trimmed_value, &*t)?),
-            ParamType::String(_) => Ok(Token::String(trimmed_value.to_string())),
-            ParamType::Struct(struct_params) => {
-                Ok(self.tokenize_struct(trimmed_value, struct_params)?)
-            }
-            ParamType::Enum(variants) => {
-                let discriminant = self.get_enum_discriminant_from_string(trimmed_value);
-                let value = self.get_enum_value_from_string(trimmed_value);
-
-                let token = self.tokenize(&variants.param_types()[discriminant], value)?;
+        parse_value(&value, param, None)
+    }
 
-                Ok(Token::Enum(Box::new((
-                    discriminant as u8,
-                    token,
-                    variants.clone(),
-                ))))
-            }
-            ParamType::Tuple(tuple_params) => Ok(self.tokenize_tuple(trimmed_value, tuple_params)?),
-        }
+    /// Same as `tokenize`, but the type of the value is taken from `property`,
+    /// whose components are used to resolve enum variants given by name.
+    pub fn tokenize_property(&self, property: &Property, value: &str) -> Result<Token, Error> {
+        parse_value(value, &parse_param(property)?, Some(property))
     }
 
-    /// Creates a `Token::Struct` from an array of parameter types and a string of values.
-    /// I.e. it takes a string containing values "value_1, value_2, value_3" and an array
-    /// of `ParamType` containing the type of each value, in order:
-    /// [ParamType::<Type of value_1>, ParamType::<Type of value_2>, ParamType::<Type of value_3>]
-    /// And attempts to return a `Token::Struct()` containing the inner types.
-    /// It works for nested/recursive structs.
+    /// Creates a `Token::Struct` from a string of values, such as `(1, true)`, and the types of
+    /// the struct's fields.
+    #[deprecated(
+        since = "0.17.0",
+        note = "use `tokenize` with a `ParamType::Struct` instead"
+    )]
     pub fn tokenize_struct(&self, value: &str, params: &[ParamType]) -> Result<Token, Error> {
-        if !value.starts_with('(') || !value.ends_with(')') {
-            return Err(Error::InvalidData(
-                "struct value string must start and end with round brackets".into(),
-            ));
-        }
-
-        if value.chars().count() == 2 {
-            return Ok(Token::Struct(vec![]));
-        }
-
-        //To parse the value string we use a two pointer/index approach.
-        //The items are comma separated and if an item is tokenized the last_item
-        //index is moved to the current position.
-        //The variable nested is incremented and decremented if a bracket is encountered,
-        //and appropriate errors are returned if the nested count is not 0.
-        //If the struct has an array inside its values the current position will be incremented
-        //until both the opening and closing bracket are inside the new item.
-        //Characters inside quotes are ignored and they are tokenized as one item.
-        //An error is return if there is an odd number of quotes.
-        let mut result = vec![];
-        let mut nested = 0isize;
-        let mut ignore = false;
-        let mut last_item = 1;
-        let mut params_iter = params.iter();
-
-        for (pos, ch) in value.chars().enumerate() {
-            match ch {
-                '(' if !ignore => {
-                    nested += 1;
-                }
-                ')' if !ignore => {
-                    nested -= 1;
-
-                    match nested.cmp(&0) {
-                        std::cmp::Ordering::Less => {
-                            return Err(Error::InvalidData(
-                                "struct value string has excess closing brackets".into(),
-                            ));
-                        }
-                        std::cmp::Ordering::Equal => {
-                            let sub = &value[last_item..pos];
-
-                            let token = self.tokenize(
-                                params_iter.next().ok_or_else(|| {
-                                    Error::InvalidData(
-                                        "struct value contains more elements than the parameter types provided".into(),
-                                    )
-                                })?,
-                                sub.to_string(),
-                            )?;
-                            result.push(token);
-                            last_item = pos + 1;
-                        }
-                        _ => {}
-                    }
-                }
-                '"' => {
-                    ignore = !ignore;
-                }
-                ',' if nested == 1 && !ignore => {
-                    let sub = &value[last_item..pos];
-                    // If we've encountered an array within a struct property
-                    // keep iterating until we see the end of it "]".
-                    if sub.contains('[') && !sub.contains(']') {
-                        continue;
-                    }
-
-                    let token = self.tokenize(
-                        params_iter.next().ok_or_else(|| {
-                            Error::InvalidData(
-                                "struct value contains more elements than the parameter types provided".into(),
-                            )
-                        })?,
-                        sub.to_string(),
-                    )?;
-                    result.push(token);
-                    last_item = pos + 1;
-                }
-                _ => (),
-            }
-        }
-
-        if ignore {
-            return Err(Error::InvalidData(
-                "struct value string has excess quotes".into(),
-            ));
-        }
-
-        if nested > 0 {
-            return Err(Error::InvalidData(
-                "struct value string has excess opening brackets".into(),
-            ));
-        }
-
-        Ok(Token::Struct(result))
+        parse_value(value, &ParamType::Struct(params.to_vec()), None)
     }
 
-    /// Creates a `Token::Array` from one parameter type and a string of values.
-    /// I.e. it takes a string containing values "value_1, value_2, value_3" and a
-    /// `ParamType` sepecifying the type.
-    /// It works for nested/recursive arrays.
-    pub fn tokenize_array<'a>(&self, value: &'a str, param: &ParamType) -> Result<Token, Error> {
-        if !value.starts_with('[') || !value.ends_with(']') {
-            return Err(Error::InvalidData(
-                "array value string must start and end with square brackets".into(),
-            ));
-        }
-
-        if value.chars().count() == 2 {
-            return Ok(Token::Array(vec![]));
-        }
-
-        //for more details about this algorithm, refer to the tokenize_struct method
-        let mut result = vec![];
-        let mut nested = 0isize;
-        let mut ignore = false;
-        let mut last_item = 1;
-        for (i, ch) in value.chars().enumerate() {
-            match ch {
-                '[' if !ignore => {
-                    nested += 1;
-                }
-                ']' if !ignore => {
-                    nested -= 1;
-
-                    match nested.cmp(&0) {
-                        std::cmp::Ordering::Less => {
-                            return Err(Error::InvalidData(
-                                "array value string has excess closing brackets".into(),
-                            ));
-                        }
-                        std::cmp::Ordering::Equal => {
-                            // Last element of this nest level; proceed to tokenize.
-                            let sub = &value[last_item..i];
-                            match self.is_array(sub) {
-                                true => {
-                                    let arr_param = ParamType::Array(
-                                        Box::new(param.to_owned()),
-                                        self.get_array_length_from_string(sub),
-                                    );
-
-                                    result.push(self.tokenize(&arr_param, sub.to_string())?);
-                                }
-                                false => {
-                                    result.push(self.tokenize(param, sub.to_string())?);
-                                }
-                            }
-
-                            last_item = i + 1;
-                        }
-                        _ => {}
-                    }
-                }
-                '"' => {
-                    ignore = !ignore;
-                }
-                ',' if nested == 1 && !ignore => {
-                    let sub = &value[last_item..i];
-                    match self.is_array(sub) {
-                        true => {
-                            let arr_param = ParamType::Array(
-                                Box::new(param.to_owned()),
-                                self.get_array_length_from_string(sub),
-                            );
-
-                            result.push(self.tokenize(&arr_param, sub.to_string())?);
-                        }
-                        false => {
-                            result.push(self.tokenize(param, sub.to_string())?);
-                        }
-                    }
-                    last_item = i + 1;
-                }
-                _ => (),
-            }
-        }
-
-        if ignore {
-            return Err(Error::InvalidData(
-                "array value string has excess quotes".into(),
-            ));
-        }
-
-        if nested > 0 {
-            return Err(Error::InvalidData(
-                "array value string has excess opening brackets".into(),
-            ));
-        }
-
-        Ok(Token::Array(result))
+    /// Creates a `Token::Array` from a string of values of type `param`, such as `[1, 2]`. The
+    /// array can have any length.
+    #[deprecated(
+        since = "0.17.0",
+        note = "use `tokenize` with a `ParamType::Array` instead"
+    )]
+    pub fn tokenize_array(&self, value: &str, param: &ParamType) -> Result<Token, Error> {
+        parse_array(value, param)
     }
 
-    /// Creates `Token::Tuple` from an array of parameter types and a string of values.
-    /// I.e. it takes a string containing values "value_1, value_2, value_3" and an array
-    /// of `ParamType` containing the type of each value, in order:
-    /// [ParamType::<Type of value_1>, ParamType::<Type of value_2>, ParamType::<Type of value_3>]
-    /// And attempts to return a `Token::Tuple()` containing the inner types.
-    /// It works for nested/recursive tuples.
+    /// Creates a `Token::Tuple` from a string of values, such as `(1, true)`, and the types of
+    /// the tuple's elements.
+    #[deprecated(
+        since = "0.17.0",
+        note = "use `tokenize` with a `ParamType::Tuple` instead"
+    )]
     pub fn tokenize_tuple(&self, value: &str, params: &[ParamType]) -> Result<Token, Error> {
-        if !value.starts_with('(') || !value.ends_with(')') {
-            return Err(Error::InvalidData(
-                "tuple value string must start and end with round brackets".into(),
-            ));
-        }
-
-        if value.chars().count() == 2 {
-            return Ok(Token::Tuple(vec![]));
-        }
+        parse_value(value, &ParamType::Tuple(params.to_vec()), None)
+    }
 
-        //for more details about this algorithm, refer to the tokenize_struct method
-        let mut result = vec![];
-        let mut nested = 0isize;
-        let mut ignore = false;
-        let mut last_item = 1;
-        let mut params_iter = params.iter();
-
-        for (pos, ch) in value.chars().enumerate() {
-            match ch {
-                '(' if !ignore => {
-                    nested += 1;
-                }
-                ')' if !ignore => {
-                    nested -= 1;
-
-                    match nested.cmp(&0) {
-                        std::cmp::Ordering::Less => {
-                            return Err(Error::InvalidData(
-                                "tuple value string has excess closing brackets".into(),
-                            ));
-                        }
-                        std::cmp::Ordering::Equal => {
-                            let sub = &value[last_item..pos];
-
-                            let token = self.tokenize(
-                                params_iter.next().ok_or_else(|| {
-                                    Error::InvalidData(
-                                        "tuple value contains more elements than the parameter types provided".into(),
-                                    )
-                                })?,
-                                sub.to_string(),
-                            )?;
-                            result.push(token);
-                            last_item = pos + 1;
-                        }
-                        _ => {}
-                    }
-                }
-                '"' => {
-                    ignore = !ignore;
+    /// Turns the arguments of a function, given as a JSON array holding them
+    /// in order or as a JSON object keyed by parameter name, into Tokens
+    /// typed after `inputs`.
+    pub fn tokenize_json_args(
+        &self,
+        inputs: &[Property],
+        args: &Value,
+    ) -> Result<Vec<Token>, Error> {
+        let values: Vec<&Value> = match args {
+            Value::Array(values) => {
+                if values.len() != inputs.len() {
+                    return Err(Error::InvalidData(format!(
+                        "expected {} arguments, got {}",
+                        inputs.len(),
+                        values.len()
+                    )));
                 }
-                ',' if nested == 1 && !ignore => {
-                    let sub = &value[last_item..pos];
-                    // If we've encountered an array within a tuple property
-                    // keep iterating until we see the end of it "]".
-                    if sub.contains('[') && !sub.contains(']') {
-                        continue;
-                    }
-
-                    let token = self.tokenize(
-                        params_iter.next().ok_or_else(|| {
-                            Error::InvalidData(
-                                "tuple value contains more elements than the parameter types provided".into(),
-                            )
-                        })?,
-                        sub.to_string(),
-                    )?;
-                    result.push(token);
-                    last_item = pos + 1;
+                values.iter().collect()
+            }
+            Value::Object(object) => {
+                if let Some(unknown) = object
+                    .keys()
+                    .find(|key| !inputs.iter().any(|input| &input.name == *key))
+                {
+                    return Err(Error::InvalidData(format!(
+                        "unknown argument `{}`, expected one of: {}",
+                        unknown,
+                        inputs.iter().map(|input| input.name.as_str()).join(", ")
+                    )));
                 }
-                _ => (),
+                inputs
+                    .iter()
+                    .map(|input| {
+                        object.get(&input.name).ok_or_else(|| {
+                            Error::InvalidData(format!("missing argument `{}`", input.name))
+                        })
+                    })
+                    .collect::<Result<_, _>>()?
             }
-        }
-
-        if ignore {
-            return Err(Error::InvalidData(
-                "tuple value string has excess quotes".into(),
-            ));
-        }
-
-        if nested > 0 {
-            return Err(Error::InvalidData(
-                "tuple value string has excess opening brackets".into(),
-            ));
-        }
+            _ => {
+                return Err(Error::InvalidData(format!(
+                    "arguments must be given as a JSON array or object, found `{}`",
+                    args
+                )))
+            }
+        };
 
-        Ok(Token::Tuple(result))
+        inputs
+            .iter()
+            .zip(values)
+            .map(|(input, value)| Token::from_json(value, &parse_param(input)?, Some(input)))
+            .collect()
     }
 
     /// Higher-level layer of the ABI decoding module.
@@ -559,13 +410,15 @@ impl ABIParser {
             )));
         }
 
-        let params_result: Result<Vec<_>, _> =
-            entry.unwrap().outputs.iter().map(parse_param).collect();
-
-        match params_result {
-            Ok(params) => Ok(ABIDecoder::decode(&params, value)?),
-            Err(e) => Err(e),
-        }
+        // Decode through the output properties so that errors name the
+        // fields that couldn't be decoded.
+        let mut cursor = DecoderCursor::new(value);
+        entry
+            .unwrap()
+            .outputs
+            .iter()
+            .map(|output| Ok(cursor.decode_property(&parse_param(output)?, output)?))
+            .collect()
     }
 
     /// Similar to decode, but it decodes only an array types and the encoded data
@@ -574,33 +427,6 @@ impl ABIParser {
         Ok(ABIDecoder::decode(params, data)?)
     }
 
-    fn is_array(&self, ele: &str) -> bool {
-        ele.starts_with('[') && ele.ends_with(']')
-    }
-
-    fn get_enum_discriminant_from_string(&self, ele: &str) -> usize {
-        let mut chars = ele.chars();
-        chars.next(); // Remove "("
-        chars.next_back(); // Remove ")"
-        let v: Vec<_> = chars.as_str().split(',').collect();
-        v[0].parse().unwrap()
-    }
-
-    fn get_enum_value_from_string(&self, ele: &str) -> String {
-        let mut chars = ele.chars();
-        chars.next(); // Remove "("
-        chars.next_back(); // Remove ")"
-        let v: Vec<_> = chars.as_str().split(',').collect();
-        v[1].to_string()
-    }
-
-    fn get_array_length_from_string(&self, ele: &str) -> usize {
-        let mut chars = ele.chars();
-        chars.next();
-        chars.next_back();
-        chars.as_str().split(',').count()
-    }
-
     /// Builds a string representation of a function selector,
     /// i.e: <fn_name>(<type_1>, <type_2>, ..., <type_n>)
     pub fn build_fn_selector(&self, fn_name: &str, params: &[Property]) -> Result<String, Error> {
@@ -729,11 +555,13 @@ pub fn parse_param(prop: &Property) -> Result<ParamType, Error> {
 pub fn parse_tuple_param(param: &Property) -> Result<ParamType, Error> {
     let mut params: Vec<ParamType> = Vec::new();
 
-    for tuple_component in param
-        .components
-        .as_ref()
-        .expect("tuples should have components")
-    {
+    let components = param.components.as_ref().ok_or_else(|| {
+        Error::InvalidType(format!(
+            "tuple `{}` should have components holding its element types",
+            param.type_field
+        ))
+    })?;
+    for tuple_component in components {
         params.push(parse_param(tuple_component)?);
     }
 
@@ -772,9 +600,13 @@ pub fn parse_array_param(param: &Property) -> Result<ParamType, Error> {
             param
                 .components
                 .as_ref()
-                .expect("array should have components")
-                .first()
-                .expect("components in array should have at least one component"),
+                .and_then(|components| components.first())
+                .ok_or_else(|| {
+                    Error::InvalidType(format!(
+                        "array `{}` should have a component holding its element type",
+                        param.type_field
+                    ))
+                })?,
         )?,
     };
 
@@ -1079,64 +911,6 @@ mod tests {
         Ok(())
     }
 
-    #[test]
-    fn tokenize_array() -> Result<(), Error> {
-        let abi = ABIParser::new();
-
-        let value = "[[1,2],[3],4]";
-        let param = ParamType::U16;
-        let tokens = abi.tokenize_array(value, &param)?;
-
-        let expected_tokens = Token::Array(vec![
-            Token::Array(vec![Token::U16(1), Token::U16(2)]), // First element, a sub-array with 2 elements
-            Token::Array(vec![Token::U16(3)]), // Second element, a sub-array with 1 element
-            Token::U16(4),                     // Third element
-        ]);
-
-        assert_eq!(tokens, expected_tokens);
-
-        let value = "[1,[2],[3],[4,5]]";
-        let param = ParamType::U16;
-        let tokens = abi.tokenize_array(value, &param)?;
-
-        let expected_tokens = Token::Array(vec![
-            Token::U16(1),
-            Token::Array(vec![Token::U16(2)]),
-            Token::Array(vec![Token::U16(3)]),
-            Token::Array(vec![Token::U16(4), Token::U16(5)]),
-        ]);
-
-        assert_eq!(tokens, expected_tokens);
-
-        let value = "[1,2,3,4,5]";
-        let param = ParamType::U16;
-        let tokens = abi.tokenize_array(value, &param)?;
-
-        let expected_tokens = Token::Array(vec![
-            Token::U16(1),
-            Token::U16(2),
-            Token::U16(3),
-            Token::U16(4),
-            Token::U16(5),
-        ]);
-
-        assert_eq!(tokens, expected_tokens);
-
-        let value = "[[1,2,3,[4,5]]]";
-        let param = ParamType::U16;
-        let tokens = abi.tokenize_array(value, &param)?;
-
-        let expected_tokens = Token::Array(vec![Token::Array(vec![
-            Token::U16(1),
-            Token::U16(2),
-            Token::U16(3),
-            Token::Array(vec![Token::U16(4), Token::U16(5)]),
-        ])]);
-
-        assert_eq!(tokens, expected_tokens);
-        Ok(())
-    }
-
     #[test]
     fn nested_array_encode_and_decode() -> Result<(), Error> {
         let json_abi = r#"
@@ -1166,11 +940,14 @@ mod tests {
 
         let function_name = "takes_nested_array";
 
-        let encoded = abi.encode_with_function_selector(json_abi, function_name, &values)?;
+        // The nested arrays don't match the declared `[u16; 3]`, so they must
+        // be rejected rather than encoded into a payload of the wrong shape.
+        let error = abi
+            .encode_with_function_selector(json_abi, function_name, &values)
+            .expect_err("Should have resulted in an error");
 
-        let expected_encode =
-            "00000000e6a030f00000000000000001000000000000000200000000000000030000000000000004";
-        assert_eq!(encoded, expected_encode);
+        let expected_msg = "expected a u16, found `[` at column 2";
+        assert!(matches!(error, Error::InvalidData(msg) if msg == expected_msg));
 
         let return_value = [
             0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, 0x0, // 0
@@ -1868,485 +1645,261 @@ mod tests {
     }
 
     #[test]
-    fn tokenize_uint_types_expected_error() {
-        let abi = ABIParser::new();
-
-        // We test only on U8 as it is the same error on all other unsigned int types
-        let error_message = abi
-            .tokenize(&ParamType::U8, "2,".to_string())
-            .unwrap_err()
-            .to_string();
-
-        assert_eq!(
-            "Parse integer error: invalid digit found in string",
-            error_message
-        );
-    }
-
-    #[test]
-    fn tokenize_bool_expected_error() {
-        let abi = ABIParser::new();
-
-        let error_message = abi
-            .tokenize(&ParamType::Bool, "True".to_string())
-            .unwrap_err()
-            .to_string();
-
-        assert_eq!(
-            "Parse boolean error: provided string was not `true` or `false`",
-            error_message
-        );
-    }
-
-    #[test]
-    fn tokenize_b256_invalid_length_expected_error() {
-        let abi = ABIParser::new();
-
-        let value = "d57a9c46dfcc7f18207013e65b44e4cb4e2c2298f4ac457ba8f82743f31e90b".to_string();
-        let error_message = abi
-            .tokenize(&ParamType::B256, value)
-            .unwrap_err()
-            .to_string();
-
-        assert_eq!(
-            "Invalid data: the hex encoding of the b256 must have 64 characters",
-            error_message
-        );
-    }
-
-    #[test]
-    fn tokenize_b256_invalid_character_expected_error() {
-        let abi = ABIParser::new();
-
-        let value = "Hd57a9c46dfcc7f18207013e65b44e4cb4e2c2298f4ac457ba8f82743f31e90b".to_string();
-        let error_message = abi
-            .tokenize(&ParamType::B256, value)
-            .unwrap_err()
-            .to_string();
-
-        assert!(error_message.contains("Parse hex error: Invalid character"));
-    }
-
-    #[test]
-    fn tokenize_tuple_invalid_start_end_bracket_expected_error() -> Result<(), Error> {
-        let abi = ABIParser::new();
-        let params = Property {
-            name: "input".to_string(),
-            type_field: "(u64, [u64; 3])".to_string(),
-            components: Some(vec![
-                Property {
-                    name: "__tuple_element".to_string(),
-                    type_field: "u64".to_string(),
-                    components: None,
-                },
-                Property {
-                    name: "__tuple_element".to_string(),
-                    type_field: "[u64; 3]".to_string(),
-                    components: None,
-                },
-            ]),
-        };
-
-        if let ParamType::Tuple(tuple_params) = parse_tuple_param(&params)? {
-            let error_message = abi
-                .tokenize_tuple("0, [0,0,0])", &tuple_params)
-                .unwrap_err()
-                .to_string();
-
-            assert_eq!(
-                "Invalid data: tuple value string must start and end with round brackets",
-                error_message
-            );
-        }
-        Ok(())
-    }
-
-    #[test]
-    fn tokenize_tuple_excess_opening_bracket_expected_error() -> Result<(), Error> {
+    #[allow(deprecated)]
+    fn tokenize_array() -> Result<(), Error> {
         let abi = ABIParser::new();
-        let params = Property {
-            name: "input".to_string(),
-            type_field: "(u64, [u64; 3])".to_string(),
-            components: Some(vec![
-                Property {
-                    name: "__tuple_element".to_string(),
-                    type_field: "u64".to_string(),
-                    components: None,
-                },
-                Property {
-                    name: "__tuple_element".to_string(),
-                    type_field: "[u64; 3]".to_string(),
-                    components: None,
-                },
-            ]),
-        };
 
-        if let ParamType::Tuple(tuple_params) = parse_tuple_param(&params)? {
-            let error_message = abi
-                .tokenize_tuple("((0, [0,0,0])", &tuple_params)
-                .unwrap_err()
-                .to_string();
+        let value = "[1,2,3,4,5]";
+        let param = ParamType::U16;
+        let tokens = abi.tokenize_array(value, &param)?;
 
-            assert_eq!(
-                "Invalid data: tuple value string has excess opening brackets",
-                error_message
-            );
-        }
-        Ok(())
-    }
+        let expected_tokens = Token::Array(vec![
+            Token::U16(1),
+            Token::U16(2),
+            Token::U16(3),
+            Token::U16(4),
+            Token::U16(5),
+        ]);
 
-    #[test]
-    fn tokenize_tuple_excess_closing_bracket_expected_error() -> Result<(), Error> {
-        let abi = ABIParser::new();
-        let params = Property {
-            name: "input".to_string(),
-            type_field: "(u64, [u64; 3])".to_string(),
-            components: Some(vec![
-                Property {
-                    name: "__tuple_element".to_string(),
-                    type_field: "u64".to_string(),
-                    components: None,
-                },
-                Property {
-                    name: "__tuple_element".to_string(),
-                    type_field: "[u64; 3]".to_string(),
-                    components: None,
-                },
-            ]),
-        };
+        assert_eq!(tokens, expected_tokens);
 
-        if let ParamType::Tuple(tuple_params) = parse_tuple_param(&params)? {
-            let error_message = abi
-                .tokenize_tuple("(0, [0,0,0]))", &tuple_params)
-                .unwrap_err()
-                .to_string();
+        let value = "[[1,2],[3,4]]";
+        let param = ParamType::Array(Box::new(ParamType::U16), 2);
+        let tokens = abi.tokenize_array(value, &param)?;
 
-            assert_eq!(
-                "Invalid data: tuple value string has excess closing brackets",
-                error_message
-            );
-        }
-        Ok(())
-    }
+        let expected_tokens = Token::Array(vec![
+            Token::Array(vec![Token::U16(1), Token::U16(2)]),
+            Token::Array(vec![Token::U16(3), Token::U16(4)]),
+        ]);
 
-    #[test]
-    fn tokenize_tuple_excess_quotes_expected_error() -> Result<(), Error> {
-        let abi = ABIParser::new();
-        let params = Property {
-            name: "input".to_string(),
-            type_field: "(u64, [u64; 3])".to_string(),
-            components: Some(vec![
-                Property {
-                    name: "__tuple_element".to_string(),
-                    type_field: "u64".to_string(),
-                    components: None,
-                },
-                Property {
-                    name: "__tuple_element".to_string(),
-                    type_field: "[u64; 3]".to_string(),
-                    components: None,
-                },
-            ]),
-        };
+        assert_eq!(tokens, expected_tokens);
 
-        if let ParamType::Tuple(tuple_params) = parse_tuple_param(&params)? {
-            let error_message = abi
-                .tokenize_tuple("(0, \"[0,0,0])", &tuple_params)
-                .unwrap_err()
-                .to_string();
+        let tokens = abi.tokenize_array("[]", &ParamType::U16)?;
+        assert_eq!(tokens, Token::Array(vec![]));
 
-            assert_eq!(
-                "Invalid data: tuple value string has excess quotes",
-                error_message
-            );
-        }
         Ok(())
     }
 
     #[test]
-    fn tokenize_tuple_excess_value_elements_expected_error() -> Result<(), Error> {
+    #[allow(deprecated)]
+    fn tokenize_struct_and_tuple() -> Result<(), Error> {
         let abi = ABIParser::new();
-        let params = Property {
-            name: "input".to_string(),
-            type_field: "(u64, [u64; 3])".to_string(),
-            components: Some(vec![
-                Property {
-                    name: "__tuple_element".to_string(),
-                    type_field: "u64".to_string(),
-                    components: None,
-                },
-                Property {
-                    name: "__tuple_element".to_string(),
-                    type_field: "[u64; 3]".to_string(),
-                    components: None,
-                },
-            ]),
-        };
+        let params = [ParamType::U8, ParamType::Bool];
 
-        if let ParamType::Tuple(tuple_params) = parse_tuple_param(&params)? {
-            let error_message = abi
-                .tokenize_tuple("(0, [0,0,0], 0, 0)", &tuple_params)
-                .unwrap_err()
-                .to_string();
-
-            assert_eq!(
-                "Invalid data: tuple value contains more elements than the parameter types provided",
-                error_message
-            );
-
-            let error_message = abi
-                .tokenize_tuple("(0, [0,0,0], 0)", &tuple_params)
-                .unwrap_err()
-                .to_string();
-
-            assert_eq!(
-                "Invalid data: tuple value contains more elements than the parameter types provided",
-                error_message
-            );
-        }
+        let expected = vec![Token::U8(1), Token::Bool(true)];
+        assert_eq!(
+            abi.tokenize_struct("(1, true)", &params)?,
+            Token::Struct(expected.clone())
+        );
+        assert_eq!(
+            abi.tokenize_tuple("(1, true)", &params)?,
+            Token::Tuple(expected)
+        );
         Ok(())
     }
 
     #[test]
-    fn tokenize_array_invalid_start_end_bracket_expected_error() {
-        let param = ParamType::U16;
+    fn tokenize_uint_types_expected_error() {
         let abi = ABIParser::new();
 
+        // We test only on U8 as it is the same error on all other unsigned int types
         let error_message = abi
-            .tokenize_array("1,2],[3],4]", &param)
+            .tokenize(&ParamType::U8, "2,".to_string())
             .unwrap_err()
             .to_string();
 
         assert_eq!(
-            "Invalid data: array value string must start and end with square brackets",
+            "Invalid data: unexpected `,` after the value at column 2",
             error_message
         );
     }
 
     #[test]
-    fn tokenize_array_excess_opening_bracket_expected_error() {
-        let param = ParamType::U16;
+    fn tokenize_bool_expected_error() {
         let abi = ABIParser::new();
 
         let error_message = abi
-            .tokenize_array("[[[1,2],[3],4]", &param)
+            .tokenize(&ParamType::Bool, "True".to_string())
             .unwrap_err()
             .to_string();
 
         assert_eq!(
-            "Invalid data: array value string has excess opening brackets",
+            "Invalid data: expected `true` or `false`, found `True` at column 1",
             error_message
         );
     }
 
     #[test]
-    fn tokenize_array_excess_closing_bracket_expected_error() {
-        let param = ParamType::U16;
+    fn tokenize_b256_invalid_length_expected_error() {
         let abi = ABIParser::new();
 
+        let value = "d57a9c46dfcc7f18207013e65b44e4cb4e2c2298f4ac457ba8f82743f31e90b".to_string();
         let error_message = abi
-            .tokenize_array("[[1,2],[3],4]]", &param)
+            .tokenize(&ParamType::B256, value)
             .unwrap_err()
             .to_string();
 
         assert_eq!(
-            "Invalid data: array value string has excess closing brackets",
+            "Invalid data: the hex encoding of the b256 must have 64 characters at column 1",
             error_message
         );
     }
 
     #[test]
-    fn tokenize_array_excess_quotes_expected_error() {
-        let param = ParamType::U16;
+    fn tokenize_b256_invalid_character_expected_error() {
         let abi = ABIParser::new();
 
+        let value = "Hd57a9c46dfcc7f18207013e65b44e4cb4e2c2298f4ac457ba8f82743f31e90b".to_string();
         let error_message = abi
-            .tokenize_array("[[1,\"2],[3],4]]", &param)
+            .tokenize(&ParamType::B256, value)
             .unwrap_err()
             .to_string();
 
-        assert_eq!(
-            "Invalid data: array value string has excess quotes",
-            error_message
-        );
-    }
-
-    #[test]
-    fn tokenize_struct_invalid_start_end_bracket_expected_error() -> Result<(), Error> {
-        let abi = ABIParser::new();
-        let params = Property {
-            name: "input".to_string(),
-            type_field: "struct MyStruct".to_string(),
-            components: Some(vec![
-                Property {
-                    name: "num".to_string(),
-                    type_field: "u64".to_string(),
-                    components: None,
-                },
-                Property {
-                    name: "arr".to_string(),
-                    type_field: "[u64; 3]".to_string(),
-                    components: None,
-                },
-            ]),
-        };
-
-        if let ParamType::Struct(struct_params) = parse_custom_type_param(&params)? {
-            let error_message = abi
-                .tokenize_struct("0, [0,0,0])", &struct_params)
-                .unwrap_err()
-                .to_string();
-
-            assert_eq!(
-                "Invalid data: struct value string must start and end with round brackets",
-                error_message
-            );
-        }
-        Ok(())
-    }
-
-    #[test]
-    fn tokenize_struct_excess_opening_bracket_expected_error() -> Result<(), Error> {
-        let abi = ABIParser::new();
-        let params = Property {
-            name: "input".to_string(),
-            type_field: "struct MyStruct".to_string(),
-            components: Some(vec![
-                Property {
-                    name: "num".to_string(),
-                    type_field: "u64".to_string(),
-                    components: None,
-                },
-                Property {
-                    name: "arr".to_string(),
-                    type_field: "[u64; 3]".to_string(),
-                    components: None,
-                },
-            ]),
-        };
-
-        if let ParamType::Struct(struct_params) = parse_custom_type_param(&params)? {
-            let error_message = abi
-                .tokenize_struct("((0, [0,0,0])", &struct_params)
-                .unwrap_err()
-                .to_string();
-
-            assert_eq!(
-                "Invalid data: struct value string has excess opening brackets",
-                error_message
-            );
-        }
-        Ok(())
+        assert!(error_message.contains("invalid hex encoding of the b256: Invalid character"));
     }
 
     #[test]
-    fn tokenize_struct_excess_closing_bracket_expected_error() -> Result<(), Error> {
-        let abi = ABIParser::new();
-        let params = Property {
-            name: "input".to_string(),
-            type_field: "struct MyStruct".to_string(),
-            components: Some(vec![
-                Property {
-                    name: "num".to_string(),
-                    type_field: "u64".to_string(),
-                    components: None,
-                },
-                Property {
-                    name: "arr".to_string(),
-                    type_field: "[u64; 3]".to_string(),
-                    components: None,
-                },
-            ]),
-        };
+    fn encode_resolves_nested_enum_variants_by_name() -> Result<(), Error> {
+        let json_abi = r#"
+        [
+            {
+                "type":"contract",
+                "inputs":[
+                    {
+                        "name":"input",
+                        "type":"(u64, enum State)",
+                        "components": [
+                            {
+                                "name": "__tuple_element",
+                                "type": "u64"
+                            },
+                            {
+                                "name": "__tuple_element",
+                                "type": "enum State",
+                                "components": [
+                                    {
+                                        "name": "Idle",
+                                        "type": "()"
+                                    },
+                                    {
+                                        "name": "Active",
+                                        "type": "u32"
+                                    }
+                                ]
+                            }
+                        ]
+                    }
+                ],
+                "name":"takes_state",
+                "outputs":[]
+            }
+        ]
+        "#;
 
-        if let ParamType::Struct(struct_params) = parse_custom_type_param(&params)? {
-            let error_message = abi
-                .tokenize_struct("(0, [0,0,0]))", &struct_params)
-                .unwrap_err()
-                .to_string();
+        let mut abi = ABIParser::new();
 
-            assert_eq!(
-                "Invalid data: struct value string has excess closing brackets",
-                error_message
-            );
-        }
+        let by_name = abi.encode(
+            json_abi,
+            "takes_state",
+            &["(0x10, /* the state */ Active(42))".to_string()],
+        )?;
+        let qualified = abi.encode(
+            json_abi,
+            "takes_state",
+            &["(16, State::Active(42))".to_string()],
+        )?;
+        let positional = abi.encode(json_abi, "takes_state", &["(16, (1, 42))".to_string()])?;
+
+        let expected_encode = "00000000000000100000000000000001000000000000002a";
+        assert_eq!(by_name, expected_encode);
+        assert_eq!(qualified, by_name);
+        assert_eq!(positional, by_name);
+
+        let error = abi
+            .encode(json_abi, "takes_state", &["(16, Paused)".to_string()])
+            .expect_err("Should have resulted in an error");
+        assert_eq!(
+            error.to_string(),
+            "Invalid data: unknown enum variant `Paused`, expected one of: Idle, Active at column 6"
+        );
         Ok(())
     }
 
     #[test]
-    fn tokenize_struct_excess_quotes_expected_error() -> Result<(), Error> {
-        let abi = ABIParser::new();
-        let params = Property {
-            name: "input".to_string(),
-            type_field: "struct MyStruct".to_string(),
-            components: Some(vec![
-                Property {
-                    name: "num".to_string(),
-                    type_field: "u64".to_string(),
-                    components: None,
-                },
-                Property {
-                    name: "arr".to_string(),
-                    type_field: "[u64; 3]".to_string(),
-                    components: None,
-                },
-            ]),
-        };
-
-        if let ParamType::Struct(struct_params) = parse_custom_type_param(&params)? {
-            let error_message = abi
-                .tokenize_struct("(0, \"[0,0,0])", &struct_params)
-                .unwrap_err()
-                .to_string();
+    fn encode_json_accepts_positional_and_named_arguments() -> Result<(), Error> {
+        let json_abi = r#"
+        [
+            {
+                "type":"contract",
+                "inputs":[
+                    {
+                        "name":"amount",
+                        "type":"u64"
+                    },
+                    {
+                        "name":"state",
+                        "type":"enum State",
+                        "components": [
+                            {
+                                "name": "Idle",
+                                "type": "()"
+                            },
+                            {
+                                "name": "Active",
+                                "type": "u32"
+                            }
+                        ]
+                    }
+                ],
+                "name":"takes_state",
+                "outputs":[]
+            }
+        ]
+        "#;
 
-            assert_eq!(
-                "Invalid data: struct value string has excess quotes",
-                error_message
-            );
-        }
-        Ok(())
-    }
+        let mut abi = ABIParser::new();
 
-    #[test]
-    fn tokenize_struct_excess_value_elements_expected_error() -> Result<(), Error> {
-        let abi = ABIParser::new();
-        let params = Property {
-            name: "input".to_string(),
-            type_field: "struct MyStruct".to_string(),
-            components: Some(vec![
-                Property {
-                    name: "num".to_string(),
-                    type_field: "u64".to_string(),
-                    components: None,
-                },
-                Property {
-                    name: "arr".to_string(),
-                    type_field: "[u64; 3]".to_string(),
-                    components: None,
-                },
-            ]),
-        };
+        let positional = abi.encode_json(
+            json_abi,
+            "takes_state",
+            &serde_json::json!([16, { "Active": 42 }]),
+        )?;
+        let named = abi.encode_json_with_function_selector(
+            json_abi,
+            "takes_state",
+            &serde_json::json!({ "state": { "Active": 42 }, "amount": "16" }),
+        )?;
+
+        let expected_encode = "00000000000000100000000000000001000000000000002a";
+        assert_eq!(positional, expected_encode);
+        assert_eq!(
+            named,
+            format!("{}{}", abi.get_encoded_function_selector(), expected_encode)
+        );
 
-        if let ParamType::Struct(struct_params) = parse_custom_type_param(&params)? {
-            let error_message = abi
-                .tokenize_struct("(0, [0,0,0], 0, 0)", &struct_params)
-                .unwrap_err()
-                .to_string();
-
-            assert_eq!(
-                "Invalid data: struct value contains more elements than the parameter types provided",
-                error_message
-            );
-
-            let error_message = abi
-                .tokenize_struct("(0, [0,0,0], 0)", &struct_params)
-                .unwrap_err()
-                .to_string();
-
-            assert_eq!(
-                "Invalid data: struct value contains more elements than the parameter types provided",
-                error_message
-            );
+        let errors = [
+            (
+                serde_json::json!([16]),
+                "Invalid data: expected 2 arguments, got 1",
+            ),
+            (
+                serde_json::json!({ "amount": 16 }),
+                "Invalid data: missing argument `state`",
+            ),
+            (
+                serde_json::json!({ "amount": 16, "state": "Idle", "extra": 1 }),
+                "Invalid data: unknown argument `extra`, expected one of: amount, state",
+            ),
+            (
+                serde_json::json!(16),
+                "Invalid data: arguments must be given as a JSON array or object, found `16`",
+            ),
+        ];
+        for (args, expected) in errors {
+            let error = abi
+                .encode_json(json_abi, "takes_state", &args)
+                .expect_err("Should have resulted in an error");
+            assert_eq!(error.to_string(), expected);
         }
         Ok(())
     }
@@ -0,0 +1,512 @@
+use crate::{Bits256, Token};
+use fuels_types::{errors::Error, param_types::ParamType, Property};
+use serde_json::{Map, Number, Value};
+
+impl Token {
+    /// Converts the token into a JSON value shaped after `param`.
+    ///
+    /// Numbers become JSON numbers, `b256` a `0x`-prefixed hex string and
+    /// arrays and tuples JSON arrays. If `property` is given, structs become
+    /// objects keyed by field name and enums objects with a single key naming
+    /// the selected variant, e.g. `{"Active": 42}`. Without it, structs become
+    /// arrays and enum variants are keyed by their discriminant.
+    ///
+    /// # Examples
+    /// ```
+    /// use fuels_core::Token;
+    /// use fuels_types::param_types::ParamType;
+    ///
+    /// let param = ParamType::Tuple(vec![ParamType::U64, ParamType::Bool]);
+    /// let token = Token::Tuple(vec![Token::U64(42), Token::Bool(true)]);
+    ///
+    /// let json = token.to_json(&param, None).unwrap();
+    /// assert_eq!(json.to_string(), "[42,true]");
+    /// assert_eq!(Token::from_json(&json, &param, None).unwrap(), token);
+    /// ```
+    pub fn to_json(&self, param: &ParamType, property: Option<&Property>) -> Result<Value, Error> {
+        to_json(self, param, property, &root_path(property))
+    }
+
+    /// The reverse of `to_json`. Besides what `to_json` produces, it also
+    /// accepts `u64`s given as decimal strings, structs given as arrays
+    /// and unit enum variants given as just their name, e.g. `"Inactive"`.
+    /// Objects with keys that aren't fields of the struct are rejected.
+    pub fn from_json(
+        value: &Value,
+        param: &ParamType,
+        property: Option<&Property>,
+    ) -> Result<Token, Error> {
+        from_json(value, param, property, &root_path(property))
+    }
+}
+
+fn root_path(property: Option<&Property>) -> String {
+    match property {
+        Some(property) if !property.name.is_empty() => property.name.clone(),
+        _ => "value".to_string(),
+    }
+}
+
+fn components(property: Option<&Property>) -> Option<&[Property]> {
+    property.and_then(|p| p.components.as_deref())
+}
+
+fn component_of(property: Option<&Property>, index: usize) -> Option<&Property> {
+    components(property).and_then(|components| components.get(index))
+}
+
+/// Name of the `index`-th component of `property`, if it has a non-empty one.
+fn name_of(property: Option<&Property>, index: usize) -> Option<&str> {
+    component_of(property, index)
+        .map(|component| component.name.as_str())
+        .filter(|name| !name.is_empty())
+}
+
+fn to_json(
+    token: &Token,
+    param: &ParamType,
+    property: Option<&Property>,
+    path: &str,
+) -> Result<Value, Error> {
+    let value = match (param, token) {
+        (ParamType::Unit, Token::Unit) => Value::Null,
+        (ParamType::U8, Token::U8(value)) | (ParamType::Byte, Token::Byte(value)) => {
+            Value::from(*value)
+        }
+        (ParamType::U16, Token::U16(value)) => Value::from(*value),
+        (ParamType::U32, Token::U32(value)) => Value::from(*value),
+        (ParamType::U64, Token::U64(value)) => Value::from(*value),
+        (ParamType::Bool, Token::Bool(value)) => Value::Bool(*value),
+        (ParamType::B256, Token::B256(value)) => Value::String(format!("0x{}", hex::encode(value))),
+        (ParamType::String(_), Token::String(value)) => Value::String(value.clone()),
+        (ParamType::Array(element, _), Token::Array(tokens)) => {
+            // Arrays of custom types hold the element's `Property` as their only component.
+            let element_property = component_of(property, 0);
+            let values = tokens
+                .iter()
+                .enumerate()
+                .map(|(index, token)| {
+                    let path = format!("{}[{}]", path, index);
+                    to_json(token, element, element_property, &path)
+                })
+                .collect::<Result<_, _>>()?;
+            Value::Array(values)
+        }
+        (ParamType::Tuple(types), Token::Tuple(tokens)) => {
+            Value::Array(fields_to_json(types, tokens, property, path)?)
+        }
+        (ParamType::Struct(types), Token::Struct(tokens)) => {
+            let values = fields_to_json(types, tokens, property, path)?;
+            let names: Option<Vec<_>> = (0..types.len()).map(|i| name_of(property, i)).collect();
+            match names {
+                Some(names) => {
+                    Value::Object(names.into_iter().map(str::to_string).zip(values).collect())
+                }
+                None => Value::Array(values),
+            }
+        }
+        (ParamType::Enum(variants), Token::Enum(selector)) => {
+            let (discriminant, token, _) = selector.as_ref();
+            let index = *discriminant as usize;
+            let variant = variants.param_types().get(index).ok_or_else(|| {
+                Error::InvalidData(format!(
+                    "`{}` has discriminant {}, but the enum only has {} variants",
+                    path,
+                    discriminant,
+                    variants.param_types().len()
+                ))
+            })?;
+            let key = name_of(property, index)
+                .map(str::to_string)
+                .unwrap_or_else(|| discriminant.to_string());
+            let path = format!("{}.{}", path, key);
+            let value = to_json(token, variant, component_of(property, index), &path)?;
+
+            let mut object = Map::new();
+            object.insert(key, value);
+            Value::Object(object)
+        }
+        _ => {
+            return Err(Error::InvalidData(format!(
+                "`{}` was expected to be of type {:?}, but the token is {:?}",
+                path, param, token
+            )))
+        }
+    };
+
+    Ok(value)
+}
+
+fn fields_to_json(
+    types: &[ParamType],
+    tokens: &[Token],
+    property: Option<&Property>,
+    path: &str,
+) -> Result<Vec<Value>, Error> {
+    if types.len() != tokens.len() {
+        return Err(Error::InvalidData(format!(
+            "`{}` was expected to have {} fields, but the token has {}",
+            path,
+            types.len(),
+            tokens.len()
+        )));
+    }
+
+    types
+        .iter()
+        .zip(tokens)
+        .enumerate()
+        .map(|(index, (param, token))| {
+            let path = field_path(path, property, index);
+            to_json(token, param, component_of(property, index), &path)
+        })
+        .collect()
+}
+
+fn field_path(path: &str, property: Option<&Property>, index: usize) -> String {
+    match name_of(property, index) {
+        Some(name) => format!("{}.{}", path, name),
+        None => format!("{}.{}", path, index),
+    }
+}
+
+fn from_json(
+    value: &Value,
+    param: &ParamType,
+    property: Option<&Property>,
+    path: &str,
+) -> Result<Token, Error> {
+    let invalid = |expected: &str| {
+        Error::InvalidData(format!(
+            "`{}` was expected to be {}, found `{}`",
+            path, expected, value
+        ))
+    };
+
+    let token = match param {
+        ParamType::Unit => match value {
+            Value::Null => Token::Unit,
+            Value::Array(values) if values.is_empty() => Token::Unit,
+            _ => return Err(invalid("null")),
+        },
+        ParamType::U8 => Token::U8(unsigned(value, path)?),
+        ParamType::Byte => Token::Byte(unsigned(value, path)?),
+        ParamType::U16 => Token::U16(unsigned(value, path)?),
+        ParamType::U32 => Token::U32(unsigned(value, path)?),
+        ParamType::U64 => Token::U64(unsigned(value, path)?),
+        ParamType::Bool => Token::Bool(value.as_bool().ok_or_else(|| invalid("a boolean"))?),
+        ParamType::B256 => {
+            let string = value
+                .as_str()
+                .ok_or_else(|| invalid("a 32 byte hex string"))?;
+            let bytes = hex::decode(string.trim_start_matches("0x"))
+                .map_err(|_| invalid("a 32 byte hex string"))?;
+            let bits: Bits256 = bytes
+                .try_into()
+                .map_err(|_| invalid("a 32 byte hex string"))?;
+            Token::B256(bits)
+        }
+        ParamType::String(length) => {
+            let string = value.as_str().ok_or_else(|| invalid("a string"))?;
+            if string.len() != *length {
+                return Err(invalid(&format!("a string of length {}", length)));
+            }
+            Token::String(string.to_string())
+        }
+        ParamType::Array(element, length) => {
+            let values = value
+                .as_array()
+                .filter(|values| values.len() == *length)
+                .ok_or_else(|| invalid(&format!("an array of {} elements", length)))?;
+            let element_property = component_of(property, 0);
+            let tokens = values
+                .iter()
+                .enumerate()
+                .map(|(index, value)| {
+                    let path = format!("{}[{}]", path, index);
+                    from_json(value, element, element_property, &path)
+                })
+                .collect::<Result<_, _>>()?;
+            Token::Array(tokens)
+        }
+        ParamType::Tuple(types) => {
+            let values = value
+                .as_array()
+                .filter(|values| values.len() == types.len())
+                .ok_or_else(|| invalid(&format!("an array of {} elements", types.len())))?;
+            Token::Tuple(fields_from_json(values, types, property, path)?)
+        }
+        ParamType::Struct(types) => match value {
+            Value::Array(values) if values.len() == types.len() => {
+                Token::Struct(fields_from_json(values, types, property, path)?)
+            }
+            Value::Object(object) => {
+                let names: Vec<&str> = (0..types.len())
+                    .filter_map(|index| name_of(property, index))
+                    .collect();
+                if let Some(unknown) = object.keys().find(|key| !names.contains(&key.as_str())) {
+                    if names.len() == types.len() {
+                        return Err(Error::InvalidData(format!(
+                            "`{}` has no field `{}`, expected: {}",
+                            path,
+                            unknown,
+                            names.join(", ")
+                        )));
+                    }
+                }
+
+                let tokens = types
+                    .iter()
+                    .enumerate()
+                    .map(|(index, param)| {
+                        let name = name_of(property, index).ok_or_else(|| {
+                            invalid("an array, since the struct's field names are unknown")
+                        })?;
+                        let path = format!("{}.{}", path, name);
+                        let value = object
+                            .get(name)
+                            .ok_or_else(|| Error::InvalidData(format!("`{}` is missing", path)))?;
+                        from_json(value, param, component_of(property, index), &path)
+                    })
+                    .collect::<Result<_, _>>()?;
+                Token::Struct(tokens)
+            }
+            _ => {
+                return Err(invalid(&format!(
+                    "an object or an array of {} elements",
+                    types.len()
+                )))
+            }
+        },
+        ParamType::Enum(variants) => {
+            let (key, inner) = match value {
+                Value::Object(object) if object.len() == 1 => {
+                    let (key, inner) = object.iter().next().expect("object has one entry");
+                    (key.as_str(), inner)
+                }
+                Value::String(key) => (key.as_str(), &Value::Null),
+                _ => return Err(invalid("an object with a single variant")),
+            };
+
+            let index = (0..variants.param_types().len())
+                .find(|&index| name_of(property, index) == Some(key))
+                .or_else(|| key.parse().ok())
+                .filter(|&index| index < variants.param_types().len())
+                .ok_or_else(|| {
+                    let valid: Vec<String> = (0..variants.param_types().len())
+                        .map(|index| {
+                            name_of(property, index)
+                                .map(str::to_string)
+                                .unwrap_or_else(|| index.to_string())
+                        })
+                        .collect();
+                    Error::InvalidData(format!(
+                        "`{}` has no variant `{}`, expected one of: {}",
+                        path,
+                        key,
+                        valid.join(", ")
+                    ))
+                })?;
+
+            let path = format!("{}.{}", path, key);
+            let variant = &variants.param_types()[index];
+            let token = from_json(inner, variant, component_of(property, index), &path)?;
+            Token::Enum(Box::new((index as u8, token, variants.clone())))
+        }
+    };
+
+    Ok(token)
+}
+
+fn fields_from_json(
+    values: &[Value],
+    types: &[ParamType],
+    property: Option<&Property>,
+    path: &str,
+) -> Result<Vec<Token>, Error> {
+    values
+        .iter()
+        .zip(types)
+        .enumerate()
+        .map(|(index, (value, param))| {
+            let path = field_path(path, property, index);
+            from_json(value, param, component_of(property, index), &path)
+        })
+        .collect()
+}
+
+/// Reads an unsigned integer given either as a JSON number or as a decimal
+/// string, the latter being needed for `u64`s that don't fit in a JS number.
+fn unsigned<T: TryFrom<u64>>(value: &Value, path: &str) -> Result<T, Error> {
+    let number = match value {
+        Value::Number(number) => number.as_u64(),
+        Value::String(string) => string.parse::<Number>().ok().and_then(|n| n.as_u64()),
+        _ => None,
+    };
+
+    number
+        .and_then(|number| T::try_from(number).ok())
+        .ok_or_else(|| {
+            Error::InvalidData(format!(
+                "`{}` was expected to be an unsigned integer of {} bits, found `{}`",
+                path,
+                std::mem::size_of::<T>() * 8,
+                value
+            ))
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuels_types::param_types::EnumVariants;
+    use serde_json::json;
+
+    fn nested_struct() -> Result<(ParamType, Property), Error> {
+        let property: Property = serde_json::from_str(
+            r#"{
+                "name": "top_value",
+                "type": "struct MyStruct",
+                "components": [
+                    { "name": "x", "type": "u64" },
+                    { "name": "id", "type": "b256" },
+                    {
+                        "name": "kind",
+                        "type": "enum Kind",
+                        "components": [
+                            { "name": "Inactive", "type": "()" },
+                            { "name": "Active", "type": "[u8; 2]" }
+                        ]
+                    }
+                ]
+            }"#,
+        )?;
+        let variants = EnumVariants::new(vec![
+            ParamType::Unit,
+            ParamType::Array(Box::new(ParamType::U8), 2),
+        ])?;
+        let param = ParamType::Struct(vec![
+            ParamType::U64,
+            ParamType::B256,
+            ParamType::Enum(variants),
+        ]);
+
+        Ok((param, property))
+    }
+
+    fn nested_token(param: &ParamType) -> Token {
+        let variants = match param {
+            ParamType::Struct(types) => match &types[2] {
+                ParamType::Enum(variants) => variants.clone(),
+                _ => unreachable!(),
+            },
+            _ => unreachable!(),
+        };
+
+        Token::Struct(vec![
+            Token::U64(u64::MAX),
+            Token::B256([1; 32]),
+            Token::Enum(Box::new((
+                1,
+                Token::Array(vec![Token::U8(1), Token::U8(2)]),
+                variants,
+            ))),
+        ])
+    }
+
+    #[test]
+    fn structs_and_enums_use_property_names() -> Result<(), Error> {
+        let (param, property) = nested_struct()?;
+        let token = nested_token(&param);
+
+        let json = token.to_json(&param, Some(&property))?;
+
+        let expected = json!({
+            "x": u64::MAX,
+            "id": format!("0x{}", "01".repeat(32)),
+            "kind": { "Active": [1, 2] }
+        });
+        assert_eq!(json, expected);
+        assert_eq!(Token::from_json(&json, &param, Some(&property))?, token);
+        Ok(())
+    }
+
+    #[test]
+    fn structs_and_enums_without_property_are_positional() -> Result<(), Error> {
+        let (param, _) = nested_struct()?;
+        let token = nested_token(&param);
+
+        let json = token.to_json(&param, None)?;
+
+        let expected = json!([u64::MAX, format!("0x{}", "01".repeat(32)), { "1": [1, 2] }]);
+        assert_eq!(json, expected);
+        assert_eq!(Token::from_json(&json, &param, None)?, token);
+        Ok(())
+    }
+
+    #[test]
+    fn from_json_accepts_relaxed_forms() -> Result<(), Error> {
+        let (param, property) = nested_struct()?;
+
+        let json = json!({
+            "x": "18446744073709551615",
+            "id": "01".repeat(32),
+            "kind": "Inactive"
+        });
+        let token = Token::from_json(&json, &param, Some(&property))?;
+
+        let expected = json!({
+            "x": u64::MAX,
+            "id": format!("0x{}", "01".repeat(32)),
+            "kind": { "Inactive": null }
+        });
+        assert_eq!(token.to_json(&param, Some(&property))?, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn errors_point_at_the_offending_value() -> Result<(), Error> {
+        let (param, property) = nested_struct()?;
+        let assert_error = |json: Value, expected: &str| {
+            let error = Token::from_json(&json, &param, Some(&property))
+                .expect_err("Should have resulted in an error");
+            assert!(
+                matches!(&error, Error::InvalidData(msg) if msg == expected),
+                "unexpected error: {}",
+                error
+            );
+        };
+
+        assert_error(
+            json!({ "x": 1, "id": "0x00", "kind": "Inactive" }),
+            "`top_value.id` was expected to be a 32 byte hex string, found `\"0x00\"`",
+        );
+        assert_error(
+            json!({ "x": 1, "id": "01".repeat(32), "kind": { "Active": [1, 256] } }),
+            "`top_value.kind.Active[1]` was expected to be an unsigned integer of 8 bits, found `256`",
+        );
+        assert_error(
+            json!({ "x": 1, "id": "01".repeat(32), "kind": "Paused" }),
+            "`top_value.kind` has no variant `Paused`, expected one of: Inactive, Active",
+        );
+        assert_error(
+            json!({ "id": "01".repeat(32), "kind": "Inactive" }),
+            "`top_value.x` is missing",
+        );
+        assert_error(
+            json!({ "x": 1, "y": 2, "id": "01".repeat(32), "kind": "Inactive" }),
+            "`top_value` has no field `y`, expected: x, id, kind",
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn to_json_detects_mismatched_tokens() {
+        let error = Token::U32(1)
+            .to_json(&ParamType::U64, None)
+            .expect_err("Should have resulted in an error");
+
+        let expected = "`value` was expected to be of type U64, but the token is U32(1)";
+        assert!(matches!(error, Error::InvalidData(msg) if msg == expected));
+    }
+}
@@ -14,11 +14,13 @@ pub mod code_gen;
 pub mod constants;
 mod encoding_utils;
 pub mod json_abi;
+mod json_token;
 pub mod parameters;
 pub mod rustfmt;
 pub mod source;
 pub mod types;
 pub mod utils;
+mod value_parser;
 
 pub mod tx {
     #[doc(no_inline)]
@@ -52,9 +54,45 @@ pub enum Token {
     Tuple(Vec<Token>),
 }
 
+/// Formats the token as a Sway-like value: numbers in decimal, `b256`s as `0x`-prefixed hex,
+/// strings quoted, structs and tuples as `(..)`, arrays as `[..]` and enums as the position of
+/// their variant followed by its value, e.g. `Variant1(42)`, or just `Variant0` for units.
 impl fmt::Display for Token {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{:?}", self)
+        fn write_list(f: &mut fmt::Formatter, tokens: &[Token]) -> fmt::Result {
+            for (index, token) in tokens.iter().enumerate() {
+                if index > 0 {
+                    write!(f, ", ")?;
+                }
+                write!(f, "{}", token)?;
+            }
+            Ok(())
+        }
+
+        match self {
+            Token::Unit => write!(f, "()"),
+            Token::U8(value) | Token::Byte(value) => write!(f, "{}", value),
+            Token::U16(value) => write!(f, "{}", value),
+            Token::U32(value) => write!(f, "{}", value),
+            Token::U64(value) => write!(f, "{}", value),
+            Token::Bool(value) => write!(f, "{}", value),
+            Token::B256(value) => write!(f, "0x{}", hex::encode(value)),
+            Token::String(value) => write!(f, "{:?}", value),
+            Token::Array(tokens) => {
+                write!(f, "[")?;
+                write_list(f, tokens)?;
+                write!(f, "]")
+            }
+            Token::Struct(tokens) | Token::Tuple(tokens) => {
+                write!(f, "(")?;
+                write_list(f, tokens)?;
+                write!(f, ")")
+            }
+            Token::Enum(selector) => match selector.as_ref() {
+                (discriminant, Token::Unit, _) => write!(f, "Variant{}", discriminant),
+                (discriminant, token, _) => write!(f, "Variant{}({})", discriminant, token),
+            },
+        }
     }
 }
 
@@ -181,6 +219,77 @@ impl Tokenizable for () {
     }
 }
 
+impl<T> Tokenizable for Option<T>
+where
+    T: Tokenizable + Parameterize,
+{
+    fn from_token(token: Token) -> Result<Self, Error> {
+        if let Token::Enum(enum_selector) = token {
+            match *enum_selector {
+                (0u8, _, _) => Ok(None),
+                (1u8, token, _) => Ok(Option::<T>::Some(T::from_token(token)?)),
+                (_, _, _) => Err(Error::InstantiationError(format!(
+                    "Could not construct Option from enum_selector. Received: {:?}",
+                    enum_selector
+                ))),
+            }
+        } else {
+            Err(Error::InstantiationError(format!(
+                "Could not construct Option from token. Received: {:?}",
+                token
+            )))
+        }
+    }
+    fn into_token(self) -> Token {
+        let (dis, tok) = match self {
+            None => (0u8, Token::Unit),
+            Some(value) => (1u8, value.into_token()),
+        };
+        if let ParamType::Enum(variants) = Self::param_type() {
+            let selector = (dis, tok, variants);
+            Token::Enum(Box::new(selector))
+        } else {
+            panic!("should never happen as Option::param_type() returns valid Enum variants");
+        }
+    }
+}
+
+impl<T, E> Tokenizable for Result<T, E>
+where
+    T: Tokenizable + Parameterize,
+    E: Tokenizable + Parameterize,
+{
+    fn from_token(token: Token) -> Result<Self, Error> {
+        if let Token::Enum(enum_selector) = token {
+            match *enum_selector {
+                (0u8, token, _) => Ok(Result::<T, E>::Ok(T::from_token(token)?)),
+                (1u8, token, _) => Ok(Result::<T, E>::Err(E::from_token(token)?)),
+                (_, _, _) => Err(Error::InstantiationError(format!(
+                    "Could not construct Result from enum_selector. Received: {:?}",
+                    enum_selector
+                ))),
+            }
+        } else {
+            Err(Error::InstantiationError(format!(
+                "Could not construct Result from token. Received: {:?}",
+                token
+            )))
+        }
+    }
+    fn into_token(self) -> Token {
+        let (dis, tok) = match self {
+            Ok(value) => (0u8, value.into_token()),
+            Err(value) => (1u8, value.into_token()),
+        };
+        if let ParamType::Enum(variants) = Self::param_type() {
+            let selector = (dis, tok, variants);
+            Token::Enum(Box::new(selector))
+        } else {
+            panic!("should never happen as Result::param_type() returns valid Enum variants");
+        }
+    }
+}
+
 impl Tokenizable for u8 {
     fn from_token(token: Token) -> Result<Self, Error> {
         match token {
@@ -462,6 +571,35 @@ impl Parameterize for Bits256 {
     }
 }
 
+// Mirrors the Sway std lib `Option<T>`, where `None` is the first variant
+// and `Some(T)` the second.
+impl<T> Parameterize for Option<T>
+where
+    T: Parameterize + Tokenizable,
+{
+    fn param_type() -> ParamType {
+        let param_types = vec![ParamType::Unit, T::param_type()];
+        let variants = EnumVariants::new(param_types)
+            .expect("should never happen as we provided valid Option param types");
+        ParamType::Enum(variants)
+    }
+}
+
+// Mirrors the Sway std lib `Result<T, E>`, where `Ok(T)` is the first variant
+// and `Err(E)` the second.
+impl<T, E> Parameterize for Result<T, E>
+where
+    T: Parameterize + Tokenizable,
+    E: Parameterize + Tokenizable,
+{
+    fn param_type() -> ParamType {
+        let param_types = vec![T::param_type(), E::param_type()];
+        let variants = EnumVariants::new(param_types)
+            .expect("should never happen as we provided valid Result param types");
+        ParamType::Enum(variants)
+    }
+}
+
 /// Converts a u8 to a right aligned array of 8 bytes.
 pub fn pad_u8(value: u8) -> ByteArray {
     let mut padded = ByteArray::default();
@@ -495,9 +633,10 @@ pub fn pad_string(s: &str) -> Vec<u8> {
 
 #[cfg(test)]
 mod tests {
-    use crate::{try_from_bytes, WORD_SIZE};
+    use crate::{try_from_bytes, Token, Tokenizable, WORD_SIZE};
     use fuel_types::{Address, AssetId, ContractId};
     use fuels_types::errors::Error;
+    use fuels_types::param_types::{EnumVariants, ParamType};
 
     #[test]
     fn can_convert_bytes_into_tuple() -> Result<(), Error> {
@@ -523,6 +662,48 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn can_convert_bytes_into_option() -> Result<(), Error> {
+        let none_in_bytes: Vec<u8> = vec![0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+        let some_in_bytes: Vec<u8> = vec![0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 42];
+
+        assert_eq!(try_from_bytes::<Option<u64>>(&none_in_bytes)?, None);
+        assert_eq!(try_from_bytes::<Option<u64>>(&some_in_bytes)?, Some(42));
+
+        Ok(())
+    }
+
+    #[test]
+    fn can_convert_bytes_into_result() -> Result<(), Error> {
+        let ok_in_bytes: Vec<u8> = vec![0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 42];
+        let err_in_bytes: Vec<u8> = vec![0, 0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 1];
+
+        assert_eq!(
+            try_from_bytes::<Result<u64, bool>>(&ok_in_bytes)?,
+            Ok(42u64)
+        );
+        assert_eq!(
+            try_from_bytes::<Result<u64, bool>>(&err_in_bytes)?,
+            Err(true)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn option_and_result_tokens_roundtrip() -> Result<(), Error> {
+        let some: Option<(u32, bool)> = Some((7, true));
+        assert_eq!(Option::from_token(some.into_token())?, some);
+
+        let none: Option<u8> = None;
+        assert_eq!(Option::<u8>::from_token(none.into_token())?, none);
+
+        let err: Result<u64, Option<u8>> = Err(Some(3));
+        assert_eq!(Result::from_token(err.into_token())?, err);
+
+        Ok(())
+    }
+
     #[test]
     fn can_convert_native_types() -> anyhow::Result<()> {
         let bytes = [0xFF; 32];
@@ -541,4 +722,27 @@ mod tests {
         );
         Ok(())
     }
+
+    #[test]
+    fn tokens_are_displayed_as_values() -> Result<(), Error> {
+        let variants = EnumVariants::new(vec![ParamType::Unit, ParamType::U64])?;
+        let token = Token::Struct(vec![
+            Token::U64(42),
+            Token::B256([0xab; 32]),
+            Token::String("a \"b\"".to_string()),
+            Token::Array(vec![Token::U8(1), Token::U8(2)]),
+            Token::Tuple(vec![Token::Bool(true), Token::Unit]),
+            Token::Enum(Box::new((1, Token::U64(7), variants.clone()))),
+            Token::Enum(Box::new((0, Token::Unit, variants))),
+        ]);
+
+        assert_eq!(
+            token.to_string(),
+            format!(
+                "(42, 0x{}, \"a \\\"b\\\"\", [1, 2], (true, ()), Variant1(7), Variant0)",
+                "ab".repeat(32)
+            )
+        );
+        Ok(())
+    }
 }
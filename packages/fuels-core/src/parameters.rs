@@ -1,6 +1,5 @@
 use crate::constants::{
-    BASE_ASSET_ID, DEFAULT_BYTE_PRICE, DEFAULT_FORWARDED_GAS, DEFAULT_GAS_LIMIT, DEFAULT_GAS_PRICE,
-    DEFAULT_MATURITY,
+    BASE_ASSET_ID, DEFAULT_BYTE_PRICE, DEFAULT_GAS_LIMIT, DEFAULT_GAS_PRICE, DEFAULT_MATURITY,
 };
 use fuel_tx::{AssetId, StorageSlot};
 
@@ -16,7 +15,9 @@ pub struct TxParameters {
 pub struct CallParameters {
     pub amount: u64,
     pub asset_id: AssetId,
-    pub gas_forwarded: u64,
+    /// Gas forwarded to the call. When `None`, the call gets all the gas remaining in the
+    /// transaction.
+    pub gas_forwarded: Option<u64>,
 }
 
 impl CallParameters {
@@ -24,7 +25,7 @@ impl CallParameters {
         Self {
             amount: amount.unwrap_or(0),
             asset_id: asset_id.unwrap_or(BASE_ASSET_ID),
-            gas_forwarded: gas_forwarded.unwrap_or(DEFAULT_FORWARDED_GAS),
+            gas_forwarded,
         }
     }
 }
@@ -34,7 +35,7 @@ impl Default for CallParameters {
         Self {
             amount: 0,
             asset_id: BASE_ASSET_ID,
-            gas_forwarded: DEFAULT_FORWARDED_GAS,
+            gas_forwarded: None,
         }
     }
 }
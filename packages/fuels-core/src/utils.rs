@@ -1,15 +1,25 @@
-use proc_macro2::{Ident, Span};
+use fuels_types::errors::Error;
+use proc_macro2::Ident;
+use syn::ext::IdentExt;
+use syn::parse::Parser;
 use syn::Ident as SynIdent;
 
-/// Expands a identifier string into an token.
-pub fn ident(name: &str) -> Ident {
-    Ident::new(name, Span::call_site())
+/// Expands a identifier string into an token, failing if the string isn't
+/// an identifier. Reserved keywords are accepted as they are.
+pub fn ident(name: &str) -> Result<Ident, Error> {
+    SynIdent::parse_any
+        .parse_str(name)
+        .map_err(|_| Error::InvalidType(format!("`{}` is not a valid identifier", name)))
 }
 
 // Expands an identifier string into a token and appending `_` if the
 /// identifier is for a reserved keyword.
 ///
 /// Parsing keywords like `self` can fail, in this case we add an underscore.
-pub fn safe_ident(name: &str) -> Ident {
-    syn::parse_str::<SynIdent>(name).unwrap_or_else(|_| ident(&format!("{}_", name)))
+pub fn safe_ident(name: &str) -> Result<Ident, Error> {
+    match syn::parse_str::<SynIdent>(name) {
+        Ok(ident) => Ok(ident),
+        Err(_) => ident(&format!("{}_", name))
+            .map_err(|_| Error::InvalidType(format!("`{}` is not a valid identifier", name))),
+    }
 }
@@ -0,0 +1,751 @@
+//! Parser for the value literals accepted by [`crate::json_abi::ABIParser`],
+//! e.g. `(1, [2, 3], "str", Active(5))`.
+//!
+//! Parsing is driven by the expected `ParamType`, so the same literal can mean
+//! different things depending on where it appears:
+//!
+//! * integers are decimal (`42`, `1_000`) or hex (`0x2a`),
+//! * `b256`s are 64 hex characters, optionally prefixed by `0x`,
+//! * strings are quoted (`"with, commas"`) or bare (`John`), in which case
+//!   they extend up to the next `,`, `)` or `]`,
+//! * arrays are written `[a, b]`, structs and tuples `(a, b)`,
+//! * enums are written `Variant(value)`, or just `Variant` for unit variants,
+//!   with the variant names taken from the ABI. The variant can be qualified
+//!   by its enum's name, as in `Kind::Active(42)`, and the positional form
+//!   `(discriminant, value)` is accepted as well,
+//! * whitespace, `// line` and `/* block */` comments are ignored between values.
+//!
+//! Errors carry the column (and line, for multi-line input) where parsing failed.
+
+use crate::Token;
+use fuels_types::{errors::Error, param_types::ParamType, Property, ENUM_KEYWORD};
+
+/// Parses `input` as a single value of type `param`. If given, `property`
+/// is used to resolve enum variants by name.
+pub(crate) fn parse_value(
+    input: &str,
+    param: &ParamType,
+    property: Option<&Property>,
+) -> Result<Token, Error> {
+    let mut parser = ValueParser { input, pos: 0 };
+
+    // A bare string is the whole input, leading whitespace and `//` included.
+    if !matches!(param, ParamType::String(_)) {
+        parser.skip_trivia();
+    }
+    let token = parser.value(param, property, 0)?;
+
+    parser.finish(token)
+}
+
+/// Parses `input` as an array of values of type `element`, of any length.
+pub(crate) fn parse_array(input: &str, element: &ParamType) -> Result<Token, Error> {
+    let mut parser = ValueParser { input, pos: 0 };
+
+    parser.skip_trivia();
+    let tokens = parser.list('[', ']', None, |parser, _| parser.value(element, None, 1))?;
+
+    parser.finish(Token::Array(tokens))
+}
+
+struct ValueParser<'a> {
+    input: &'a str,
+    /// Byte offset of the next character to be read.
+    pos: usize,
+}
+
+impl<'a> ValueParser<'a> {
+    /// Checks that nothing but trivia follows the parsed `token`.
+    fn finish(&mut self, token: Token) -> Result<Token, Error> {
+        self.skip_trivia();
+
+        match self.peek() {
+            None => Ok(token),
+            Some(ch) => Err(self.error(format!("unexpected `{}` after the value", ch))),
+        }
+    }
+
+    fn value(
+        &mut self,
+        param: &ParamType,
+        property: Option<&Property>,
+        depth: usize,
+    ) -> Result<Token, Error> {
+        match param {
+            ParamType::Unit => self.unit(),
+            ParamType::U8 => Ok(Token::U8(self.unsigned("u8")?)),
+            ParamType::U16 => Ok(Token::U16(self.unsigned("u16")?)),
+            ParamType::U32 => Ok(Token::U32(self.unsigned("u32")?)),
+            ParamType::U64 => Ok(Token::U64(self.unsigned("u64")?)),
+            ParamType::Byte => Ok(Token::Byte(self.unsigned("byte")?)),
+            ParamType::Bool => self.bool(),
+            ParamType::B256 => self.b256(),
+            ParamType::String(length) => self.string(*length, depth),
+            ParamType::Array(element, length) => {
+                // Arrays of custom types hold the element's `Property` as their only component.
+                let element_property = component_of(property, 0);
+                let tokens = self.list('[', ']', Some(*length), |parser, _| {
+                    parser.value(element, element_property, depth + 1)
+                })?;
+                Ok(Token::Array(tokens))
+            }
+            ParamType::Struct(types) => Ok(Token::Struct(self.fields(types, property, depth)?)),
+            ParamType::Tuple(types) => Ok(Token::Tuple(self.fields(types, property, depth)?)),
+            ParamType::Enum(variants) => {
+                let variants_types = variants.param_types();
+                let start = self.pos;
+
+                let (discriminant, token) = if self.peek() == Some('(') {
+                    self.positional_variant(variants_types, property, depth)?
+                } else {
+                    self.named_variant(variants_types, property, depth)?
+                };
+
+                if discriminant > u8::MAX as usize {
+                    return Err(self.error_at(start, "enum discriminant doesn't fit in a u8"));
+                }
+
+                Ok(Token::Enum(Box::new((
+                    discriminant as u8,
+                    token,
+                    variants.clone(),
+                ))))
+            }
+        }
+    }
+
+    /// The fields of a struct or the elements of a tuple, as `(a, b, ...)`.
+    fn fields(
+        &mut self,
+        types: &[ParamType],
+        property: Option<&Property>,
+        depth: usize,
+    ) -> Result<Vec<Token>, Error> {
+        self.list('(', ')', Some(types.len()), |parser, index| {
+            parser.value(&types[index], component_of(property, index), depth + 1)
+        })
+    }
+
+    /// `(discriminant, value)`
+    fn positional_variant(
+        &mut self,
+        variants: &[ParamType],
+        property: Option<&Property>,
+        depth: usize,
+    ) -> Result<(usize, Token), Error> {
+        self.expect('(')?;
+        self.skip_trivia();
+
+        let start = self.pos;
+        let discriminant: usize = self.unsigned("discriminant")?;
+        let variant = variants.get(discriminant).ok_or_else(|| {
+            self.error_at(
+                start,
+                format!(
+                    "discriminant {} is out of range, the enum has {} variants",
+                    discriminant,
+                    variants.len()
+                ),
+            )
+        })?;
+
+        self.skip_trivia();
+        self.expect(',')?;
+        self.skip_trivia();
+        let token = self.value(variant, component_of(property, discriminant), depth + 1)?;
+        self.skip_trivia();
+        self.expect(')')?;
+
+        Ok((discriminant, token))
+    }
+
+    /// `Variant(value)`, or just `Variant` for unit variants.
+    fn named_variant(
+        &mut self,
+        variants: &[ParamType],
+        property: Option<&Property>,
+        depth: usize,
+    ) -> Result<(usize, Token), Error> {
+        let mut start = self.pos;
+        let mut name = self.word();
+        if name.is_empty() {
+            return Err(self.unexpected("an enum variant"));
+        }
+
+        let components = property
+            .and_then(|p| p.components.as_deref())
+            .unwrap_or_default();
+        if components.is_empty() {
+            return Err(self.error_at(
+                start,
+                "the enum's variant names are unknown, use `(discriminant, value)` instead",
+            ));
+        }
+        if components.len() < variants.len() {
+            return Err(self.error_at(
+                start,
+                format!(
+                    "the ABI names {} of the enum's {} variants, use `(discriminant, value)` instead",
+                    components.len(),
+                    variants.len()
+                ),
+            ));
+        }
+        // Names are looked up by index, so that each one maps to its own discriminant.
+        let names: Vec<&str> = components[..variants.len()]
+            .iter()
+            .map(|component| component.name.as_str())
+            .collect();
+
+        // The variant may be qualified by the name of its enum, i.e. `Kind::Active`.
+        if self.input[self.pos..].starts_with("::") {
+            let enum_name = property.and_then(|p| p.type_field.strip_prefix(ENUM_KEYWORD));
+            if enum_name != Some(name) {
+                return Err(self.error_at(
+                    start,
+                    format!(
+                        "expected a variant of enum `{}`, found `{}::`",
+                        enum_name.unwrap_or_default(),
+                        name
+                    ),
+                ));
+            }
+            self.pos += 2;
+            start = self.pos;
+            name = self.word();
+        }
+
+        let discriminant = names.iter().position(|&n| n == name).ok_or_else(|| {
+            self.error_at(
+                start,
+                format!(
+                    "unknown enum variant `{}`, expected one of: {}",
+                    name,
+                    names.join(", ")
+                ),
+            )
+        })?;
+        let variant = &variants[discriminant];
+        let variant_property = component_of(property, discriminant);
+
+        self.skip_trivia();
+        let token = match (self.peek(), variant) {
+            (Some('('), ParamType::Unit) => {
+                self.unit()?;
+                Token::Unit
+            }
+            (Some('('), _) => {
+                self.expect('(')?;
+                self.skip_trivia();
+                let token = self.value(variant, variant_property, depth + 1)?;
+                self.skip_trivia();
+                self.expect(')')?;
+                token
+            }
+            (_, ParamType::Unit) => Token::Unit,
+            _ => {
+                return Err(self.error_at(
+                    start,
+                    format!("enum variant `{}` must be given a value", name),
+                ))
+            }
+        };
+
+        Ok((discriminant, token))
+    }
+
+    /// Parses `open value, value, ... close`, allowing a trailing comma,
+    /// and checks that exactly `expected` values were given, if any.
+    fn list(
+        &mut self,
+        open: char,
+        close: char,
+        expected: Option<usize>,
+        mut element: impl FnMut(&mut Self, usize) -> Result<Token, Error>,
+    ) -> Result<Vec<Token>, Error> {
+        self.expect(open)?;
+        self.skip_trivia();
+
+        let mut tokens = vec![];
+        while self.peek() != Some(close) {
+            if Some(tokens.len()) == expected {
+                return Err(self.error(format!(
+                    "expected `{}`, only {} elements were expected",
+                    close,
+                    tokens.len()
+                )));
+            }
+            tokens.push(element(self, tokens.len())?);
+            self.skip_trivia();
+
+            match self.peek() {
+                Some(',') => {
+                    self.bump();
+                    self.skip_trivia();
+                }
+                Some(ch) if ch == close => {}
+                _ => return Err(self.unexpected(&format!("`,` or `{}`", close))),
+            }
+        }
+
+        match expected {
+            Some(expected) if tokens.len() != expected => {
+                return Err(self.error(format!(
+                    "expected {} elements, found {}",
+                    expected,
+                    tokens.len()
+                )))
+            }
+            _ => {}
+        }
+        self.expect(close)?;
+
+        Ok(tokens)
+    }
+
+    fn unit(&mut self) -> Result<Token, Error> {
+        if self.peek() == Some('(') {
+            self.bump();
+            self.skip_trivia();
+            self.expect(')')?;
+        } else {
+            // Anything put in place of a unit, e.g. the `0` in `(1, 0)`, is ignored.
+            self.word();
+        }
+
+        Ok(Token::Unit)
+    }
+
+    fn bool(&mut self) -> Result<Token, Error> {
+        let start = self.pos;
+        match self.word() {
+            "true" => Ok(Token::Bool(true)),
+            "false" => Ok(Token::Bool(false)),
+            word => Err(self.error_at(
+                start,
+                format!("expected `true` or `false`, found `{}`", word),
+            )),
+        }
+    }
+
+    fn b256(&mut self) -> Result<Token, Error> {
+        const B256_HEX_ENC_LENGTH: usize = 64;
+
+        let start = self.pos;
+        let word = self.word();
+        let digits = word.strip_prefix("0x").unwrap_or(word);
+
+        if digits.len() != B256_HEX_ENC_LENGTH {
+            return Err(self.error_at(
+                start,
+                format!(
+                    "the hex encoding of the b256 must have {} characters",
+                    B256_HEX_ENC_LENGTH
+                ),
+            ));
+        }
+
+        let mut bytes = [0u8; 32];
+        hex::decode_to_slice(digits, &mut bytes).map_err(|err| {
+            self.error_at(start, format!("invalid hex encoding of the b256: {}", err))
+        })?;
+
+        Ok(Token::B256(bytes))
+    }
+
+    fn string(&mut self, length: usize, depth: usize) -> Result<Token, Error> {
+        let start = self.pos;
+
+        let string = if self.peek() == Some('"') {
+            self.quoted_string()?
+        } else if depth == 0 {
+            // At the top level a bare string is the whole input. It is trimmed only if that's
+            // needed for it to have the expected length.
+            let rest = &self.input[self.pos..];
+            self.pos += rest.len();
+            if rest.len() == length {
+                rest.to_string()
+            } else {
+                rest.trim().to_string()
+            }
+        } else {
+            let rest = &self.input[self.pos..];
+            let end = rest.find([',', ')', ']']).unwrap_or(rest.len());
+            let string = rest[..end].trim_end();
+            self.pos += string.len();
+            string.to_string()
+        };
+
+        if string.len() != length {
+            return Err(self.error_at(
+                start,
+                format!(
+                    "expected a string of length {}, found one of length {}",
+                    length,
+                    string.len()
+                ),
+            ));
+        }
+
+        Ok(Token::String(string))
+    }
+
+    fn quoted_string(&mut self) -> Result<String, Error> {
+        let start = self.pos;
+        self.expect('"')?;
+
+        let mut string = String::new();
+        loop {
+            match self.bump() {
+                Some('"') => return Ok(string),
+                Some('\\') => match self.bump() {
+                    Some(ch @ ('"' | '\\')) => string.push(ch),
+                    Some('n') => string.push('\n'),
+                    Some('t') => string.push('\t'),
+                    _ => {
+                        return Err(self.error_at(self.pos - 1, "invalid escape sequence"));
+                    }
+                },
+                Some(ch) => string.push(ch),
+                None => return Err(self.error_at(start, "unterminated string")),
+            }
+        }
+    }
+
+    /// Parses a decimal or `0x`-prefixed hex integer that must fit in `T`.
+    fn unsigned<T: TryFrom<u64>>(&mut self, type_name: &str) -> Result<T, Error> {
+        let start = self.pos;
+        let word = self.word();
+        if word.is_empty() {
+            return Err(self.unexpected(&format!("a {}", type_name)));
+        }
+
+        let digits = word.replace('_', "");
+        let number = match digits.strip_prefix("0x") {
+            Some(hex) => u64::from_str_radix(hex, 16),
+            None => digits.parse::<u64>(),
+        };
+
+        number
+            .ok()
+            .and_then(|number| T::try_from(number).ok())
+            .ok_or_else(|| {
+                self.error_at(start, format!("expected a {}, found `{}`", type_name, word))
+            })
+    }
+
+    /// Consumes a run of alphanumeric characters and underscores.
+    fn word(&mut self) -> &'a str {
+        let rest = &self.input[self.pos..];
+        let end = rest
+            .find(|ch: char| !(ch.is_ascii_alphanumeric() || ch == '_'))
+            .unwrap_or(rest.len());
+        self.pos += end;
+        &rest[..end]
+    }
+
+    /// Skips whitespace and comments.
+    fn skip_trivia(&mut self) {
+        loop {
+            let rest = &self.input[self.pos..];
+            let trimmed = rest.trim_start();
+            self.pos += rest.len() - trimmed.len();
+
+            if trimmed.starts_with("//") {
+                self.pos += trimmed.find('\n').unwrap_or(trimmed.len());
+            } else if trimmed.starts_with("/*") {
+                // An unterminated comment swallows the rest of the input.
+                self.pos += trimmed
+                    .find("*/")
+                    .map(|end| end + 2)
+                    .unwrap_or(trimmed.len());
+            } else {
+                break;
+            }
+        }
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.input[self.pos..].chars().next()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let ch = self.peek()?;
+        self.pos += ch.len_utf8();
+        Some(ch)
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), Error> {
+        if self.peek() != Some(expected) {
+            return Err(self.unexpected(&format!("`{}`", expected)));
+        }
+        self.bump();
+        Ok(())
+    }
+
+    fn unexpected(&self, expected: &str) -> Error {
+        match self.peek() {
+            Some(ch) => self.error(format!("expected {}, found `{}`", expected, ch)),
+            None => self.error(format!("expected {}, found the end of the input", expected)),
+        }
+    }
+
+    fn error(&self, msg: impl AsRef<str>) -> Error {
+        self.error_at(self.pos, msg)
+    }
+
+    fn error_at(&self, pos: usize, msg: impl AsRef<str>) -> Error {
+        let before = &self.input[..pos];
+        let line = before.matches('\n').count() + 1;
+        let column = before[before.rfind('\n').map(|i| i + 1).unwrap_or(0)..]
+            .chars()
+            .count()
+            + 1;
+
+        let location = if self.input.contains('\n') {
+            format!("line {}, column {}", line, column)
+        } else {
+            format!("column {}", column)
+        };
+
+        Error::InvalidData(format!("{} at {}", msg.as_ref(), location))
+    }
+}
+
+fn component_of(property: Option<&Property>, index: usize) -> Option<&Property> {
+    property
+        .and_then(|p| p.components.as_ref())
+        .and_then(|components| components.get(index))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuels_types::param_types::EnumVariants;
+
+    fn state_enum() -> Result<(ParamType, Property), Error> {
+        let property: Property = serde_json::from_str(
+            r#"{
+                "name": "state",
+                "type": "enum State",
+                "components": [
+                    { "name": "Idle", "type": "()" },
+                    {
+                        "name": "Active",
+                        "type": "struct Job",
+                        "components": [
+                            { "name": "id", "type": "u64" },
+                            { "name": "owner", "type": "str[4]" }
+                        ]
+                    },
+                    { "name": "Done", "type": "[u8; 2]" }
+                ]
+            }"#,
+        )?;
+        let param = ParamType::Enum(EnumVariants::new(vec![
+            ParamType::Unit,
+            ParamType::Struct(vec![ParamType::U64, ParamType::String(4)]),
+            ParamType::Array(Box::new(ParamType::U8), 2),
+        ])?);
+
+        Ok((param, property))
+    }
+
+    fn error_of(result: Result<Token, Error>) -> String {
+        match result.expect_err("Should have resulted in an error") {
+            Error::InvalidData(msg) => msg,
+            other => panic!("unexpected error: {}", other),
+        }
+    }
+
+    #[test]
+    fn parses_nested_values_with_whitespace_and_comments() -> Result<(), Error> {
+        let param = ParamType::Tuple(vec![
+            ParamType::U8,
+            ParamType::Array(Box::new(ParamType::U64), 2),
+            ParamType::String(6),
+            ParamType::Struct(vec![ParamType::Bool, ParamType::Byte]),
+        ]);
+        let input = r#"
+            ( 1, // the first element
+              [0x2a, 1_000,], /* trailing commas are fine */
+              "a, (b)", (true, 255) )
+        "#;
+
+        let token = parse_value(input, &param, None)?;
+
+        let expected = Token::Tuple(vec![
+            Token::U8(1),
+            Token::Array(vec![Token::U64(42), Token::U64(1000)]),
+            Token::String("a, (b)".to_string()),
+            Token::Struct(vec![Token::Bool(true), Token::Byte(255)]),
+        ]);
+        assert_eq!(token, expected);
+        Ok(())
+    }
+
+    #[test]
+    fn parses_enums_by_variant_name() -> Result<(), Error> {
+        let (param, property) = state_enum()?;
+        let variants = match &param {
+            ParamType::Enum(variants) => variants.clone(),
+            _ => unreachable!(),
+        };
+        let parse = |input| parse_value(input, &param, Some(&property));
+
+        assert_eq!(
+            parse("Idle")?,
+            Token::Enum(Box::new((0, Token::Unit, variants.clone())))
+        );
+        assert_eq!(
+            parse("Active((7, John))")?,
+            Token::Enum(Box::new((
+                1,
+                Token::Struct(vec![Token::U64(7), Token::String("John".to_string())]),
+                variants.clone()
+            )))
+        );
+        assert_eq!(parse("State::Done([1, 2])")?, parse("Done([1, 2])")?);
+        // The positional form still works.
+        assert_eq!(parse("(2, [1, 2])")?, parse("Done([1, 2])")?);
+        Ok(())
+    }
+
+    #[test]
+    fn nested_enums_are_parsed_by_name() -> Result<(), Error> {
+        let (state, state_property) = state_enum()?;
+        let property = Property {
+            name: "states".to_string(),
+            type_field: "[enum State; 2]".to_string(),
+            components: Some(vec![state_property]),
+        };
+        let param = ParamType::Array(Box::new(state), 2);
+
+        let token = parse_value("[Idle, Done([3, 4])]", &param, Some(&property))?;
+
+        let discriminants: Vec<u8> = match token {
+            Token::Array(tokens) => tokens
+                .into_iter()
+                .map(|token| match token {
+                    Token::Enum(selector) => selector.0,
+                    _ => panic!("expected an enum"),
+                })
+                .collect(),
+            _ => panic!("expected an array"),
+        };
+        assert_eq!(discriminants, [0, 2]);
+        Ok(())
+    }
+
+    #[test]
+    fn errors_report_column() -> Result<(), Error> {
+        let param = ParamType::Struct(vec![
+            ParamType::U64,
+            ParamType::Array(Box::new(ParamType::U8), 3),
+        ]);
+        let (state, property) = state_enum()?;
+
+        let cases = [
+            (
+                "(0, [0,0,0]",
+                "expected `,` or `)`, found the end of the input at column 12",
+            ),
+            ("((0, [0,0,0])", "expected a u64, found `(` at column 2"),
+            (
+                "(0, [0,0,0]))",
+                "unexpected `)` after the value at column 13",
+            ),
+            (
+                "(0, [0,0,0], 0)",
+                "expected `)`, only 2 elements were expected at column 14",
+            ),
+            ("(0, [0,0])", "expected 3 elements, found 2 at column 9"),
+            ("(0, [0,256,0])", "expected a u8, found `256` at column 8"),
+            ("0, [0,0,0])", "expected `(`, found `0` at column 1"),
+        ];
+        for (input, expected) in cases {
+            assert_eq!(error_of(parse_value(input, &param, None)), expected);
+        }
+
+        assert_eq!(
+            error_of(parse_value("Paused", &state, Some(&property))),
+            "unknown enum variant `Paused`, expected one of: Idle, Active, Done at column 1"
+        );
+        assert_eq!(
+            error_of(parse_value("Kind::Idle", &state, Some(&property))),
+            "expected a variant of enum `State`, found `Kind::` at column 1"
+        );
+        assert_eq!(
+            error_of(parse_value("State::Paused", &state, Some(&property))),
+            "unknown enum variant `Paused`, expected one of: Idle, Active, Done at column 8"
+        );
+        assert_eq!(
+            error_of(parse_value("Idle", &state, None)),
+            "the enum's variant names are unknown, use `(discriminant, value)` instead at column 1"
+        );
+        assert_eq!(
+            error_of(parse_value("Active", &state, Some(&property))),
+            "enum variant `Active` must be given a value at column 1"
+        );
+        assert_eq!(
+            error_of(parse_value("(3, 0)", &state, Some(&property))),
+            "discriminant 3 is out of range, the enum has 3 variants at column 2"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn errors_in_multi_line_input_report_line() {
+        let param = ParamType::Array(Box::new(ParamType::Bool), 2);
+
+        let error = error_of(parse_value("[\n  true,\n  True\n]", &param, None));
+
+        assert_eq!(
+            error,
+            "expected `true` or `false`, found `True` at line 3, column 3"
+        );
+    }
+
+    #[test]
+    fn strings_can_be_quoted_or_bare() -> Result<(), Error> {
+        let param = ParamType::Tuple(vec![ParamType::String(4), ParamType::String(5)]);
+
+        let token = parse_value(r#"(John, "\"Ann\"")"#, &param, None)?;
+
+        let expected = Token::Tuple(vec![
+            Token::String("John".to_string()),
+            Token::String("\"Ann\"".to_string()),
+        ]);
+        assert_eq!(token, expected);
+
+        assert_eq!(
+            error_of(parse_value(r#"(John, "Ann)"#, &param, None)),
+            "unterminated string at column 8"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn top_level_bare_strings_are_the_whole_input() -> Result<(), Error> {
+        let parse = |input, length| parse_value(input, &ParamType::String(length), None);
+
+        assert_eq!(parse("  ab", 4)?, Token::String("  ab".to_string()));
+        assert_eq!(parse("// ab", 5)?, Token::String("// ab".to_string()));
+        assert_eq!(parse("/* a */", 7)?, Token::String("/* a */".to_string()));
+        // Surrounding whitespace is dropped when the string would be too long otherwise.
+        assert_eq!(parse(" ab ", 2)?, Token::String("ab".to_string()));
+        Ok(())
+    }
+
+    #[test]
+    fn variant_names_missing_from_the_abi_are_an_error() -> Result<(), Error> {
+        let (param, mut property) = state_enum()?;
+        property.components.as_mut().unwrap().truncate(2);
+
+        assert_eq!(
+            error_of(parse_value("Active((7, John))", &param, Some(&property))),
+            "the ABI names 2 of the enum's 3 variants, use `(discriminant, value)` instead at column 1"
+        );
+        Ok(())
+    }
+}
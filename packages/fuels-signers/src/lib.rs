@@ -1,4 +1,6 @@
 pub mod provider;
+pub mod remote;
+pub mod utxo_manager;
 pub mod wallet;
 
 #[doc(no_inline)]
@@ -6,19 +8,65 @@ pub use fuel_crypto;
 
 use async_trait::async_trait;
 use fuel_crypto::Signature;
-use fuel_gql_client::{fuel_tx::Transaction, fuel_types::Address};
+use fuel_gql_client::{
+    fuel_tx::{Input, Transaction, UtxoId},
+    fuel_types::{Address, AssetId},
+};
+use provider::Provider;
 use std::error::Error;
+use wallet::WalletError;
 
 /// A wallet instantiated with a locally stored private key
 pub type LocalWallet = wallet::Wallet;
 
+/// An account known by its address, which is enough to look up its coins and read the state
+/// of the blockchain, but not to sign transactions
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+pub trait ViewOnlyAccount: std::fmt::Debug + Send + Sync {
+    /// Returns the account's Fuel Address
+    fn address(&self) -> Address;
+
+    fn get_provider(&self) -> Result<&Provider, WalletError>;
+
+    fn set_provider(&mut self, provider: Provider);
+
+    /// Returns `Input::Coin`s spending coins of the account for the given asset ID and amount,
+    /// to be validated against the witness at `witness_index`.
+    async fn get_asset_inputs_for_amount(
+        &self,
+        asset_id: AssetId,
+        amount: u64,
+        witness_index: u8,
+    ) -> Result<Vec<Input>, WalletError> {
+        let spendable = self
+            .get_provider()?
+            .get_spendable_coins(&self.address(), asset_id, amount)
+            .await?;
+
+        Ok(spendable
+            .into_iter()
+            .map(|coin| {
+                Input::coin_signed(
+                    UtxoId::from(coin.utxo_id),
+                    coin.owner.into(),
+                    coin.amount.0,
+                    asset_id,
+                    witness_index,
+                    0,
+                )
+            })
+            .collect())
+    }
+}
+
 /// Trait for signing transactions and messages
 ///
 /// Implement this trait to support different signing modes, e.g. Ledger, hosted etc.
 #[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
 #[cfg_attr(not(target_arch = "wasm32"), async_trait)]
 pub trait Signer: std::fmt::Debug + Send + Sync {
-    type Error: Error + Send + Sync;
+    type Error: Error + Send + Sync + Into<fuels_types::errors::Error>;
     /// Signs the hash of the provided message
     async fn sign_message<S: Send + Sync + AsRef<[u8]>>(
         &self,
@@ -27,11 +75,14 @@ pub trait Signer: std::fmt::Debug + Send + Sync {
 
     /// Signs the transaction
     async fn sign_transaction(&self, message: &mut Transaction) -> Result<Signature, Self::Error>;
-
-    /// Returns the signer's Fuel Address
-    fn address(&self) -> Address;
 }
 
+/// An account able to sign, and so to pay for, its transactions. It is implemented for every
+/// `ViewOnlyAccount` that is also a `Signer`, such as `LocalWallet` or `RemoteSigner`.
+pub trait Account: ViewOnlyAccount + Signer {}
+
+impl<T: ViewOnlyAccount + Signer> Account for T {}
+
 #[cfg(test)]
 #[cfg(feature = "test-helpers")]
 mod tests {
@@ -0,0 +1,284 @@
+use crate::provider::Provider;
+use crate::wallet::WalletError;
+use crate::{Signer, ViewOnlyAccount};
+use async_trait::async_trait;
+use fuel_crypto::{Message, Signature};
+use fuel_gql_client::{
+    fuel_tx::{Address, Transaction, Witness},
+    fuel_types::bytes::SerializableVec,
+};
+use fuels_types::errors::Error;
+use serde::Deserialize;
+use serde_json::{json, Value};
+use std::str::FromStr;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use thiserror::Error;
+
+/// A signer whose private key is held by a remote service, e.g. a custodial service or a
+/// KMS-backed key, which signs over HTTP JSON-RPC.
+///
+/// The service must implement two methods, whose parameters and results are hex encoded:
+/// - `fuel_signMessage`, taking the address of the key and a message, and returning the
+///   signature of the hash of the message;
+/// - `fuel_signTransaction`, taking the address of the key and a serialized transaction, and
+///   returning the signature of the transaction's id.
+///
+/// The returned signatures are checked against the address of the signer before being used.
+#[derive(Debug, Clone)]
+pub struct RemoteSigner {
+    url: String,
+    address: Address,
+    client: reqwest::Client,
+    provider: Option<Provider>,
+    next_request_id: Arc<AtomicU64>,
+}
+
+#[derive(Error, Debug)]
+/// Error thrown by the `RemoteSigner`
+pub enum RemoteSignerError {
+    #[error("Request to the remote signer failed: {0}")]
+    Request(#[from] reqwest::Error),
+    #[error("Remote signer error {code}: {message}")]
+    Rpc { code: i64, message: String },
+    #[error("Invalid response from the remote signer: {0}")]
+    InvalidResponse(String),
+    #[error("The remote signer didn't sign as {0}")]
+    WrongSigner(Address),
+}
+
+impl From<RemoteSignerError> for Error {
+    fn from(e: RemoteSignerError) -> Self {
+        Error::WalletError(e.to_string())
+    }
+}
+
+#[derive(Deserialize)]
+struct RpcResponse {
+    result: Option<Value>,
+    error: Option<RpcError>,
+}
+
+#[derive(Deserialize)]
+struct RpcError {
+    code: i64,
+    message: String,
+}
+
+impl RemoteSigner {
+    /// Creates a signer for the key of `address`, held by the JSON-RPC service at `url`.
+    pub fn new(url: &str, address: Address, provider: Option<Provider>) -> Self {
+        Self {
+            url: url.to_string(),
+            address,
+            client: reqwest::Client::new(),
+            provider,
+            next_request_id: Default::default(),
+        }
+    }
+
+    async fn request(&self, method: &str, data: &[u8]) -> Result<Signature, RemoteSignerError> {
+        let request = json!({
+            "jsonrpc": "2.0",
+            "id": self.next_request_id.fetch_add(1, Ordering::Relaxed),
+            "method": method,
+            "params": [format!("{:#x}", self.address), format!("0x{}", hex::encode(data))],
+        });
+
+        let response: RpcResponse = self
+            .client
+            .post(&self.url)
+            .json(&request)
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await?;
+
+        match (response.result, response.error) {
+            (_, Some(RpcError { code, message })) => Err(RemoteSignerError::Rpc { code, message }),
+            (Some(Value::String(signature)), None) => {
+                Signature::from_str(signature.trim_start_matches("0x")).map_err(|_| {
+                    RemoteSignerError::InvalidResponse(format!("invalid signature `{}`", signature))
+                })
+            }
+            (result, None) => Err(RemoteSignerError::InvalidResponse(format!(
+                "expected a signature, got {:?}",
+                result
+            ))),
+        }
+    }
+
+    /// Fails unless `signature` is a signature of `message` by the key of the signer's address
+    fn check_signer(
+        &self,
+        signature: &Signature,
+        message: &Message,
+    ) -> Result<(), RemoteSignerError> {
+        match signature.recover(message) {
+            Ok(public_key) if *public_key.hash() == *self.address => Ok(()),
+            _ => Err(RemoteSignerError::WrongSigner(self.address)),
+        }
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl Signer for RemoteSigner {
+    type Error = RemoteSignerError;
+
+    async fn sign_message<S: Send + Sync + AsRef<[u8]>>(
+        &self,
+        message: S,
+    ) -> Result<Signature, Self::Error> {
+        let signature = self.request("fuel_signMessage", message.as_ref()).await?;
+        self.check_signer(&signature, &Message::new(message))?;
+
+        Ok(signature)
+    }
+
+    async fn sign_transaction(&self, tx: &mut Transaction) -> Result<Signature, Self::Error> {
+        let signature = self
+            .request("fuel_signTransaction", &tx.clone().to_bytes())
+            .await?;
+
+        // Safety: the message is the id of the transaction, which is a secure hash. See
+        // `Wallet::sign_transaction`.
+        let message = unsafe { Message::from_bytes_unchecked(*tx.id()) };
+        self.check_signer(&signature, &message)?;
+
+        let mut witnesses: Vec<Witness> = tx.witnesses().to_vec();
+        witnesses.push(Witness::from(signature.as_ref()));
+        tx.set_witnesses(witnesses);
+
+        Ok(signature)
+    }
+}
+
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl ViewOnlyAccount for RemoteSigner {
+    fn address(&self) -> Address {
+        self.address
+    }
+
+    fn get_provider(&self) -> Result<&Provider, WalletError> {
+        self.provider.as_ref().ok_or(WalletError::NoProvider)
+    }
+
+    fn set_provider(&mut self, provider: Provider) {
+        self.provider = Some(provider)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wallet::Wallet;
+    use fuel_crypto::{PublicKey, SecretKey};
+    use fuel_gql_client::fuel_types::bytes::Deserializable;
+    use hyper::service::{make_service_fn, service_fn};
+    use hyper::{Body, Request, Response, Server};
+    use std::convert::Infallible;
+
+    /// Serves the JSON-RPC methods of a remote signer signing with `secret` the requests made
+    /// for `address`, returning the url of the server
+    fn mock_signer(secret: SecretKey, address: Address) -> String {
+        let make_service = make_service_fn(move |_| async move {
+            Ok::<_, Infallible>(service_fn(move |request: Request<Body>| async move {
+                let body = hyper::body::to_bytes(request.into_body()).await.unwrap();
+                let request: Value = serde_json::from_slice(&body).unwrap();
+                let data = hex::decode(&request["params"][1].as_str().unwrap()[2..]).unwrap();
+
+                let signature = match request["method"].as_str().unwrap() {
+                    _ if request["params"][0] != format!("{:#x}", address) => {
+                        Err((-32000, "unknown key"))
+                    }
+                    "fuel_signMessage" => Ok(Signature::sign(&secret, &Message::new(data))),
+                    "fuel_signTransaction" => {
+                        let tx = Transaction::from_bytes(&data).unwrap();
+                        let message = unsafe { Message::from_bytes_unchecked(*tx.id()) };
+                        Ok(Signature::sign(&secret, &message))
+                    }
+                    _ => Err((-32601, "method not found")),
+                };
+
+                let response = match signature {
+                    Ok(signature) => json!({
+                        "jsonrpc": "2.0",
+                        "id": request["id"],
+                        "result": format!("0x{}", hex::encode(signature)),
+                    }),
+                    Err((code, message)) => json!({
+                        "jsonrpc": "2.0",
+                        "id": request["id"],
+                        "error": { "code": code, "message": message },
+                    }),
+                };
+                Ok::<_, Infallible>(Response::new(Body::from(response.to_string())))
+            }))
+        });
+
+        let server = Server::bind(&([127, 0, 0, 1], 0).into()).serve(make_service);
+        let url = format!("http://{}", server.local_addr());
+        tokio::spawn(server);
+
+        url
+    }
+
+    fn address_of(secret: &SecretKey) -> Address {
+        Address::new(*PublicKey::from(secret).hash())
+    }
+
+    #[tokio::test]
+    async fn signs_like_a_local_wallet() -> Result<(), Error> {
+        let mut rng = rand::thread_rng();
+        let secret = SecretKey::random(&mut rng);
+        let wallet = Wallet::new_from_private_key(secret, None);
+        let url = mock_signer(secret, wallet.address());
+        // ANCHOR: remote_signer
+        let signer = RemoteSigner::new(&url, wallet.address(), None);
+        // ANCHOR_END: remote_signer
+
+        let message = "my message";
+        assert_eq!(
+            signer.sign_message(message).await?,
+            wallet.sign_message(message).await?
+        );
+
+        let mut tx = Transaction::default();
+        let mut expected_tx = tx.clone();
+        let signature = signer.sign_transaction(&mut tx).await?;
+        assert_eq!(signature, wallet.sign_transaction(&mut expected_tx).await?);
+        assert_eq!(tx.witnesses(), expected_tx.witnesses());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn fails_on_rpc_errors_and_signatures_of_other_keys() {
+        let mut rng = rand::thread_rng();
+        let secret = SecretKey::random(&mut rng);
+        let address = address_of(&secret);
+        let other_address = address_of(&SecretKey::random(&mut rng));
+
+        let url = mock_signer(secret, address);
+        let error = RemoteSigner::new(&url, other_address, None)
+            .sign_message("my message")
+            .await
+            .expect_err("should error");
+        assert_eq!(error.to_string(), "Remote signer error -32000: unknown key");
+
+        // The service answers for `other_address`, but signs with the key of `address`
+        let url = mock_signer(secret, other_address);
+        let mut tx = Transaction::default();
+        let error = RemoteSigner::new(&url, other_address, None)
+            .sign_transaction(&mut tx)
+            .await
+            .expect_err("should error");
+        assert_eq!(
+            error.to_string(),
+            format!("The remote signer didn't sign as {}", other_address)
+        );
+        assert!(tx.witnesses().is_empty());
+    }
+}
@@ -0,0 +1,457 @@
+use crate::provider::ProviderError;
+use crate::wallet::WalletError;
+use crate::{Account, LocalWallet};
+use fuel_gql_client::{
+    client::schema::coin::Coin,
+    fuel_tx::{Address, AssetId, Bytes32, Input, Output, Receipt, Transaction, UtxoId},
+};
+use fuels_core::parameters::TxParameters;
+use fuels_types::errors::Error;
+use std::collections::{HashMap, HashSet};
+use std::io;
+use std::sync::Arc;
+use tokio::sync::{Mutex, Notify};
+
+/// The message of the error the node returns when the coins of a wallet don't cover a query.
+const NOT_ENOUGH_COINS: &str = "enough coins could not be found";
+
+/// Hands out the coins of an account to transactions built concurrently, so that no two of them
+/// spend the same coin.
+///
+/// Querying the node for spendable coins returns the same coins until a transaction spending
+/// them is committed, so transactions built in parallel from the same wallet would conflict.
+/// The `UtxoManager` keeps track of the coins reserved for transactions being built and of the
+/// coins spent by submitted transactions, and never hands them out twice. When the coins that
+/// aren't in use don't cover a reservation, it waits for the transactions in flight to settle,
+/// so that a wallet holding a single coin can still send concurrent transfers, each one spending
+/// the change of the previous one once it is committed. Clones of a `UtxoManager` share their state, and can be moved
+/// to different tasks:
+///
+/// ```no_run
+/// # use fuels::prelude::*;
+/// # use fuels::signers::utxo_manager::UtxoManager;
+/// # async fn foo(wallet: LocalWallet, to: Address) -> Result<(), Error> {
+/// let manager = UtxoManager::new(wallet)?;
+///
+/// let transfers = (0..10).map(|_| {
+///     let manager = manager.clone();
+///     tokio::spawn(async move {
+///         manager
+///             .transfer(&to, 1, BASE_ASSET_ID, TxParameters::default())
+///             .await
+///     })
+/// });
+/// for transfer in transfers.collect::<Vec<_>>() {
+///     transfer.await.unwrap()?;
+/// }
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct UtxoManager<A = LocalWallet> {
+    wallet: A,
+    state: Arc<Mutex<UtxoState>>,
+
+    /// Notified when coins are released or a pending transaction settles.
+    settled: Arc<Notify>,
+}
+
+#[derive(Debug, Default)]
+struct UtxoState {
+    /// Coins handed out to transactions that aren't submitted yet.
+    reserved: HashSet<UtxoId>,
+
+    /// Coins spent by submitted transactions, by transaction id.
+    pending: HashMap<Bytes32, Vec<UtxoId>>,
+
+    /// Change outputs of submitted transactions, by transaction id.
+    change: HashMap<Bytes32, Vec<(UtxoId, AssetId)>>,
+}
+
+impl UtxoState {
+    fn used_coins(&self) -> impl Iterator<Item = &UtxoId> {
+        self.reserved.iter().chain(self.pending.values().flatten())
+    }
+
+    fn in_flight(&self) -> bool {
+        !self.reserved.is_empty() || !self.pending.is_empty()
+    }
+}
+
+impl<A: Account> UtxoManager<A> {
+    /// Creates a manager for the coins of `wallet`, such as a `LocalWallet` or a
+    /// `RemoteSigner`, which must have a provider.
+    pub fn new(wallet: A) -> Result<Self, WalletError> {
+        wallet.get_provider()?;
+
+        Ok(Self {
+            wallet,
+            state: Default::default(),
+            settled: Default::default(),
+        })
+    }
+
+    pub fn wallet(&self) -> &A {
+        &self.wallet
+    }
+
+    /// Reserves spendable coins of asset `asset_id` adding up at least to `amount`. The coins
+    /// aren't handed out again until they are released, or the transaction spending them
+    /// fails.
+    ///
+    /// If the coins that aren't in use don't add up to `amount`, waits for coins to be released
+    /// and for pending transactions to settle, and fails only once no coin is in use. The change
+    /// of a pending transaction isn't handed out before the transaction settles. A task
+    /// holding reserved coins must therefore not make a reservation the wallet can't cover, as
+    /// it would wait for its own coins.
+    pub async fn reserve_coins(
+        &self,
+        asset_id: AssetId,
+        amount: u64,
+    ) -> Result<Vec<Coin>, ProviderError> {
+        loop {
+            // The state stays locked while querying the node, so that concurrent reservations
+            // exclude each other's coins
+            let mut state = self.state.lock().await;
+
+            let excluded: Vec<String> = state
+                .used_coins()
+                .map(|utxo_id| format!("{:#x}", utxo_id))
+                .collect();
+
+            let coins = self
+                .wallet
+                .get_provider()?
+                .client
+                .coins_to_spend(
+                    &self.wallet.address().to_string(),
+                    vec![(format!("{:#x}", asset_id).as_str(), amount)],
+                    None,
+                    Some(excluded.iter().map(String::as_str).collect()),
+                )
+                .await;
+
+            match coins {
+                Ok(coins) => {
+                    state
+                        .reserved
+                        .extend(coins.iter().map(|coin| UtxoId::from(coin.utxo_id.clone())));
+                    return Ok(coins);
+                }
+                // The coins in use come back once released, or as the change of the
+                // transaction spending them
+                Err(e) if Self::is_not_enough_coins(&e) && state.in_flight() => {
+                    let settled = self.settled.notified();
+                    drop(state);
+                    settled.await;
+                }
+                Err(e) => return Err(e.into()),
+            }
+        }
+    }
+
+    /// Reserves spendable coins like `reserve_coins`, returning them as inputs signed by the
+    /// witness at `witness_index`.
+    pub async fn reserve_inputs(
+        &self,
+        asset_id: AssetId,
+        amount: u64,
+        witness_index: u8,
+    ) -> Result<Vec<Input>, ProviderError> {
+        let coins = self.reserve_coins(asset_id, amount).await?;
+
+        Ok(coins
+            .into_iter()
+            .map(|coin| {
+                Input::coin_signed(
+                    UtxoId::from(coin.utxo_id),
+                    coin.owner.into(),
+                    coin.amount.0,
+                    asset_id,
+                    witness_index,
+                    0,
+                )
+            })
+            .collect())
+    }
+
+    /// Releases reserved coins, e.g. when the transaction they were reserved for is dropped.
+    pub async fn release(&self, utxo_ids: &[UtxoId]) {
+        let mut state = self.state.lock().await;
+        for utxo_id in utxo_ids {
+            state.reserved.remove(utxo_id);
+        }
+        self.settled.notify_waiters();
+    }
+
+    /// Releases the coins spent by `tx` that were reserved and not submitted.
+    pub async fn release_inputs(&self, tx: &Transaction) {
+        self.release(&Self::coin_inputs(tx)).await
+    }
+
+    /// Marks the coins spent by `tx` as pending until the transaction settles, and records its
+    /// change outputs. Returns the id of the transaction.
+    pub async fn mark_pending(&self, tx: &Transaction) -> Bytes32 {
+        let tx_id = tx.id();
+        let inputs = Self::coin_inputs(tx);
+        let change = tx
+            .outputs()
+            .iter()
+            .enumerate()
+            .filter_map(|(index, output)| match output {
+                Output::Change { to, asset_id, .. } if *to == self.wallet.address() => {
+                    Some((UtxoId::new(tx_id, index as u8), *asset_id))
+                }
+                _ => None,
+            })
+            .collect();
+
+        let mut state = self.state.lock().await;
+        for utxo_id in &inputs {
+            state.reserved.remove(utxo_id);
+        }
+        state.pending.insert(tx_id, inputs);
+        state.change.insert(tx_id, change);
+
+        tx_id
+    }
+
+    /// Forgets a pending transaction once it is committed or has failed, waking up the
+    /// reservations waiting for coins. If committed, its coins are spent and its change outputs
+    /// are coins known to the node. Otherwise, its coins are spendable again.
+    pub async fn settle(&self, tx_id: &Bytes32) {
+        let mut state = self.state.lock().await;
+        state.pending.remove(tx_id);
+        state.change.remove(tx_id);
+        self.settled.notify_waiters();
+    }
+
+    /// Returns the id and asset of the change outputs the pending transaction `tx_id` creates
+    /// for the wallet. They only become spendable, and are only handed out by `reserve_coins`,
+    /// once the transaction is committed.
+    pub async fn predicted_change(&self, tx_id: &Bytes32) -> Vec<(UtxoId, AssetId)> {
+        let state = self.state.lock().await;
+        state.change.get(tx_id).cloned().unwrap_or_default()
+    }
+
+    /// Returns whether `utxo_id` is reserved or spent by a pending transaction.
+    pub async fn is_used(&self, utxo_id: &UtxoId) -> bool {
+        self.state
+            .lock()
+            .await
+            .used_coins()
+            .any(|used| used == utxo_id)
+    }
+
+    /// Submits a transaction spending coins reserved by this manager. The coins are pending
+    /// while the transaction is submitted, and released if it can't be.
+    pub async fn send_transaction(&self, tx: &Transaction) -> Result<Vec<Receipt>, ProviderError> {
+        let provider = self.wallet.get_provider()?;
+
+        let tx_id = self.mark_pending(tx).await;
+        let receipts = provider.send_transaction(tx).await;
+        self.settle(&tx_id).await;
+
+        Ok(receipts?)
+    }
+
+    /// Transfers funds to another `Address` like `Wallet::transfer`, spending coins reserved by
+    /// this manager so that transfers can be sent concurrently.
+    pub async fn transfer(
+        &self,
+        to: &Address,
+        amount: u64,
+        asset_id: AssetId,
+        tx_parameters: TxParameters,
+    ) -> Result<(String, Vec<Receipt>), Error> {
+        let inputs = self
+            .reserve_inputs(asset_id, amount, 0)
+            .await
+            .map_err(WalletError::from)?;
+        let outputs = vec![
+            Output::coin(*to, amount, asset_id),
+            Output::change(self.wallet.address(), 0, asset_id),
+        ];
+
+        let mut tx =
+            self.wallet
+                .get_provider()?
+                .build_transfer_tx(&inputs, &outputs, tx_parameters);
+        if let Err(e) = self.wallet.sign_transaction(&mut tx).await {
+            self.release_inputs(&tx).await;
+            return Err(e.into());
+        }
+
+        let receipts = self
+            .send_transaction(&tx)
+            .await
+            .map_err(WalletError::from)?;
+
+        Ok((tx.id().to_string(), receipts))
+    }
+
+    fn is_not_enough_coins(error: &io::Error) -> bool {
+        error.kind() == io::ErrorKind::Other && error.to_string().contains(NOT_ENOUGH_COINS)
+    }
+
+    fn coin_inputs(tx: &Transaction) -> Vec<UtxoId> {
+        tx.inputs()
+            .iter()
+            .filter_map(|input| match input {
+                Input::CoinSigned { utxo_id, .. } | Input::CoinPredicate { utxo_id, .. } => {
+                    Some(*utxo_id)
+                }
+                _ => None,
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "test-helpers")]
+mod tests {
+    use super::*;
+    use crate::provider::Provider;
+    use crate::wallet::Wallet;
+    use fuel_gql_client::client::FuelClient;
+    use fuels_core::constants::BASE_ASSET_ID;
+    use fuels_test_helpers::{setup_single_asset_coins, setup_test_client};
+
+    async fn setup_manager(num_coins: u64, amount_per_coin: u64) -> UtxoManager {
+        let mut wallet = Wallet::new_random(None);
+        let coins =
+            setup_single_asset_coins(wallet.address(), BASE_ASSET_ID, num_coins, amount_per_coin);
+        let (client, _) = setup_test_client(coins, None).await;
+        wallet.set_provider(Provider::new(client));
+
+        UtxoManager::new(wallet).unwrap()
+    }
+
+    #[tokio::test]
+    async fn concurrent_transfers_spend_different_coins() -> Result<(), Error> {
+        let manager = setup_manager(20, 100).await;
+        let recipient = Wallet::new_random(None).address();
+
+        // ANCHOR: concurrent_transfers
+        let transfers: Vec<_> = (0..20)
+            .map(|_| {
+                let manager = manager.clone();
+                tokio::spawn(async move {
+                    manager
+                        .transfer(&recipient, 1, BASE_ASSET_ID, TxParameters::default())
+                        .await
+                })
+            })
+            .collect();
+        for transfer in transfers {
+            transfer.await.unwrap()?;
+        }
+        // ANCHOR_END: concurrent_transfers
+
+        let balance = manager
+            .wallet()
+            .get_provider()?
+            .get_asset_balance(&recipient, BASE_ASSET_ID)
+            .await
+            .map_err(WalletError::from)?;
+        assert_eq!(balance, 20);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn concurrent_transfers_spend_the_change_of_a_single_coin() -> Result<(), Error> {
+        let manager = setup_manager(1, 100).await;
+        let recipient = Wallet::new_random(None).address();
+
+        let transfers: Vec<_> = (0..10)
+            .map(|_| {
+                let manager = manager.clone();
+                tokio::spawn(async move {
+                    manager
+                        .transfer(&recipient, 1, BASE_ASSET_ID, TxParameters::default())
+                        .await
+                })
+            })
+            .collect();
+        for transfer in transfers {
+            transfer.await.unwrap()?;
+        }
+
+        let provider = manager.wallet().get_provider()?;
+        let balance_of = |address| provider.get_asset_balance(address, BASE_ASSET_ID);
+        assert_eq!(balance_of(&recipient).await.map_err(WalletError::from)?, 10);
+        assert_eq!(
+            balance_of(&manager.wallet().address())
+                .await
+                .map_err(WalletError::from)?,
+            90
+        );
+
+        // Once no coin is in use, a reservation the wallet can't cover fails
+        assert!(manager.reserve_coins(BASE_ASSET_ID, 91).await.is_err());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn failed_submissions_release_their_coins() -> Result<(), WalletError> {
+        let manager = setup_manager(1, 100).await;
+
+        let inputs = manager.reserve_inputs(BASE_ASSET_ID, 1, 0).await?;
+        // The only coin is reserved, so another reservation waits for it
+        let waiting = tokio::time::timeout(
+            std::time::Duration::from_millis(100),
+            manager.reserve_coins(BASE_ASSET_ID, 1),
+        );
+        assert!(waiting.await.is_err());
+
+        // Without a witness, the transaction is rejected
+        let outputs = vec![Output::change(manager.wallet().address(), 0, BASE_ASSET_ID)];
+        let tx = manager.wallet().get_provider()?.build_transfer_tx(
+            &inputs,
+            &outputs,
+            TxParameters::default(),
+        );
+        let tx_id = manager.mark_pending(&tx).await;
+        assert_eq!(
+            manager.predicted_change(&tx_id).await,
+            vec![(UtxoId::new(tx_id, 0), BASE_ASSET_ID)]
+        );
+        manager.settle(&tx_id).await;
+
+        assert!(manager.send_transaction(&tx).await.is_err());
+        assert!(manager.predicted_change(&tx_id).await.is_empty());
+        assert_eq!(manager.reserve_inputs(BASE_ASSET_ID, 1, 0).await?, inputs);
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn node_errors_are_returned_while_coins_are_in_flight() -> Result<(), WalletError> {
+        // No node listens on this address
+        let client = FuelClient::new("127.0.0.1:1").unwrap();
+        let mut wallet = Wallet::new_random(None);
+        wallet.set_provider(Provider::new(client));
+        let manager = UtxoManager::new(wallet)?;
+
+        let inputs = vec![Input::coin_signed(
+            UtxoId::new(Bytes32::zeroed(), 0),
+            manager.wallet().address(),
+            100,
+            BASE_ASSET_ID,
+            0,
+            0,
+        )];
+        let tx = manager.wallet().get_provider()?.build_transfer_tx(
+            &inputs,
+            &[],
+            TxParameters::default(),
+        );
+        manager.mark_pending(&tx).await;
+
+        let reservation = tokio::time::timeout(
+            std::time::Duration::from_secs(5),
+            manager.reserve_coins(BASE_ASSET_ID, 1),
+        );
+        assert!(reservation.await.expect("should not wait").is_err());
+        Ok(())
+    }
+}
@@ -1,5 +1,5 @@
 use crate::provider::{Provider, ProviderError};
-use crate::Signer;
+use crate::{Signer, ViewOnlyAccount};
 use async_trait::async_trait;
 use coins_bip32::{path::DerivationPath, Bip32Error};
 use coins_bip39::{English, Mnemonic, MnemonicError};
@@ -8,7 +8,7 @@ use eth_keystore::KeystoreError;
 use fuel_crypto::{Message, PublicKey, SecretKey, Signature};
 use fuel_gql_client::{
     client::{schema::coin::Coin, types::TransactionResponse, PaginatedResult, PaginationRequest},
-    fuel_tx::{Address, AssetId, Input, Output, Receipt, Transaction, UtxoId, Witness},
+    fuel_tx::{Address, AssetId, Input, Output, Receipt, Transaction, Witness},
 };
 use fuels_core::parameters::TxParameters;
 use fuels_types::errors::Error;
@@ -119,6 +119,11 @@ impl Wallet {
         }
     }
 
+    /// Returns the wallet's Fuel Address
+    pub fn address(&self) -> Address {
+        self.address
+    }
+
     pub fn get_provider(&self) -> Result<&Provider, WalletError> {
         self.provider.as_ref().ok_or(WalletError::NoProvider)
     }
@@ -311,20 +316,7 @@ impl Wallet {
         amount: u64,
         witness_index: u8,
     ) -> Result<Vec<Input>, WalletError> {
-        let spendable = self.get_spendable_coins(&asset_id, amount).await?;
-        let mut inputs = vec![];
-        for coin in spendable {
-            let input_coin = Input::coin_signed(
-                UtxoId::from(coin.utxo_id),
-                coin.owner.into(),
-                coin.amount.0,
-                asset_id,
-                witness_index,
-                0,
-            );
-            inputs.push(input_coin);
-        }
-        Ok(inputs)
+        ViewOnlyAccount::get_asset_inputs_for_amount(self, asset_id, amount, witness_index).await
     }
 
     /// Gets all coins owned by the wallet, *even spent ones*. This returns actual coins (UTXOs).
@@ -406,10 +398,22 @@ impl Signer for Wallet {
 
         Ok(sig)
     }
+}
 
+#[cfg_attr(target_arch = "wasm32", async_trait(?Send))]
+#[cfg_attr(not(target_arch = "wasm32"), async_trait)]
+impl ViewOnlyAccount for Wallet {
     fn address(&self) -> Address {
         self.address
     }
+
+    fn get_provider(&self) -> Result<&Provider, WalletError> {
+        Wallet::get_provider(self)
+    }
+
+    fn set_provider(&mut self, provider: Provider) {
+        Wallet::set_provider(self, provider)
+    }
 }
 
 impl fmt::Debug for Wallet {
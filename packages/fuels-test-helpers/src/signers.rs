@@ -10,7 +10,7 @@ use fuel_core_interfaces::model::Coin;
 #[cfg(not(feature = "fuel-core-lib"))]
 use crate::node::Config;
 
-use fuels_signers::{provider::Provider, LocalWallet, Signer};
+use fuels_signers::{provider::Provider, LocalWallet};
 
 use crate::{setup_single_asset_coins, setup_test_client, wallets_config::WalletsConfig};
 
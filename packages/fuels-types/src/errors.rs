@@ -23,6 +23,45 @@ impl From<Utf8Error> for CodecError {
     }
 }
 
+/// A `CodecError` raised while decoding, together with the position of the
+/// element that couldn't be decoded.
+#[derive(Debug)]
+pub struct DecodingError {
+    /// Offset, in bytes from the start of the buffer, of the failing element.
+    pub offset: usize,
+    /// Path of the failing element, e.g. `arg[1].field.inner[3]`.
+    pub path: String,
+    pub error: CodecError,
+}
+
+impl fmt::Display for DecodingError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "failed to decode `{}` at byte offset {}: ",
+            self.path, self.offset
+        )?;
+        match &self.error {
+            CodecError::InvalidData(msg) => write!(f, "{}", msg),
+            CodecError::Utf8Error(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for DecodingError {}
+
+impl From<DecodingError> for CodecError {
+    fn from(err: DecodingError) -> CodecError {
+        match err.error {
+            CodecError::InvalidData(msg) => CodecError::InvalidData(format!(
+                "{} (while decoding `{}` at byte offset {})",
+                msg, err.path, err.offset
+            )),
+            utf8_error => utf8_error,
+        }
+    }
+}
+
 #[derive(Error, Debug)]
 pub enum Error {
     #[error("Invalid name: {0}")]
@@ -70,6 +109,12 @@ impl From<CodecError> for Error {
     }
 }
 
+impl From<DecodingError> for Error {
+    fn from(err: DecodingError) -> Error {
+        Error::InvalidData(err.to_string())
+    }
+}
+
 impl From<ParseError> for Error {
     fn from(err: ParseError) -> Error {
         Error::InvalidType(err.to_string())
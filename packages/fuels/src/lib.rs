@@ -57,14 +57,17 @@ pub mod prelude {
     //! use fuels::prelude::*;
     //! ```
 
-    pub use super::contract::contract::{Contract, MultiContractCallHandler};
+    pub use super::contract::contract::{
+        Contract, MultiContractCallHandler, TypedMultiCallHandler,
+    };
     pub use super::core::constants::*;
     pub use super::core::parameters::*;
     pub use super::core::tx::{Address, AssetId, ContractId};
-    pub use super::core::{Token, Tokenizable};
-    pub use super::fuels_abigen::abigen;
+    pub use super::core::{Parameterize, Token, Tokenizable};
+    pub use super::fuels_abigen::{abigen, Parameterize, Tokenizable};
     pub use super::signers::provider::*;
-    pub use super::signers::{LocalWallet, Signer};
+    pub use super::signers::remote::RemoteSigner;
+    pub use super::signers::{Account, LocalWallet, Signer, ViewOnlyAccount};
     pub use super::test_helpers::Config;
     pub use super::test_helpers::*;
     pub use super::tx::Salt;
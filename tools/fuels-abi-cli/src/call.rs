@@ -0,0 +1,232 @@
+use std::path::Path;
+use std::str::FromStr;
+
+use fuel_gql_client::client::FuelClient;
+use fuels_contract::contract::Contract;
+use fuels_core::abi_encoder::ABIEncoder;
+use fuels_core::json_abi::{parse_param, ABIParser};
+use fuels_core::tx::{ContractId, Receipt};
+use fuels_core::Token;
+use fuels_signers::fuel_crypto::SecretKey;
+use fuels_signers::{provider::Provider, LocalWallet};
+use fuels_types::Function;
+
+use crate::{read_abi, receipts, Call};
+
+/// Environment variable holding the password of the `--keystore` wallet.
+const KEYSTORE_PASSWORD_VAR: &str = "FUEL_KEYSTORE_PASSWORD";
+/// Environment variable holding the private key of the wallet, used when no
+/// keystore is given.
+const PRIVATE_KEY_VAR: &str = "FUEL_PRIVATE_KEY";
+
+/// Calls, or simulates a call to, a contract's function on a node. Returns the
+/// decoded return value followed by the logs emitted during the call.
+pub(crate) fn call(call: Call) -> anyhow::Result<String> {
+    tokio::runtime::Runtime::new()?.block_on(call_contract(call))
+}
+
+async fn call_contract(call: Call) -> anyhow::Result<String> {
+    let abi = read_abi(&call.abi)?;
+    let function = abi
+        .iter()
+        .find(|f| f.name == call.function_name)
+        .ok_or_else(|| anyhow::anyhow!("couldn't find function name: {}", call.function_name))?;
+
+    let args = tokenize_args(function, &call.args, call.json.as_deref())?;
+
+    let parser = ABIParser::new();
+    let signature = parser.build_fn_selector(&function.name, &function.inputs)?;
+    let selector = ABIEncoder::encode_function_selector(&signature);
+
+    let output = function.outputs.first();
+    let output_param = output.map(parse_param).transpose()?;
+
+    let contract_id = ContractId::from_str(&call.contract)
+        .map_err(|err| anyhow::anyhow!("invalid contract id `{}`: {}", call.contract, err))?;
+
+    let provider = connect(&call.node)?;
+
+    // A dry run is only read, so it needs neither a wallet nor coins.
+    let response = if call.dry_run {
+        Contract::method_hash::<Token, LocalWallet>(
+            &provider,
+            contract_id,
+            None,
+            selector,
+            output_param,
+            &args,
+        )?
+        .read()
+        .await?
+    } else {
+        let wallet = load_wallet(call.keystore.as_deref(), provider.clone())?;
+        Contract::method_hash::<Token, _>(
+            &provider,
+            contract_id,
+            Some(&wallet),
+            selector,
+            output_param,
+            &args,
+        )?
+        .call()
+        .await?
+    };
+
+    let mut result = String::new();
+    if let Some(output) = output {
+        let value = crate::output_to_json(&response.value, output)?;
+        result.push_str(&format!("{}\n", value));
+    }
+
+    let logs: Vec<Receipt> = response
+        .receipts
+        .into_iter()
+        .filter(|receipt| matches!(receipt, Receipt::Log { .. } | Receipt::LogData { .. }))
+        .collect();
+    result.push_str(&receipts::decode_receipts(&abi, &logs, Some(&contract_id))?);
+
+    Ok(result)
+}
+
+/// Tokenizes the arguments of `function`, given either in the value syntax
+/// or as a JSON array or object.
+fn tokenize_args(
+    function: &Function,
+    args: &[String],
+    json: Option<&str>,
+) -> anyhow::Result<Vec<Token>> {
+    let parser = ABIParser::new();
+
+    if let Some(json) = json {
+        return Ok(parser.tokenize_json_args(&function.inputs, &serde_json::from_str(json)?)?);
+    }
+
+    if function.inputs.len() != args.len() {
+        anyhow::bail!(
+            "function `{}` expects {} arguments, got {}",
+            function.name,
+            function.inputs.len(),
+            args.len()
+        );
+    }
+
+    function
+        .inputs
+        .iter()
+        .zip(args)
+        .map(|(input, arg)| Ok(parser.tokenize_property(input, arg)?))
+        .collect()
+}
+
+/// Connects to the node, given either as `host:port` or as an `http://` or
+/// `https://` URL such as `https://node.example.com/graphql`.
+fn connect(node: &str) -> anyhow::Result<Provider> {
+    let client = FuelClient::new(node)
+        .map_err(|err| anyhow::anyhow!("invalid node address `{}`: {}", node, err))?;
+
+    Ok(Provider::new(client))
+}
+
+/// Loads the wallet paying for the call, either from an encrypted keystore or
+/// from a private key held in an environment variable.
+fn load_wallet(keystore: Option<&Path>, provider: Provider) -> anyhow::Result<LocalWallet> {
+    match keystore {
+        Some(keystore) => {
+            let password = std::env::var(KEYSTORE_PASSWORD_VAR).map_err(|_| {
+                anyhow::anyhow!(
+                    "the keystore's password must be set in `{}`",
+                    KEYSTORE_PASSWORD_VAR
+                )
+            })?;
+            Ok(LocalWallet::load_keystore(
+                keystore,
+                password,
+                Some(provider),
+            )?)
+        }
+        None => {
+            let private_key = std::env::var(PRIVATE_KEY_VAR).map_err(|_| {
+                anyhow::anyhow!(
+                    "either pass `--keystore` or set the wallet's private key in `{}`",
+                    PRIVATE_KEY_VAR
+                )
+            })?;
+            let secret_key = SecretKey::from_str(&private_key)
+                .map_err(|err| anyhow::anyhow!("invalid private key: {}", err))?;
+            Ok(LocalWallet::new_from_private_key(
+                secret_key,
+                Some(provider),
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn function() -> Function {
+        serde_json::from_str(
+            r#"{
+                "type": "function",
+                "name": "set",
+                "inputs": [
+                    { "name": "id", "type": "u64" },
+                    {
+                        "name": "point",
+                        "type": "struct Point",
+                        "components": [
+                            { "name": "x", "type": "u8" },
+                            { "name": "y", "type": "bool" }
+                        ]
+                    }
+                ],
+                "outputs": []
+            }"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn args_are_tokenized_from_values_or_json() -> anyhow::Result<()> {
+        let function = function();
+        let expected = vec![
+            Token::U64(1),
+            Token::Struct(vec![Token::U8(2), Token::Bool(true)]),
+        ];
+
+        let args = ["1".to_string(), "(2, true)".to_string()];
+        assert_eq!(tokenize_args(&function, &args, None)?, expected);
+
+        let json = r#"{"point": {"x": 2, "y": true}, "id": 1}"#;
+        assert_eq!(tokenize_args(&function, &[], Some(json))?, expected);
+        assert_eq!(
+            tokenize_args(&function, &[], Some("[1, [2, true]]"))?,
+            expected
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn args_must_match_the_inputs() {
+        let error = tokenize_args(&function(), &["1".to_string()], None).unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "function `set` expects 2 arguments, got 1"
+        );
+    }
+
+    #[test]
+    fn nodes_can_be_given_as_addresses_or_urls() {
+        for node in [
+            "127.0.0.1:4000",
+            "http://127.0.0.1:4000",
+            "https://node.example.com/graphql",
+        ] {
+            assert!(connect(node).is_ok(), "{}", node);
+        }
+
+        assert!(connect("http://[::1").is_err());
+    }
+}
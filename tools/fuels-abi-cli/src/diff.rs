@@ -0,0 +1,528 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use fuels_types::{JsonABI, Property};
+use serde_json::json;
+
+use crate::inspect::custom_types_of;
+use crate::selectors::function_selectors;
+
+/// Returned by `diff` when the new ABI breaks clients of the old one,
+/// so that the CLI can exit with a non-zero code after printing `report`.
+#[derive(Debug)]
+pub(crate) struct BreakingChanges {
+    pub report: String,
+}
+
+impl fmt::Display for BreakingChanges {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.report)
+    }
+}
+
+impl std::error::Error for BreakingChanges {}
+
+struct Change {
+    kind: &'static str,
+    breaking: bool,
+    message: String,
+}
+
+impl Change {
+    fn breaking(kind: &'static str, message: String) -> Self {
+        Self {
+            kind,
+            breaking: true,
+            message,
+        }
+    }
+
+    fn compatible(kind: &'static str, message: String) -> Self {
+        Self {
+            kind,
+            breaking: false,
+            message,
+        }
+    }
+}
+
+/// Compares two versions of an ABI and reports, as text or JSON, the changes
+/// that affect its clients. Fails with `BreakingChanges` if any of them
+/// would break clients of the `old` ABI.
+pub(crate) fn diff(old: &JsonABI, new: &JsonABI, as_json: bool) -> anyhow::Result<String> {
+    let mut changes = diff_functions(old, new)?;
+    changes.extend(diff_custom_types(old, new));
+
+    let breaking = changes.iter().any(|change| change.breaking);
+
+    let report = if as_json {
+        let changes: Vec<_> = changes
+            .iter()
+            .map(|change| {
+                json!({
+                    "kind": change.kind,
+                    "breaking": change.breaking,
+                    "message": change.message,
+                })
+            })
+            .collect();
+        serde_json::to_string_pretty(&json!({ "breaking": breaking, "changes": changes }))?
+    } else {
+        let mut report = String::new();
+        for change in &changes {
+            let label = if change.breaking {
+                "breaking"
+            } else {
+                "compatible"
+            };
+            report.push_str(&format!("[{}] {}\n", label, change.message));
+        }
+        let count = changes.iter().filter(|change| change.breaking).count();
+        match count {
+            0 => report.push_str("No breaking changes"),
+            1 => report.push_str("1 breaking change"),
+            _ => report.push_str(&format!("{} breaking changes", count)),
+        }
+        report
+    };
+
+    if breaking {
+        return Err(BreakingChanges { report }.into());
+    }
+
+    Ok(report)
+}
+
+fn diff_functions(old: &JsonABI, new: &JsonABI) -> anyhow::Result<Vec<Change>> {
+    let old_selectors = function_selectors(old)?;
+    let new_selectors = function_selectors(new)?;
+    let new_by_name: HashMap<_, _> = new_selectors
+        .iter()
+        .map(|selector| (selector.function.name.as_str(), selector))
+        .collect();
+
+    let mut changes = vec![];
+    for old_selector in &old_selectors {
+        let name = &old_selector.function.name;
+        let new_selector = match new_by_name.get(name.as_str()) {
+            Some(new_selector) => new_selector,
+            None => {
+                changes.push(Change::breaking(
+                    "function_removed",
+                    format!("function `{}` was removed", name),
+                ));
+                continue;
+            }
+        };
+
+        if old_selector.selector != new_selector.selector {
+            changes.push(Change::breaking(
+                "selector_changed",
+                format!(
+                    "selector of `{}` changed from 0x{} (`{}`) to 0x{} (`{}`)",
+                    name,
+                    hex::encode(old_selector.selector),
+                    old_selector.signature,
+                    hex::encode(new_selector.selector),
+                    new_selector.signature
+                ),
+            ));
+        }
+
+        let old_output = describe_outputs(&old_selector.function.outputs);
+        let new_output = describe_outputs(&new_selector.function.outputs);
+        if old_output != new_output {
+            changes.push(Change::breaking(
+                "output_changed",
+                format!(
+                    "output of `{}` changed from `{}` to `{}`",
+                    name, old_output, new_output
+                ),
+            ));
+        }
+    }
+
+    for new_selector in &new_selectors {
+        let name = &new_selector.function.name;
+        if !old_selectors.iter().any(|s| &s.function.name == name) {
+            changes.push(Change::compatible(
+                "function_added",
+                format!("function `{}` was added", name),
+            ));
+        }
+    }
+
+    Ok(changes)
+}
+
+fn describe_outputs(outputs: &[Property]) -> String {
+    let outputs: Vec<_> = outputs
+        .iter()
+        .map(|output| output.type_field.as_str())
+        .collect();
+    match outputs.as_slice() {
+        [] => "()".to_string(),
+        [output] => output.to_string(),
+        outputs => format!("({})", outputs.join(", ")),
+    }
+}
+
+/// Compares the structs and enums found in both ABIs, by name.
+fn diff_custom_types(old: &JsonABI, new: &JsonABI) -> Vec<Change> {
+    let old_types = custom_types_of(old);
+    let new_types = custom_types_of(new);
+
+    let mut changes = vec![];
+    for old_type in &old_types {
+        let new_type = match new_types
+            .iter()
+            .find(|new_type| new_type.type_field == old_type.type_field)
+        {
+            Some(new_type) => new_type,
+            None => {
+                changes.push(Change::breaking(
+                    "type_removed",
+                    format!("`{}` was removed", old_type.type_field),
+                ));
+                continue;
+            }
+        };
+
+        changes.extend(diff_components(old_type, new_type));
+    }
+
+    for new_type in &new_types {
+        if !old_types
+            .iter()
+            .any(|old_type| old_type.type_field == new_type.type_field)
+        {
+            changes.push(Change::compatible(
+                "type_added",
+                format!("`{}` was added", new_type.type_field),
+            ));
+        }
+    }
+
+    changes
+}
+
+/// Compares the fields, or variants, of two versions of a custom type. Values are encoded by
+/// position, so only changes to the sequence of types, or to which name is at which position,
+/// break clients.
+fn diff_components(old_type: &Property, new_type: &Property) -> Option<Change> {
+    let old_components = components_of(old_type);
+    let new_components = components_of(new_type);
+    if old_components == new_components {
+        return None;
+    }
+
+    let mut old_sorted = old_components.clone();
+    let mut new_sorted = new_components.clone();
+    old_sorted.sort();
+    new_sorted.sort();
+
+    let old_types: Vec<_> = old_components
+        .iter()
+        .map(|(_, type_field)| *type_field)
+        .collect();
+    let new_types: Vec<_> = new_components
+        .iter()
+        .map(|(_, type_field)| *type_field)
+        .collect();
+
+    let change = if old_type.is_enum_type() && old_sorted == new_sorted {
+        let moved: Vec<String> = old_components
+            .iter()
+            .enumerate()
+            .filter_map(|(old_index, variant)| {
+                let new_index = new_components.iter().position(|v| v == variant)?;
+                (old_index != new_index)
+                    .then(|| format!("`{}` {} -> {}", variant.0, old_index, new_index))
+            })
+            .collect();
+        Change::breaking(
+            "variants_reordered",
+            format!(
+                "variants of `{}` were reordered, shifting their discriminants: {}",
+                old_type.type_field,
+                moved.join(", ")
+            ),
+        )
+    } else if old_types == new_types && old_sorted != new_sorted {
+        let renamed: Vec<String> = old_components
+            .iter()
+            .zip(&new_components)
+            .filter(|(old, new)| old.0 != new.0)
+            .map(|(old, new)| format!("`{}` -> `{}`", old.0, new.0))
+            .collect();
+        let what = if old_type.is_enum_type() {
+            "variants"
+        } else {
+            "fields"
+        };
+        Change::compatible(
+            "renamed",
+            format!(
+                "{} of `{}` were renamed, keeping their positions: {}",
+                what,
+                old_type.type_field,
+                renamed.join(", ")
+            ),
+        )
+    } else {
+        Change::breaking(
+            "layout_changed",
+            format!(
+                "layout of `{}` changed from {} to {}",
+                old_type.type_field,
+                describe_components(&old_components),
+                describe_components(&new_components)
+            ),
+        )
+    };
+
+    Some(change)
+}
+
+/// The name and type of each field, or variant, of a custom type.
+fn components_of(property: &Property) -> Vec<(&str, &str)> {
+    property
+        .components
+        .iter()
+        .flatten()
+        .map(|component| (component.name.as_str(), component.type_field.as_str()))
+        .collect()
+}
+
+fn describe_components(components: &[(&str, &str)]) -> String {
+    let components: Vec<_> = components
+        .iter()
+        .map(|(name, type_field)| format!("{}: {}", name, type_field))
+        .collect();
+    format!("{{ {} }}", components.join(", "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn function(name: &str, inputs: &str, output: &str) -> String {
+        format!(
+            r#"{{ "type": "function", "name": "{}", "inputs": [{}], "outputs": [{}] }}"#,
+            name, inputs, output
+        )
+    }
+
+    fn abi(functions: &[String]) -> JsonABI {
+        serde_json::from_str(&format!("[{}]", functions.join(","))).unwrap()
+    }
+
+    fn point(fields: &[(&str, &str)]) -> String {
+        let components: Vec<_> = fields
+            .iter()
+            .map(|(name, type_field)| {
+                format!(r#"{{ "name": "{}", "type": "{}" }}"#, name, type_field)
+            })
+            .collect();
+        format!(
+            r#"{{ "name": "", "type": "struct Point", "components": [{}] }}"#,
+            components.join(",")
+        )
+    }
+
+    fn state(variants: &[&str]) -> String {
+        let components: Vec<_> = variants
+            .iter()
+            .map(|name| format!(r#"{{ "name": "{}", "type": "()" }}"#, name))
+            .collect();
+        format!(
+            r#"{{ "name": "", "type": "enum State", "components": [{}] }}"#,
+            components.join(",")
+        )
+    }
+
+    fn u64_param(name: &str) -> String {
+        format!(r#"{{ "name": "{}", "type": "u64" }}"#, name)
+    }
+
+    /// Returns the report and whether `diff` failed with `BreakingChanges`.
+    fn report(old: &JsonABI, new: &JsonABI) -> (String, bool) {
+        match diff(old, new, false) {
+            Ok(report) => (report, false),
+            Err(err) => (err.downcast::<BreakingChanges>().unwrap().report, true),
+        }
+    }
+
+    #[test]
+    fn identical_abis_have_no_changes() {
+        let old = abi(&[function("get", "", &u64_param(""))]);
+
+        assert_eq!(
+            report(&old, &old),
+            ("No breaking changes".to_string(), false)
+        );
+    }
+
+    #[test]
+    fn added_functions_are_compatible_and_removed_ones_breaking() {
+        let old = abi(&[function("get", "", "")]);
+        let new = abi(&[function("set", "", "")]);
+
+        let (text, breaking) = report(&old, &new);
+
+        assert!(breaking);
+        assert_eq!(
+            text,
+            "[breaking] function `get` was removed\n\
+             [compatible] function `set` was added\n\
+             1 breaking change"
+        );
+        assert_eq!(
+            report(
+                &old,
+                &abi(&[function("get", "", ""), function("set", "", "")])
+            ),
+            (
+                "[compatible] function `set` was added\nNo breaking changes".to_string(),
+                false
+            )
+        );
+    }
+
+    #[test]
+    fn changed_selectors_and_outputs_are_breaking() {
+        let old = abi(&[function("get", &u64_param("id"), &u64_param(""))]);
+        let new = abi(&[function(
+            "get",
+            r#"{ "name": "id", "type": "u32" }"#,
+            r#"{ "name": "", "type": "bool" }"#,
+        )]);
+
+        let (report, breaking) = report(&old, &new);
+
+        assert!(breaking);
+        assert_eq!(
+            report,
+            "[breaking] selector of `get` changed from 0x00000000a809c975 (`get(u64)`) to 0x000000002b3fe53e (`get(u32)`)\n\
+             [breaking] output of `get` changed from `u64` to `bool`\n\
+             2 breaking changes"
+        );
+    }
+
+    #[test]
+    fn changed_layouts_are_breaking() {
+        let old = abi(&[function("get", "", &point(&[("x", "u64"), ("y", "u64")]))]);
+        let new = abi(&[function("get", "", &point(&[("x", "u64"), ("y", "u32")]))]);
+
+        assert_eq!(
+            report(&old, &new),
+            (
+                "[breaking] layout of `struct Point` changed from { x: u64, y: u64 } to { x: u64, y: u32 }\n\
+                 1 breaking change"
+                    .to_string(),
+                true
+            )
+        );
+    }
+
+    #[test]
+    fn swapped_fields_of_the_same_type_are_breaking() {
+        let old = abi(&[function("get", "", &point(&[("x", "u64"), ("y", "u64")]))]);
+        let new = abi(&[function("get", "", &point(&[("y", "u64"), ("x", "u64")]))]);
+
+        let (report, breaking) = report(&old, &new);
+
+        assert!(breaking);
+        assert!(report.starts_with("[breaking] layout of `struct Point` changed"));
+    }
+
+    #[test]
+    fn reordered_variants_are_breaking() {
+        let old = abi(&[function("get", "", &state(&["A", "B", "C"]))]);
+        let new = abi(&[function("get", "", &state(&["B", "A", "C"]))]);
+
+        assert_eq!(
+            report(&old, &new),
+            (
+                "[breaking] variants of `enum State` were reordered, shifting their discriminants: `A` 0 -> 1, `B` 1 -> 0\n\
+                 1 breaking change"
+                    .to_string(),
+                true
+            )
+        );
+    }
+
+    #[test]
+    fn renamed_fields_and_variants_are_compatible() {
+        let old = abi(&[
+            function("get", "", &point(&[("x", "u64"), ("y", "u64")])),
+            function("state", "", &state(&["A", "B"])),
+        ]);
+        let new = abi(&[
+            function("get", "", &point(&[("x", "u64"), ("z", "u64")])),
+            function("state", "", &state(&["A", "Renamed"])),
+        ]);
+
+        assert_eq!(
+            report(&old, &new),
+            (
+                "[compatible] fields of `struct Point` were renamed, keeping their positions: `y` -> `z`\n\
+                 [compatible] variants of `enum State` were renamed, keeping their positions: `B` -> `Renamed`\n\
+                 No breaking changes"
+                    .to_string(),
+                false
+            )
+        );
+    }
+
+    #[test]
+    fn removed_types_are_breaking_and_added_ones_compatible() {
+        let old = abi(&[function("get", "", &point(&[("x", "u64")]))]);
+        let new = abi(&[function("get", "", &state(&["A"]))]);
+
+        assert_eq!(
+            report(&old, &new),
+            (
+                "[breaking] output of `get` changed from `struct Point` to `enum State`\n\
+                 [breaking] `struct Point` was removed\n\
+                 [compatible] `enum State` was added\n\
+                 2 breaking changes"
+                    .to_string(),
+                true
+            )
+        );
+    }
+
+    #[test]
+    fn json_reports_list_every_change() -> anyhow::Result<()> {
+        let old = abi(&[function("get", "", "")]);
+        let new = abi(&[function("set", "", "")]);
+
+        let error = diff(&old, &new, true).unwrap_err();
+        let report: serde_json::Value =
+            serde_json::from_str(&error.downcast::<BreakingChanges>().unwrap().report)?;
+
+        assert_eq!(
+            report,
+            json!({
+                "breaking": true,
+                "changes": [
+                    {
+                        "kind": "function_removed",
+                        "breaking": true,
+                        "message": "function `get` was removed"
+                    },
+                    {
+                        "kind": "function_added",
+                        "breaking": false,
+                        "message": "function `set` was added"
+                    }
+                ]
+            })
+        );
+
+        let report: serde_json::Value = serde_json::from_str(&diff(&old, &old, true)?)?;
+        assert_eq!(report, json!({ "breaking": false, "changes": [] }));
+        Ok(())
+    }
+}
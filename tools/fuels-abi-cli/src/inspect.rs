@@ -0,0 +1,150 @@
+use fuels_types::{JsonABI, Property};
+
+/// Pretty-prints the functions of `abi` along with the structs and enums
+/// they use, each custom type being listed once.
+pub(crate) fn inspect(abi: &JsonABI) -> String {
+    let custom_types = custom_types_of(abi);
+
+    let mut result = String::from("Functions:\n");
+    for function in abi {
+        let inputs: Vec<String> = function
+            .inputs
+            .iter()
+            .map(|input| format!("{}: {}", input.name, input.type_field))
+            .collect();
+        let output = match function.outputs.first() {
+            Some(output) if output.type_field != "()" => format!(" -> {}", output.type_field),
+            _ => String::new(),
+        };
+        result.push_str(&format!(
+            "    fn {}({}){}\n",
+            function.name,
+            inputs.join(", "),
+            output
+        ));
+    }
+
+    for (title, is_kind) in [
+        ("Structs", Property::is_struct_type as fn(&Property) -> bool),
+        ("Enums", Property::is_enum_type),
+    ] {
+        let types: Vec<_> = custom_types.iter().filter(|p| is_kind(p)).collect();
+        if types.is_empty() {
+            continue;
+        }
+
+        result.push_str(&format!("\n{}:\n", title));
+        for custom_type in types {
+            result.push_str(&format!("    {} {{\n", custom_type.type_field));
+            for component in custom_type.components.iter().flatten() {
+                result.push_str(&format!(
+                    "        {}: {},\n",
+                    component.name, component.type_field
+                ));
+            }
+            result.push_str("    }\n");
+        }
+    }
+
+    result
+}
+
+/// The structs and enums used by the functions of `abi`, in order of appearance.
+pub(crate) fn custom_types_of(abi: &JsonABI) -> Vec<&Property> {
+    let mut custom_types = vec![];
+    for function in abi {
+        for property in function.inputs.iter().chain(&function.outputs) {
+            collect_custom_types(property, &mut custom_types);
+        }
+    }
+    custom_types
+}
+
+/// Collects the structs and enums found in `property`, in order of appearance.
+fn collect_custom_types<'a>(property: &'a Property, custom_types: &mut Vec<&'a Property>) {
+    let is_new = !custom_types
+        .iter()
+        .any(|known| known.type_field == property.type_field);
+    if (property.is_struct_type() || property.is_enum_type()) && is_new {
+        custom_types.push(property);
+    }
+
+    for component in property.components.iter().flatten() {
+        collect_custom_types(component, custom_types);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lists_functions_and_each_custom_type_once() -> anyhow::Result<()> {
+        let config = r#"{
+            "name": "config",
+            "type": "struct Config",
+            "components": [
+                { "name": "owner", "type": "b256" },
+                { "name": "limit", "type": "u64" }
+            ]
+        }"#;
+        let abi: JsonABI = serde_json::from_str(&format!(
+            r#"[
+                {{
+                    "type": "function",
+                    "name": "set",
+                    "inputs": [
+                        {config},
+                        {{
+                            "name": "state",
+                            "type": "enum State",
+                            "components": [
+                                {{ "name": "Idle", "type": "()" }},
+                                {busy}
+                            ]
+                        }}
+                    ],
+                    "outputs": [{{ "name": "", "type": "()" }}]
+                }},
+                {{
+                    "type": "function",
+                    "name": "get",
+                    "inputs": [],
+                    "outputs": [{config}]
+                }}
+            ]"#,
+            config = config,
+            busy = config.replace("\"config\"", "\"Busy\"")
+        ))?;
+
+        let expected = "\
+Functions:
+    fn set(config: struct Config, state: enum State)
+    fn get() -> struct Config
+
+Structs:
+    struct Config {
+        owner: b256,
+        limit: u64,
+    }
+
+Enums:
+    enum State {
+        Idle: (),
+        Busy: struct Config,
+    }
+";
+        assert_eq!(inspect(&abi), expected);
+        Ok(())
+    }
+
+    #[test]
+    fn sections_without_types_are_left_out() -> anyhow::Result<()> {
+        let abi: JsonABI = serde_json::from_str(
+            r#"[{ "type": "function", "name": "noop", "inputs": [], "outputs": [] }]"#,
+        )?;
+
+        assert_eq!(inspect(&abi), "Functions:\n    fn noop()\n");
+        Ok(())
+    }
+}
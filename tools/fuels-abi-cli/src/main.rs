@@ -1,10 +1,20 @@
+mod call;
+mod diff;
+mod inspect;
+mod receipts;
+mod selectors;
+
+use fuels_core::abi_encoder::ABIEncoder;
 use fuels_core::code_gen::abigen::Abigen;
 use fuels_core::json_abi::parse_param;
 use fuels_core::json_abi::ABIParser;
-use fuels_types::{param_types::ParamType, Property};
+use fuels_core::tx::ContractId;
+use fuels_core::Token;
+use fuels_types::{errors::Error, param_types::ParamType, JsonABI, Property};
 
 use std::fs;
 use std::path::PathBuf;
+use std::str::FromStr;
 use structopt::StructOpt;
 
 #[derive(StructOpt, Debug)]
@@ -16,6 +26,29 @@ enum Opt {
     Encode(Encode),
     /// Decode ABI call result.
     Decode(Decode),
+    /// Decode the receipts of a transaction.
+    DecodeReceipts(DecodeReceipts),
+    /// List the functions of a JSON ABI with their selectors.
+    Selectors { abi_path: String },
+    /// Find the function of a JSON ABI matching a selector.
+    Lookup {
+        abi_path: String,
+        /// Hex encoded selector, either 8 bytes long or only its 4 significant bytes.
+        selector: String,
+    },
+    /// Pretty-print the functions, structs and enums of a JSON ABI.
+    Inspect { abi_path: String },
+    /// Call a contract's function on a node.
+    Call(Call),
+    /// Report the changes between two versions of a JSON ABI, exiting with
+    /// a non-zero code if the new version breaks clients of the old one.
+    Diff {
+        old_abi_path: String,
+        new_abi_path: String,
+        /// Print the report as JSON.
+        #[structopt(long)]
+        json: bool,
+    },
 }
 
 #[derive(StructOpt, Debug)]
@@ -37,6 +70,10 @@ enum Encode {
         function_name: String,
         #[structopt(short, number_of_values = 1)]
         params: Vec<String>,
+        /// Arguments as JSON, either an array holding them in order or an
+        /// object keyed by parameter name, instead of `-p` params.
+        #[structopt(long, conflicts_with = "params")]
+        json: Option<String>,
     },
     /// Specify types of input params inline.
     Params {
@@ -50,6 +87,9 @@ enum Encode {
             allow_hyphen_values = true
         )]
         params: Vec<String>,
+        /// Params are given as JSON values.
+        #[structopt(long)]
+        json: bool,
     },
 }
 
@@ -60,15 +100,67 @@ enum Decode {
         abi_path: String,
         function_name: String,
         data: String,
+        /// Print the outputs as a single JSON array.
+        #[structopt(long)]
+        json: bool,
     },
     /// Specify types of input params inline.
     Params {
         #[structopt(short, name = "type", number_of_values = 1)]
         types: Vec<String>,
         data: String,
+        /// Print the params as a single JSON array.
+        #[structopt(long)]
+        json: bool,
     },
 }
 
+#[derive(StructOpt, Debug)]
+struct DecodeReceipts {
+    /// JSON ABI of the called contract.
+    abi_path: String,
+    /// JSON file holding the receipts, either serialized from `Vec<Receipt>`
+    /// or as returned by the node's GraphQL API.
+    receipts_path: String,
+    /// Id of the contract the ABI describes. When given, only the calls to
+    /// this contract are resolved and decoded.
+    #[structopt(long)]
+    contract: Option<String>,
+}
+
+#[derive(StructOpt, Debug)]
+struct Call {
+    /// Address of the node, e.g. `127.0.0.1:4000`, `http://localhost:4000` or
+    /// `https://node.example.com/graphql`.
+    #[structopt(long)]
+    node: String,
+    /// Id of the contract to call.
+    #[structopt(long)]
+    contract: String,
+    /// JSON ABI of the contract.
+    #[structopt(long)]
+    abi: String,
+    /// Name of the function to call.
+    #[structopt(long = "fn")]
+    function_name: String,
+    /// Arguments of the function, in the same syntax as `encode function` params.
+    #[structopt(long)]
+    args: Vec<String>,
+    /// Arguments as JSON, either an array holding them in order or an
+    /// object keyed by parameter name, instead of `--args`.
+    #[structopt(long, conflicts_with = "args")]
+    json: Option<String>,
+    /// Encrypted keystore of the wallet paying for the call, whose password is
+    /// read from `FUEL_KEYSTORE_PASSWORD`. Without it, the wallet's private key
+    /// is read from `FUEL_PRIVATE_KEY`.
+    #[structopt(long, parse(from_os_str))]
+    keystore: Option<PathBuf>,
+    /// Read the result of the call without modifying the chain's state. Dry
+    /// runs spend no coins, so they need no wallet.
+    #[structopt(long)]
+    dry_run: bool,
+}
+
 fn execute<I>(args: I) -> anyhow::Result<String>
 where
     I: IntoIterator,
@@ -78,19 +170,43 @@ where
 
     match opt {
         Opt::Codegen(code) => code_gen(code),
+        Opt::Encode(Encode::Function {
+            abi_path,
+            function_name,
+            json: Some(args),
+            ..
+        }) => encode_json_input(&abi_path, &function_name, &args),
         Opt::Encode(Encode::Function {
             abi_path,
             function_name,
             params,
+            json: None,
         }) => encode_input(&abi_path, &function_name, &params),
-        Opt::Encode(Encode::Params { params }) => encode_params(&params),
-        Opt::Decode(Decode::Params { types, data }) => decode_params(&types, &data),
+        Opt::Encode(Encode::Params { params, json }) => encode_params(&params, json),
+        Opt::Decode(Decode::Params { types, data, json }) => decode_params(&types, &data, json),
 
         Opt::Decode(Decode::Function {
             abi_path,
             function_name,
             data,
-        }) => decode_call_output(&abi_path, &function_name, &data),
+            json,
+        }) => decode_call_output(&abi_path, &function_name, &data, json),
+        Opt::DecodeReceipts(DecodeReceipts {
+            abi_path,
+            receipts_path,
+            contract,
+        }) => decode_receipts(&abi_path, &receipts_path, contract.as_deref()),
+        Opt::Selectors { abi_path } => selectors::list_selectors(&read_abi(&abi_path)?),
+        Opt::Lookup { abi_path, selector } => {
+            selectors::lookup_selector(&read_abi(&abi_path)?, &selector)
+        }
+        Opt::Inspect { abi_path } => Ok(inspect::inspect(&read_abi(&abi_path)?)),
+        Opt::Call(call) => call::call(call),
+        Opt::Diff {
+            old_abi_path,
+            new_abi_path,
+            json,
+        } => diff::diff(&read_abi(&old_abi_path)?, &read_abi(&new_abi_path)?, json),
     }
 }
 
@@ -118,10 +234,25 @@ fn code_gen(code: Codegen) -> anyhow::Result<String> {
     Ok("File generated".into())
 }
 
-fn encode_params(params: &[String]) -> anyhow::Result<String> {
+fn encode_params(params: &[String], json: bool) -> anyhow::Result<String> {
     let abi_coder = ABIParser::new();
 
-    Ok(abi_coder.encode_params(params)?)
+    if !json {
+        return Ok(abi_coder.encode_params(params)?);
+    }
+
+    let mut types = vec![];
+    let mut tokens = vec![];
+    for pair in params.chunks(2) {
+        let param = parse_type(&pair[0])?;
+        let value: serde_json::Value = serde_json::from_str(&pair[1])?;
+        tokens.push(Token::from_json(&value, &param, None)?);
+        types.push(param);
+    }
+
+    let encoded = ABIEncoder::encode_with_types(&types, &tokens).map_err(Error::from)?;
+
+    Ok(hex::encode(encoded))
 }
 
 fn encode_input(path: &str, function_name: &str, values: &[String]) -> anyhow::Result<String> {
@@ -138,51 +269,244 @@ fn encode_input(path: &str, function_name: &str, values: &[String]) -> anyhow::R
     Ok(result)
 }
 
-fn decode_params(types: &[String], data: &str) -> anyhow::Result<String> {
+fn encode_json_input(path: &str, function_name: &str, args: &str) -> anyhow::Result<String> {
+    let contract = fs::read_to_string(path)?;
+    let args: serde_json::Value = serde_json::from_str(args)?;
+
+    let mut abi_coder = ABIParser::new();
+
+    Ok(abi_coder.encode_json_with_function_selector(&contract, function_name, &args)?)
+}
+
+fn parse_type(type_field: &str) -> anyhow::Result<ParamType> {
+    Ok(parse_param(&Property {
+        name: "".into(),
+        type_field: type_field.to_owned(),
+        components: None,
+    })?)
+}
+
+fn decode_params(types: &[String], data: &str, json: bool) -> anyhow::Result<String> {
     let abi_coder = ABIParser::new();
 
-    let types: Result<Vec<ParamType>, _> = types
+    let types: Vec<ParamType> = types
         .iter()
-        .map(|s| {
-            parse_param(&Property {
-                name: "".into(),
-                type_field: s.to_owned(),
-                components: None,
-            })
-        })
-        .collect();
+        .map(|s| parse_type(s))
+        .collect::<Result<_, _>>()?;
 
-    let data: Vec<u8> = hex::decode(&data)?;
+    let data: Vec<u8> = hex::decode(data)?;
 
-    let decoded = abi_coder.decode_params(&types.unwrap(), &data)?;
+    let decoded = abi_coder.decode_params(&types, &data)?;
 
-    let mut result: String = String::new();
-    for token in decoded {
-        let format = format!("{}\n", token);
-        result.push_str(&format);
-    }
+    let values = decoded
+        .iter()
+        .zip(&types)
+        .map(|(token, param)| token.to_json(param, None))
+        .collect::<Result<Vec<_>, _>>()?;
 
-    Ok(result)
+    Ok(format_values(values, json))
 }
 
-fn decode_call_output(path: &str, function_name: &str, data: &str) -> anyhow::Result<String> {
+fn decode_call_output(
+    path: &str,
+    function_name: &str,
+    data: &str,
+    json: bool,
+) -> anyhow::Result<String> {
     let contract = fs::read_to_string(path)?;
 
+    let parsed_abi: JsonABI = serde_json::from_str(&contract)?;
+    let outputs = match parsed_abi.iter().find(|f| f.name == function_name) {
+        Some(function) => &function.outputs,
+        None => anyhow::bail!("couldn't find function name: {}", function_name),
+    };
+
     let abi_coder = ABIParser::new();
 
-    let decoded = abi_coder.decode(&contract, function_name, data.as_bytes())?;
+    let data: Vec<u8> = hex::decode(data)?;
+
+    let decoded = abi_coder.decode(&contract, function_name, &data)?;
+
+    let values = decoded
+        .iter()
+        .zip(outputs)
+        .map(|(token, output)| output_to_json(token, output))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(format_values(values, json))
+}
+
+/// Formats decoded values either one per line or, in JSON mode, as a single
+/// JSON array so that the whole output can be parsed at once.
+fn format_values(values: Vec<serde_json::Value>, json: bool) -> String {
+    if json {
+        return serde_json::Value::Array(values).to_string();
+    }
 
     let mut result: String = String::new();
-    for res in decoded {
-        let format = format!("{}\n", res);
-        result.push_str(&format);
+    for value in values {
+        result.push_str(&format!("{}\n", value));
     }
 
-    Ok(result)
+    result
+}
+
+fn read_abi(path: &str) -> anyhow::Result<JsonABI> {
+    Ok(serde_json::from_str(&fs::read_to_string(path)?)?)
+}
+
+fn decode_receipts(
+    abi_path: &str,
+    receipts_path: &str,
+    contract: Option<&str>,
+) -> anyhow::Result<String> {
+    let abi = read_abi(abi_path)?;
+    let receipts = receipts::parse_receipts(&fs::read_to_string(receipts_path)?)?;
+    let contract = contract
+        .map(|contract| {
+            ContractId::from_str(contract)
+                .map_err(|err| anyhow::anyhow!("invalid contract id `{}`: {}", contract, err))
+        })
+        .transpose()?;
+
+    receipts::decode_receipts(&abi, &receipts, contract.as_ref())
+}
+
+/// Formats a decoded output as JSON, naming struct fields and enum variants
+/// after the ABI.
+fn output_to_json(token: &Token, output: &Property) -> anyhow::Result<serde_json::Value> {
+    Ok(token.to_json(&parse_param(output)?, Some(output))?)
 }
 
 fn main() -> anyhow::Result<()> {
-    println!("{}", execute(std::env::args())?);
+    match execute(std::env::args()) {
+        Ok(output) => println!("{}", output),
+        Err(err) => match err.downcast::<diff::BreakingChanges>() {
+            Ok(breaking_changes) => {
+                println!("{}", breaking_changes.report);
+                std::process::exit(1);
+            }
+            Err(err) => return Err(err),
+        },
+    }
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const COMPLEX_ABI: &str = "examples/complex_type.json";
+
+    fn run(args: &[&str]) -> anyhow::Result<String> {
+        execute(std::iter::once("fuels-abi-cli").chain(args.iter().copied()))
+    }
+
+    fn color() -> String {
+        format!("0x{}", "ab".repeat(32))
+    }
+
+    #[test]
+    fn json_arguments_can_be_an_array_or_an_object() -> anyhow::Result<()> {
+        let color = color();
+        let by_position = format!(r#"[10, 20, "{}", {{"a": true, "b": 5}}]"#, color);
+        let by_name = format!(
+            r#"{{"value": {{"b": 5, "a": true}}, "color": "{}", "amount": 20, "gas": 10}}"#,
+            color
+        );
+
+        let expected = run(&[
+            "encode",
+            "function",
+            COMPLEX_ABI,
+            "boo",
+            "-p",
+            "10",
+            "-p",
+            "20",
+            "-p",
+            &color,
+            "-p",
+            "(true, 5)",
+        ])?;
+        assert_eq!(
+            expected,
+            format!(
+                "0000000087f27a39000000000000000a0000000000000014{}00000000000000010000000000000005",
+                "ab".repeat(32)
+            )
+        );
+        assert_eq!(
+            run(&[
+                "encode",
+                "function",
+                COMPLEX_ABI,
+                "boo",
+                "--json",
+                &by_position
+            ])?,
+            expected
+        );
+        assert_eq!(
+            run(&["encode", "function", COMPLEX_ABI, "boo", "--json", &by_name])?,
+            expected
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn json_arguments_are_checked_against_the_abi() {
+        let error = run(&[
+            "encode",
+            "function",
+            COMPLEX_ABI,
+            "boo",
+            "--json",
+            r#"{"gas": 10}"#,
+        ])
+        .unwrap_err();
+
+        assert!(error.to_string().contains("amount"), "{}", error);
+    }
+
+    #[test]
+    fn decoded_outputs_are_named_after_the_abi() -> anyhow::Result<()> {
+        let data = "00000000000000010000000000000005";
+
+        assert_eq!(
+            run(&["decode", "function", COMPLEX_ABI, "boo", data, "--json"])?,
+            r#"[{"a":true,"b":5}]"#
+        );
+        assert_eq!(
+            run(&["decode", "function", COMPLEX_ABI, "boo", data])?,
+            "{\"a\":true,\"b\":5}\n"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn json_params_round_trip() -> anyhow::Result<()> {
+        let color = color();
+        let quoted_color = format!("\"{}\"", color);
+
+        let encoded = run(&[
+            "encode",
+            "params",
+            "-v",
+            "u64",
+            "\"18446744073709551615\"",
+            "-v",
+            "b256",
+            &quoted_color,
+            "--json",
+        ])?;
+        let decoded = run(&[
+            "decode", "params", "-t", "u64", "-t", "b256", &encoded, "--json",
+        ])?;
+
+        let decoded: serde_json::Value = serde_json::from_str(&decoded)?;
+        assert_eq!(decoded, serde_json::json!([u64::MAX, color]));
+        Ok(())
+    }
+}
@@ -0,0 +1,423 @@
+use fuel_types::bytes::Deserializable;
+use fuels_core::abi_decoder::ABIDecoder;
+use fuels_core::json_abi::parse_param;
+use fuels_core::tx::{ContractId, Receipt};
+use fuels_types::{errors::Error, Function, JsonABI};
+use serde_json::Value;
+
+use crate::selectors::function_selectors;
+
+/// Reads receipts from a JSON dump, either as serialized by `serde` (e.g. the
+/// receipts returned by `Provider::send_transaction`) or as returned by the
+/// node's GraphQL API, i.e. objects holding a hex encoded `rawPayload`.
+/// The receipts array can be nested inside a GraphQL response, under a
+/// `receipts` key.
+pub(crate) fn parse_receipts(json: &str) -> anyhow::Result<Vec<Receipt>> {
+    let value: Value = serde_json::from_str(json)?;
+
+    let receipts = find_receipts(&value)
+        .ok_or_else(|| anyhow::anyhow!("couldn't find an array of receipts in the input"))?;
+
+    receipts
+        .iter()
+        .enumerate()
+        .map(|(index, receipt)| {
+            parse_receipt(receipt)
+                .map_err(|err| anyhow::anyhow!("invalid receipt at index {}: {}", index, err))
+        })
+        .collect()
+}
+
+fn find_receipts(value: &Value) -> Option<&Vec<Value>> {
+    match value {
+        Value::Array(receipts) => Some(receipts),
+        Value::Object(object) => object
+            .get("receipts")
+            .and_then(Value::as_array)
+            .or_else(|| object.values().find_map(find_receipts)),
+        _ => None,
+    }
+}
+
+fn parse_receipt(value: &Value) -> anyhow::Result<Receipt> {
+    match value.get("rawPayload").and_then(Value::as_str) {
+        Some(payload) => {
+            let bytes = hex::decode(payload.trim_start_matches("0x"))?;
+            Ok(Receipt::from_bytes(&bytes)?)
+        }
+        // `fuel-types` deserializes ids from borrowed strings, which
+        // `serde_json::from_value` can't provide.
+        None => Ok(serde_json::from_str(&value.to_string())?),
+    }
+}
+
+/// Describes each receipt on its own line, resolving called functions
+/// through the selectors of `abi` and decoding their return values. Only the
+/// calls to `contract`, the contract `abi` describes, are resolved when it is
+/// known. A value that can't be decoded is shown raw, along with the error.
+pub(crate) fn decode_receipts(
+    abi: &JsonABI,
+    receipts: &[Receipt],
+    contract: Option<&ContractId>,
+) -> anyhow::Result<String> {
+    let selectors = function_selectors(abi)?;
+
+    // Functions being executed, innermost last, so that returned values can
+    // be decoded with the right output type.
+    let mut call_stack: Vec<(ContractId, Option<&Function>)> = vec![];
+
+    let mut result = String::new();
+    for receipt in receipts {
+        let line = match receipt {
+            Receipt::Call {
+                id,
+                to,
+                amount,
+                asset_id,
+                gas,
+                param1,
+                ..
+            } => {
+                let selector = param1.to_be_bytes();
+                let function = selectors
+                    .iter()
+                    .filter(|_| contract.is_none() || contract == Some(to))
+                    .find(|s| s.selector == selector)
+                    .map(|s| s.function);
+                call_stack.push((*to, function));
+
+                let name = match &function {
+                    Some(function) => function.name.clone(),
+                    None => format!("unknown function (selector 0x{})", hex::encode(selector)),
+                };
+                format!(
+                    "Call {:#x} -> {:#x}: {} (amount: {} of {:#x}, gas: {})",
+                    id, to, name, amount, asset_id, gas
+                )
+            }
+            Receipt::Return { id, val, .. } => {
+                let value = match returning_function(&mut call_stack, id) {
+                    Some(function) => decode_output(function, &val.to_be_bytes(), val.to_string()),
+                    None => val.to_string(),
+                };
+                format!("Return {:#x}: {}", id, value)
+            }
+            Receipt::ReturnData { id, data, .. } => {
+                let value = match returning_function(&mut call_stack, id) {
+                    Some(function) => {
+                        decode_output(function, data, format!("0x{}", hex::encode(data)))
+                    }
+                    None => format!("0x{}", hex::encode(data)),
+                };
+                format!("ReturnData {:#x}: {}", id, value)
+            }
+            Receipt::Log {
+                id, ra, rb, rc, rd, ..
+            } => format!("Log {:#x}: {} {} {} {}", id, ra, rb, rc, rd),
+            Receipt::LogData { id, data, .. } => {
+                format!("LogData {:#x}: 0x{}", id, hex::encode(data))
+            }
+            Receipt::Revert { id, ra, .. } => format!("Revert {:#x}: {}", id, ra),
+            Receipt::Panic { id, reason, .. } => {
+                format!("Panic {:#x}: {:?}", id, reason.reason())
+            }
+            Receipt::Transfer {
+                id,
+                to,
+                amount,
+                asset_id,
+                ..
+            } => format!(
+                "Transfer {:#x} -> {:#x}: {} of {:#x}",
+                id, to, amount, asset_id
+            ),
+            Receipt::TransferOut {
+                id,
+                to,
+                amount,
+                asset_id,
+                ..
+            } => format!(
+                "TransferOut {:#x} -> {:#x}: {} of {:#x}",
+                id, to, amount, asset_id
+            ),
+            Receipt::ScriptResult { result, gas_used } => {
+                format!("ScriptResult: {:?} (gas used: {})", result, gas_used)
+            }
+        };
+
+        result.push_str(&line);
+        result.push('\n');
+    }
+
+    Ok(result)
+}
+
+/// Pops the function returning from contract `id`, if it is the innermost call.
+fn returning_function<'a>(
+    call_stack: &mut Vec<(ContractId, Option<&'a Function>)>,
+    id: &ContractId,
+) -> Option<&'a Function> {
+    match call_stack.last() {
+        Some((contract_id, function)) if contract_id == id => {
+            let function = *function;
+            call_stack.pop();
+            function
+        }
+        _ => None,
+    }
+}
+
+/// Decodes the value returned by `function`, falling back to `raw` and the
+/// reason it couldn't be decoded.
+fn decode_output(function: &Function, data: &[u8], raw: String) -> String {
+    let output = match function.outputs.first() {
+        Some(output) => output,
+        None => return "()".to_string(),
+    };
+
+    let decoded = parse_param(output)
+        .and_then(|param| ABIDecoder::decode_single(&param, data).map_err(Error::from))
+        .map_err(anyhow::Error::from)
+        .and_then(|token| crate::output_to_json(&token, output));
+
+    match decoded {
+        Ok(value) => value.to_string(),
+        Err(err) => format!(
+            "{} (couldn't decode the output of `{}`: {})",
+            raw, function.name, err
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fuel_types::bytes::SerializableVec;
+    use fuels_core::tx::{AssetId, Bytes32, ScriptExecutionResult};
+    use serde_json::json;
+
+    const ABI: &str = r#"[
+        {
+            "type": "function",
+            "name": "outer",
+            "inputs": [],
+            "outputs": [{ "name": "", "type": "u64" }]
+        },
+        {
+            "type": "function",
+            "name": "get_pair",
+            "inputs": [{ "name": "id", "type": "u8" }],
+            "outputs": [
+                {
+                    "name": "",
+                    "type": "struct Pair",
+                    "components": [
+                        { "name": "a", "type": "u64" },
+                        { "name": "b", "type": "bool" }
+                    ]
+                }
+            ]
+        }
+    ]"#;
+
+    fn param1_of(abi: &JsonABI, name: &str) -> u64 {
+        let selectors = function_selectors(abi).unwrap();
+        let selector = selectors
+            .iter()
+            .find(|s| s.function.name == name)
+            .unwrap()
+            .selector;
+        u64::from_be_bytes(selector)
+    }
+
+    fn call(id: ContractId, to: ContractId, param1: u64) -> Receipt {
+        Receipt::call(id, to, 0, AssetId::default(), 100, param1, 0, 0, 0)
+    }
+
+    fn sample_receipts() -> Vec<Receipt> {
+        vec![
+            Receipt::ret(ContractId::from([1; 32]), 5, 0, 0),
+            Receipt::script_result(ScriptExecutionResult::Success, 42),
+        ]
+    }
+
+    #[test]
+    fn parses_receipts_serialized_by_serde() -> anyhow::Result<()> {
+        let receipts = sample_receipts();
+
+        let json = serde_json::to_string(&receipts)?;
+
+        assert_eq!(parse_receipts(&json)?, receipts);
+        Ok(())
+    }
+
+    #[test]
+    fn parses_raw_payloads_nested_in_a_graphql_response() -> anyhow::Result<()> {
+        let receipts = sample_receipts();
+        let raw_receipts: Vec<_> = receipts
+            .iter()
+            .map(|receipt| json!({ "rawPayload": format!("0x{}", hex::encode(receipt.clone().to_bytes())) }))
+            .collect();
+
+        let json = json!({ "data": { "transaction": { "receipts": raw_receipts } } });
+
+        assert_eq!(parse_receipts(&json.to_string())?, receipts);
+        Ok(())
+    }
+
+    #[test]
+    fn parse_errors_name_the_invalid_receipt() {
+        let json = json!({ "receipts": [{ "rawPayload": "0x00" }] });
+        let error = parse_receipts(&json.to_string()).unwrap_err();
+        assert!(
+            error
+                .to_string()
+                .starts_with("invalid receipt at index 0: "),
+            "{}",
+            error
+        );
+
+        let error = parse_receipts(r#"{ "data": 1 }"#).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "couldn't find an array of receipts in the input"
+        );
+    }
+
+    #[test]
+    fn returns_are_decoded_with_the_innermost_call() -> anyhow::Result<()> {
+        let abi: JsonABI = serde_json::from_str(ABI)?;
+        let script = ContractId::zeroed();
+        let outer = ContractId::from([1; 32]);
+        let inner = ContractId::from([2; 32]);
+        let pair = [[0u8; 7].as_slice(), &[7], &[0; 7], &[1]].concat();
+
+        let receipts = vec![
+            call(script, outer, param1_of(&abi, "outer")),
+            call(outer, inner, param1_of(&abi, "get_pair")),
+            Receipt::return_data(inner, 0, 16, Bytes32::zeroed(), pair, 0, 0),
+            Receipt::ret(outer, 5, 0, 0),
+            // Returned by the script itself, whose call isn't on the stack
+            Receipt::ret(script, 1, 0, 0),
+            Receipt::script_result(ScriptExecutionResult::Success, 42),
+        ];
+
+        let expected = [
+            format!(
+                "Call {:#x} -> {:#x}: outer (amount: 0 of {:#x}, gas: 100)",
+                script,
+                outer,
+                AssetId::default()
+            ),
+            format!(
+                "Call {:#x} -> {:#x}: get_pair (amount: 0 of {:#x}, gas: 100)",
+                outer,
+                inner,
+                AssetId::default()
+            ),
+            format!("ReturnData {:#x}: {{\"a\":7,\"b\":true}}", inner),
+            format!("Return {:#x}: 5", outer),
+            format!("Return {:#x}: 1", script),
+            "ScriptResult: Success (gas used: 42)".to_string(),
+        ];
+        assert_eq!(
+            decode_receipts(&abi, &receipts, None)?,
+            expected.join("\n") + "\n"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn unknown_functions_are_shown_undecoded() -> anyhow::Result<()> {
+        let abi: JsonABI = serde_json::from_str(ABI)?;
+        let script = ContractId::zeroed();
+        let contract = ContractId::from([1; 32]);
+
+        let receipts = vec![
+            call(script, contract, 0xdeadbeef),
+            Receipt::return_data(contract, 0, 2, Bytes32::zeroed(), vec![1, 2], 0, 0),
+        ];
+
+        let expected = [
+            format!(
+                "Call {:#x} -> {:#x}: unknown function (selector 0x00000000deadbeef) (amount: 0 of {:#x}, gas: 100)",
+                script,
+                contract,
+                AssetId::default()
+            ),
+            format!("ReturnData {:#x}: 0x0102", contract),
+        ];
+        assert_eq!(
+            decode_receipts(&abi, &receipts, None)?,
+            expected.join("\n") + "\n"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn undecodable_outputs_are_shown_raw_with_the_error() -> anyhow::Result<()> {
+        let abi: JsonABI = serde_json::from_str(ABI)?;
+        let script = ContractId::zeroed();
+        let contract = ContractId::from([1; 32]);
+
+        // A struct can't be returned in a single word
+        let receipts = vec![
+            call(script, contract, param1_of(&abi, "get_pair")),
+            Receipt::ret(contract, 5, 0, 0),
+            Receipt::script_result(ScriptExecutionResult::Success, 42),
+        ];
+
+        let decoded = decode_receipts(&abi, &receipts, None)?;
+        let lines: Vec<_> = decoded.lines().collect();
+        assert!(
+            lines[1].starts_with(&format!(
+                "Return {:#x}: 5 (couldn't decode the output of `get_pair`: ",
+                contract
+            )),
+            "{}",
+            lines[1]
+        );
+        assert_eq!(lines[2], "ScriptResult: Success (gas used: 42)");
+        Ok(())
+    }
+
+    #[test]
+    fn only_calls_to_the_given_contract_are_decoded() -> anyhow::Result<()> {
+        let abi: JsonABI = serde_json::from_str(ABI)?;
+        let script = ContractId::zeroed();
+        let contract = ContractId::from([1; 32]);
+        let other = ContractId::from([2; 32]);
+        let selector = param1_of(&abi, "outer");
+
+        let receipts = vec![
+            call(script, contract, selector),
+            Receipt::ret(contract, 5, 0, 0),
+            call(script, other, selector),
+            Receipt::ret(other, 6, 0, 0),
+        ];
+
+        let expected = [
+            format!(
+                "Call {:#x} -> {:#x}: outer (amount: 0 of {:#x}, gas: 100)",
+                script,
+                contract,
+                AssetId::default()
+            ),
+            format!("Return {:#x}: 5", contract),
+            format!(
+                "Call {:#x} -> {:#x}: unknown function (selector 0x{:016x}) (amount: 0 of {:#x}, gas: 100)",
+                script,
+                other,
+                selector,
+                AssetId::default()
+            ),
+            format!("Return {:#x}: 6", other),
+        ];
+        assert_eq!(
+            decode_receipts(&abi, &receipts, Some(&contract))?,
+            expected.join("\n") + "\n"
+        );
+        Ok(())
+    }
+}
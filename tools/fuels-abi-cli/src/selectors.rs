@@ -0,0 +1,133 @@
+use fuels_core::abi_encoder::ABIEncoder;
+use fuels_core::json_abi::ABIParser;
+use fuels_types::{Function, JsonABI};
+
+/// A function of the ABI along with its canonical signature and selector.
+pub(crate) struct Selector<'a> {
+    pub function: &'a Function,
+    pub signature: String,
+    pub selector: [u8; 8],
+}
+
+/// Computes the selector of every function in `abi`, the same way abigen does.
+pub(crate) fn function_selectors(abi: &JsonABI) -> anyhow::Result<Vec<Selector<'_>>> {
+    let parser = ABIParser::new();
+
+    abi.iter()
+        .filter(|function| function.type_field == "function" || function.type_field == "contract")
+        .map(|function| {
+            let signature = parser.build_fn_selector(&function.name, &function.inputs)?;
+            let selector = ABIEncoder::encode_function_selector(&signature);
+            Ok(Selector {
+                function,
+                signature,
+                selector,
+            })
+        })
+        .collect()
+}
+
+/// Lists every function of `abi` with its selector and canonical signature.
+pub(crate) fn list_selectors(abi: &JsonABI) -> anyhow::Result<String> {
+    let mut result = String::new();
+    for Selector {
+        signature,
+        selector,
+        ..
+    } in function_selectors(abi)?
+    {
+        result.push_str(&format!("0x{}  {}\n", hex::encode(selector), signature));
+    }
+
+    Ok(result)
+}
+
+/// Finds the function of `abi` whose selector is `selector`, given in hex.
+/// Both the full 8 byte selector and its last 4 significant bytes are accepted.
+pub(crate) fn lookup_selector(abi: &JsonABI, selector: &str) -> anyhow::Result<String> {
+    let bytes = hex::decode(selector.trim_start_matches("0x"))?;
+    let selector: [u8; 8] = match bytes.len() {
+        8 => bytes.as_slice().try_into()?,
+        4 => {
+            let mut selector = [0; 8];
+            selector[4..].copy_from_slice(&bytes);
+            selector
+        }
+        _ => anyhow::bail!("a selector must be 4 or 8 bytes long, got {}", bytes.len()),
+    };
+
+    function_selectors(abi)?
+        .into_iter()
+        .find(|s| s.selector == selector)
+        .map(|s| s.signature)
+        .ok_or_else(|| anyhow::anyhow!("no function has the selector 0x{}", hex::encode(selector)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ABI: &str = r#"[
+        {
+            "type": "function",
+            "name": "takes_u32_returns_bool",
+            "inputs": [{ "name": "arg", "type": "u32" }],
+            "outputs": [{ "name": "", "type": "bool" }]
+        },
+        {
+            "type": "function",
+            "name": "get",
+            "inputs": [],
+            "outputs": [{ "name": "", "type": "u64" }]
+        }
+    ]"#;
+
+    #[test]
+    fn lists_selectors_with_signatures() -> anyhow::Result<()> {
+        let abi: JsonABI = serde_json::from_str(ABI)?;
+
+        assert_eq!(
+            list_selectors(&abi)?,
+            "0x000000006355e6ee  takes_u32_returns_bool(u32)\n0x0000000075b70457  get()\n"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn looks_up_full_and_short_selectors() -> anyhow::Result<()> {
+        let abi: JsonABI = serde_json::from_str(ABI)?;
+
+        for selector in [
+            "0x000000006355e6ee",
+            "000000006355e6ee",
+            "0x6355e6ee",
+            "6355e6ee",
+        ] {
+            assert_eq!(
+                lookup_selector(&abi, selector)?,
+                "takes_u32_returns_bool(u32)"
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn unknown_and_malformed_selectors_are_errors() -> anyhow::Result<()> {
+        let abi: JsonABI = serde_json::from_str(ABI)?;
+
+        let error = lookup_selector(&abi, "0xdeadbeef").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "no function has the selector 0x00000000deadbeef"
+        );
+
+        let error = lookup_selector(&abi, "0x6355e6").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "a selector must be 4 or 8 bytes long, got 3"
+        );
+
+        assert!(lookup_selector(&abi, "0xzz").is_err());
+        Ok(())
+    }
+}